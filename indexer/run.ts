@@ -93,7 +93,7 @@ function extractCanisterId(url: string): string | null {
 function generateSql(sub: Submission): string {
   const id = imageId(sub.appUrl);
   const canisterId = extractCanisterId(sub.appUrl);
-  return `INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES (${sqlOpt(sub.appUrl)}, ${sqlOpt(canisterId)}, ${sqlOpt(sub.aiTitle)}, ${sqlOpt(sub.aiDescription)}, ${sqlOpt(id)}, ${sqlOpt(sub.authorName)}, ${sqlOpt(sub.appName)}, ${sqlOpt(sub.socialPostUrl)});`;
+  return `INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES (${sqlOpt(sub.appUrl)}, ${sqlOpt(sub.canonicalUrl)}, ${sqlOpt(canisterId)}, ${sqlOpt(sub.aiTitle)}, ${sqlOpt(sub.aiDescription)}, ${sqlOpt(id)}, ${sqlOpt(sub.authorName)}, ${sqlOpt(sub.appName)}, ${sqlOpt(sub.socialPostUrl)});`;
 }
 
 // Process submissions that need it
@@ -1,11 +1,14 @@
 import { readFileSync } from 'fs';
 import { parse } from 'csv-parse/sync';
+import { canonicalizeUrl } from './url.js';
 
 export type Submission = {
   authorName: string;
   appName: string;
   socialPostUrl: string;
   appUrl: string;
+  /** `appUrl` in canonical form, see `canonicalizeUrl`. */
+  canonicalUrl: string;
   aiTitle: string;
   aiDescription: string;
 };
@@ -21,7 +24,8 @@ const HEADER = [
 
 /**
  * Parse submissions.csv and return validated Submission records.
- * Skips rows with missing/empty app URLs or obvious test/spam entries.
+ * Skips rows with missing/empty app URLs, URLs that cannot be canonicalized
+ * or obvious test/spam entries.
  * Supports optional aiTitle and aiDescription columns (5th and 6th).
  */
 export function loadSubmissions(csvPath: string): Submission[] {
@@ -52,15 +56,24 @@ export function loadSubmissions(csvPath: string): Submission[] {
     // Strip fragment identifiers (e.g. #caffeineAdminToken=...)
     const cleanUrl = appUrl.split('#')[0];
 
-    // Deduplicate by cleaned URL
-    if (seenUrls.has(cleanUrl)) continue;
-    seenUrls.add(cleanUrl);
+    // Deduplicate by canonical URL, so trailing slashes, host case and
+    // tracking params don't let the same app in twice
+    let canonicalUrl: string;
+    try {
+      canonicalUrl = canonicalizeUrl(cleanUrl);
+    } catch (err) {
+      console.warn(`Skipping ${cleanUrl}: ${err instanceof Error ? err.message : err}`);
+      continue;
+    }
+    if (seenUrls.has(canonicalUrl)) continue;
+    seenUrls.add(canonicalUrl);
 
     submissions.push({
       authorName: authorName || 'Unknown',
       appName: appName || 'Untitled',
       socialPostUrl: socialPostUrl || '',
       appUrl: cleanUrl,
+      canonicalUrl,
       aiTitle: aiTitle || '',
       aiDescription: aiDescription || '',
     });
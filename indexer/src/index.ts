@@ -4,6 +4,7 @@ export { BrowserExtractor } from './extractor.js';
 export { AIEnricher } from './ai.js';
 export { loadSubmissions, serializeSubmissions } from './csv.js';
 export type { Submission } from './csv.js';
+export { canonicalizeUrl } from './url.js';
 export type {
   Input,
   PageSnapshot,
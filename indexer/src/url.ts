@@ -0,0 +1,56 @@
+/**
+ * Query parameters added by ad and social platforms that never change which
+ * page is served. `utm_*` is matched by prefix.
+ */
+const TRACKING_PARAMS = new Set([
+  'dclid',
+  'fbclid',
+  'gbraid',
+  'gclid',
+  'igshid',
+  'mc_cid',
+  'mc_eid',
+  'msclkid',
+  'ref_src',
+  'twclid',
+  'wbraid',
+  'yclid',
+]);
+
+/**
+ * The canonical form of an app URL, as computed by the canister for its
+ * `canonical_url` column: https, lowercase host without the default port,
+ * no trailing slash, no tracking params and no fragment. A `www.` prefix is
+ * kept. Keep in sync with `server/src/app/canonical_url.rs`; both are tested
+ * against `server/src/app/canonical_url_tests.json`.
+ */
+export function canonicalizeUrl(url: string): string {
+  const match = /^https?:\/\/([^#]*)/i.exec(url.trim());
+  if (!match) throw new Error(`Not an http(s) URL: ${url}`);
+
+  const rest = match[1];
+  const authorityEnd = rest.search(/[/?]/);
+  const authority = authorityEnd === -1 ? rest : rest.slice(0, authorityEnd);
+  const pathAndQuery = authorityEnd === -1 ? '' : rest.slice(authorityEnd);
+
+  const host = authority
+    .split('@')
+    .pop()!
+    .replace(/:(443|80)$/, '')
+    .toLowerCase();
+  if (!host) throw new Error(`URL has no host: ${url}`);
+
+  const queryStart = pathAndQuery.indexOf('?');
+  const path = (queryStart === -1 ? pathAndQuery : pathAndQuery.slice(0, queryStart)).replace(
+    /\/+$/,
+    ''
+  );
+  const query = (queryStart === -1 ? '' : pathAndQuery.slice(queryStart + 1))
+    .split('&')
+    .filter((param) => {
+      const name = param.split('=')[0].toLowerCase();
+      return param !== '' && !name.startsWith('utm_') && !TRACKING_PARAMS.has(name);
+    });
+
+  return `https://${host}${path}${query.length ? `?${query.join('&')}` : ''}`;
+}
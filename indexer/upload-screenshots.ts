@@ -0,0 +1,88 @@
+// Move the screenshots in `indexer/images/` into the canister's image store.
+//
+// Screenshots used to be bundled into the canister binary under
+// `<image_id>_1500.jpg`, with `image_id` the MD5 of the app URL. This uploads
+// the 1500px original of every app that still points at such an ID through
+// `create_image_upload` / `upload_image_chunk` / `commit_image_upload`, which
+// re-keys the app to the stored image. Apps already moved are skipped, so
+// the script can be re-run after a failure. Run it after every deploy that
+// adds apps, as a controller.
+//
+// Usage: pnpm upload-screenshots <canister-url> [icp args...]
+//   e.g. pnpm upload-screenshots https://<canister-id>.icp0.io -e ic
+import { execFileSync } from 'child_process';
+import { existsSync, readFileSync } from 'fs';
+import { dirname, join } from 'path';
+import { fileURLToPath } from 'url';
+
+const __dirname = dirname(fileURLToPath(import.meta.url));
+const imagesDir = join(__dirname, 'images');
+
+// Each chunk is passed on the command line as a Candid blob literal, three
+// characters per byte, which has to stay under Linux's 128 KiB limit for a
+// single argument.
+const CHUNK_BYTES = 32 * 1024;
+
+interface App {
+  id: number;
+  image_id: string | null;
+}
+
+const [canisterUrl, ...icpArgs] = process.argv.slice(2);
+if (!canisterUrl) {
+  console.error('Usage: pnpm upload-screenshots <canister-url> [icp args...]');
+  process.exit(1);
+}
+
+/**
+ * The `Ok` value of the `Result` that `method` returns. The reply is read
+ * as JSON with `--output json` rather than parsed from Candid text.
+ */
+function call(method: string, args: string): unknown {
+  const output = execFileSync(
+    'icp',
+    ['canister', 'call', '--output', 'json', ...icpArgs, 'server', method, args],
+    { encoding: 'utf8' }
+  );
+  let result = JSON.parse(output);
+  // Return values may be printed as an array; these methods return one
+  if (Array.isArray(result)) result = result[0];
+  if (result && typeof result === 'object' && 'Ok' in result) return result.Ok;
+  const error = result && typeof result === 'object' && 'Err' in result ? result.Err : output;
+  throw new Error(`${method} failed: ${typeof error === 'string' ? error.trim() : JSON.stringify(error)}`);
+}
+
+function blob(bytes: Buffer): string {
+  return `blob "${Array.from(bytes, (b) => `\\${b.toString(16).padStart(2, '0')}`).join('')}"`;
+}
+
+const response = await fetch(new URL('/api/apps', canisterUrl));
+if (!response.ok) throw new Error(`GET /api/apps: ${response.status}`);
+const apps = (await response.json()) as App[];
+
+let uploaded = 0;
+let failed = 0;
+for (const app of apps) {
+  if (!app.image_id) continue;
+  const file = join(imagesDir, `${app.image_id}_1500.jpg`);
+  if (!existsSync(file)) continue;
+
+  try {
+    const data = readFileSync(file);
+    // A `nat64`, which may be printed as a string to keep its precision
+    const uploadId = String(call('create_image_upload', '()'));
+    for (let offset = 0; offset < data.length; offset += CHUNK_BYTES) {
+      const chunk = data.subarray(offset, offset + CHUNK_BYTES);
+      call('upload_image_chunk', `(${uploadId}, ${blob(chunk)})`);
+    }
+    call('commit_image_upload', `(${uploadId}, opt ${app.id})`);
+    uploaded++;
+    console.log(`App ${app.id}: uploaded ${app.image_id}_1500.jpg (${data.length} bytes)`);
+  } catch (e) {
+    failed++;
+    console.error(`App ${app.id}: ${e instanceof Error ? e.message : e}`);
+  }
+}
+
+console.log(`\nUploaded ${uploaded} screenshots, ${failed} failed`);
+if (failed > 0) process.exit(1);
@@ -0,0 +1,33 @@
+// Check `canonicalizeUrl` against the test vectors the canister's
+// `canonicalize` is tested with, so the two stay in sync.
+//
+// Usage: pnpm test
+import assert from 'node:assert/strict';
+import { readFileSync } from 'fs';
+import { test } from 'node:test';
+import { dirname, join } from 'path';
+import { fileURLToPath } from 'url';
+
+import { canonicalizeUrl } from './src/url.js';
+
+const __dirname = dirname(fileURLToPath(import.meta.url));
+
+interface Vector {
+  url: string;
+  canonical?: string;
+  error?: boolean;
+}
+
+const vectors = JSON.parse(
+  readFileSync(join(__dirname, '../server/src/app/canonical_url_tests.json'), 'utf8')
+) as Vector[];
+
+for (const vector of vectors) {
+  test(vector.url, () => {
+    if (vector.error) {
+      assert.throws(() => canonicalizeUrl(vector.url));
+    } else {
+      assert.equal(canonicalizeUrl(vector.url), vector.canonical);
+    }
+  });
+}
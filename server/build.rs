@@ -1,9 +1,28 @@
+use std::path::Path;
+
+/// The CJK fallback fonts, generated by `subset-cjk-fonts.sh` next to them.
+const CJK_FONTS: [&str; 2] = [
+    "src/ogimage/includes/NotoSansCJKjp-Bold-Subset.otf",
+    "src/ogimage/includes/NotoSansCJKjp-Regular-Subset.otf",
+];
+
 fn main() {
     ic_asset_router::build::generate_routes();
-
     ic_sql_migrate::Builder::new()
         .with_migrations_dir("migrations")
         .with_seeds_dir("src/seeds")
         .build()
         .unwrap();
+
+    println!("cargo:rustc-check-cfg=cfg(cjk_font)");
+    println!("cargo:rerun-if-changed=src/ogimage/includes");
+    if CJK_FONTS.iter().all(|font| Path::new(font).exists()) {
+        println!("cargo:rustc-cfg=cjk_font");
+    } else {
+        println!(
+            "cargo:warning=CJK fallback fonts are missing, so OG images leave out Chinese, \
+             Japanese and Korean text. Run server/src/ogimage/includes/subset-cjk-fonts.sh \
+             to generate them."
+        );
+    }
 }
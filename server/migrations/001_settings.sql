@@ -0,0 +1,6 @@
+-- Deployment settings passed as canister init/upgrade arguments. Persisted so
+-- an upgrade without arguments keeps the previous values.
+CREATE TABLE IF NOT EXISTS setting (
+    key   TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
@@ -0,0 +1,9 @@
+-- Rendered OG images, persisted so they survive upgrades. `input_hash` covers
+-- everything an image is rendered from; a mismatch means the copy is stale.
+CREATE TABLE IF NOT EXISTS og_image (
+    app_id     INTEGER NOT NULL,
+    file_name  TEXT NOT NULL,
+    input_hash TEXT NOT NULL,
+    data       BLOB NOT NULL,
+    PRIMARY KEY (app_id, file_name)
+);
@@ -0,0 +1,13 @@
+-- OG templates and background images uploaded at runtime. Every upload is a
+-- new version; at most one version per name is active, and names without an
+-- active version use the copy compiled into the canister. `preview_key` is an
+-- unguessable handle for previewing a version over HTTP before activating it.
+CREATE TABLE IF NOT EXISTS og_template (
+    name        TEXT NOT NULL,
+    version     INTEGER NOT NULL,
+    data        BLOB NOT NULL,
+    preview_key TEXT NOT NULL UNIQUE,
+    active      INTEGER NOT NULL DEFAULT 0,
+    created_at  INTEGER NOT NULL DEFAULT (strftime('%s','now')),
+    PRIMARY KEY (name, version)
+);
@@ -0,0 +1,18 @@
+-- Screenshots resized on request. `image` records each source's dimensions
+-- and a hash of its bytes; `image_variant` holds every size and format
+-- rendered from it so far. Variants of a replaced source are dropped.
+CREATE TABLE IF NOT EXISTS image (
+    image_id    TEXT PRIMARY KEY,
+    source_hash TEXT NOT NULL,
+    width       INTEGER NOT NULL,
+    height      INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS image_variant (
+    image_id TEXT NOT NULL REFERENCES image (image_id),
+    width    INTEGER NOT NULL,
+    format   TEXT NOT NULL,
+    height   INTEGER NOT NULL,
+    data     BLOB NOT NULL,
+    PRIMARY KEY (image_id, width, format)
+);
@@ -0,0 +1,22 @@
+-- Screenshots uploaded at runtime, kept in the database (and so in stable
+-- memory) rather than built into the canister. `image_id` is derived from
+-- the content, and `app.image_id` refers to it; `image` records dimensions.
+-- Uploads arrive in chunks and are staged in `image_upload_chunk` until
+-- committed.
+CREATE TABLE IF NOT EXISTS stored_image (
+    image_id     TEXT PRIMARY KEY,
+    content_type TEXT NOT NULL,
+    data         BLOB NOT NULL,
+    created_at   INTEGER NOT NULL DEFAULT (strftime('%s','now'))
+);
+
+CREATE TABLE IF NOT EXISTS image_upload (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s','now'))
+);
+
+CREATE TABLE IF NOT EXISTS image_upload_chunk (
+    id        INTEGER PRIMARY KEY AUTOINCREMENT,
+    upload_id INTEGER NOT NULL REFERENCES image_upload (id),
+    data      BLOB NOT NULL
+);
@@ -0,0 +1,18 @@
+-- Frontend assets uploaded at runtime. `frontend_asset` is the committed
+-- set, served in place of the compiled-in files at the same paths; batches
+-- stage chunks in `asset_chunk` until they are committed.
+CREATE TABLE IF NOT EXISTS frontend_asset (
+    path TEXT PRIMARY KEY,
+    data BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS asset_batch (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    created_at INTEGER NOT NULL DEFAULT (strftime('%s','now'))
+);
+
+CREATE TABLE IF NOT EXISTS asset_chunk (
+    id       INTEGER PRIMARY KEY AUTOINCREMENT,
+    batch_id INTEGER NOT NULL REFERENCES asset_batch (id),
+    data     BLOB NOT NULL
+);
@@ -0,0 +1,5 @@
+-- BlurHash placeholder and colours of each screenshot, computed on upload
+-- and, for bundled screenshots, at install and upgrade.
+ALTER TABLE image ADD COLUMN blurhash TEXT;
+ALTER TABLE image ADD COLUMN dominant_color TEXT;
+ALTER TABLE image ADD COLUMN accent_color TEXT;
@@ -0,0 +1,8 @@
+-- Statistics for spotting screenshots of apps that were down: a perceptual
+-- hash, luma standard deviation and the share of pixels in the dominant
+-- colour. `quality_flags` holds the resulting flags, space-separated, and is
+-- recomputed whenever screenshots change.
+ALTER TABLE image ADD COLUMN phash TEXT;
+ALTER TABLE image ADD COLUMN luma_stddev REAL;
+ALTER TABLE image ADD COLUMN uniform_fraction REAL;
+ALTER TABLE image ADD COLUMN quality_flags TEXT;
@@ -0,0 +1,12 @@
+-- Latest link-health check of each app's URL. `status_code` is NULL when
+-- the request failed outright, with the reason in `error`. `dead_since` is
+-- NULL while the app is alive.
+CREATE TABLE IF NOT EXISTS link_check (
+    app_id        INTEGER PRIMARY KEY REFERENCES app (id),
+    status_code   INTEGER,
+    latency_ms    INTEGER NOT NULL,
+    error         TEXT,
+    checked_at    INTEGER NOT NULL,
+    last_alive_at INTEGER,
+    dead_since    INTEGER
+);
@@ -0,0 +1,13 @@
+-- State of each scheduled job. `cursor` is set while a pass is under way
+-- and marks where the next slice carries on. Times are seconds since the
+-- Unix epoch.
+CREATE TABLE IF NOT EXISTS job (
+    name              TEXT PRIMARY KEY,
+    cursor            TEXT,
+    next_run_at       INTEGER NOT NULL,
+    last_run_at       INTEGER,
+    last_completed_at INTEGER,
+    last_instructions INTEGER,
+    last_error        TEXT,
+    passes            INTEGER NOT NULL DEFAULT 0
+);
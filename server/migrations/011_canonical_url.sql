@@ -0,0 +1,13 @@
+-- Canonical form of each app URL, see `app::canonical_url`. Filled in after
+-- migrations and seeds run, since canonicalization is done in Rust. An app
+-- whose canonical URL is already taken keeps it NULL and is recorded in
+-- `app_url_duplicate` along with the app it duplicates.
+ALTER TABLE app ADD COLUMN canonical_url TEXT;
+CREATE UNIQUE INDEX IF NOT EXISTS app_canonical_url ON app (canonical_url);
+
+CREATE TABLE IF NOT EXISTS app_url_duplicate (
+    app_id        INTEGER PRIMARY KEY REFERENCES app (id),
+    duplicate_of  INTEGER NOT NULL REFERENCES app (id),
+    canonical_url TEXT NOT NULL,
+    found_at      INTEGER NOT NULL DEFAULT (strftime('%s','now'))
+);
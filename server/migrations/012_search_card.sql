@@ -0,0 +1,9 @@
+-- Rendered `/og/search.png` cards, keyed by normalized query, see
+-- `ogimage::search_card`. Only queries with results get their own card, and
+-- cards are dropped after a day or once there are too many.
+CREATE TABLE IF NOT EXISTS search_card (
+    query       TEXT PRIMARY KEY,
+    input_hash  TEXT NOT NULL,
+    data        BLOB NOT NULL,
+    rendered_at INTEGER NOT NULL
+);
@@ -0,0 +1,2 @@
+-- The 1200x600 Twitter card was dropped; X is served the landscape card.
+DELETE FROM og_image WHERE file_name LIKE 'og-twitter.%';
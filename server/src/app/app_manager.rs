@@ -1,84 +1,68 @@
 use super::app_types::App;
-use ic_rusqlite::with_connection;
+use super::canonical_url;
+use crate::link_health::DEPRIORITIZE_AFTER_SECONDS;
+use ic_rusqlite::{with_connection, Row};
+
+/// The columns [`app_from_row`] reads, in order, with the joins they need.
+/// Queries append their own `WHERE` and `ORDER BY`.
+const APP_SELECT: &str = "
+    SELECT
+      id,
+      url,
+      canister_id,
+      title,
+      description,
+      image_id,
+      author_name,
+      app_name,
+      social_post_url,
+      created_at,
+      updated_at,
+      blurhash,
+      dominant_color,
+      accent_color,
+      quality_flags,
+      CASE WHEN checked_at IS NULL THEN NULL ELSE dead_since IS NULL END,
+      last_alive_at,
+      dead_since
+    FROM app
+    LEFT JOIN image USING (image_id)
+    LEFT JOIN link_check ON link_check.app_id = app.id";
+
+/// Condition that leaves out apps found to duplicate an earlier app's URL,
+/// see [`canonical_url::backfill`]. Listings and counts use it; the app's
+/// own page stays reachable.
+pub(crate) const LISTED: &str = "app.id NOT IN (SELECT app_id FROM app_url_duplicate)";
 
 pub struct AppManager {}
 
 impl AppManager {
     pub fn get_by_id(id: i64) -> Result<App, String> {
         with_connection(|conn| {
-            let sql = r#"
-                SELECT
-                  id,
-                  url,
-                  canister_id,
-                  title,
-                  description,
-                  image_id,
-                  author_name,
-                  app_name,
-                  social_post_url,
-                  created_at,
-                  updated_at
-                FROM app
-                WHERE id = ?1
-            "#;
-
-            conn.query_row(sql, (id,), |row| {
-                Ok(App {
-                    id: row.get(0)?,
-                    url: row.get(1)?,
-                    canister_id: row.get(2)?,
-                    title: row.get(3)?,
-                    description: row.get(4)?,
-                    image_id: row.get(5)?,
-                    author_name: row.get(6)?,
-                    app_name: row.get(7)?,
-                    social_post_url: row.get(8)?,
-                    created_at: row.get(9)?,
-                    updated_at: row.get(10)?,
-                })
-            })
-            .map_err(|e| e.to_string())
+            let sql = format!(
+                "{APP_SELECT}
+                 WHERE id = ?1"
+            );
+
+            conn.query_row(&sql, (id,), app_from_row)
+                .map_err(|e| e.to_string())
         })
     }
 
+    /// Every listed app in submission order, except that apps unreachable
+    /// for a week are listed last.
     pub fn list() -> Result<Vec<App>, String> {
         with_connection(|conn| {
-            let sql = r#"
-            SELECT
-              id,
-              url,
-              canister_id,
-              title,
-              description,
-              image_id,
-              author_name,
-              app_name,
-              social_post_url,
-              created_at,
-              updated_at
-            FROM app
-            ORDER BY id
-        "#;
-
-            let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+            let sql = format!(
+                "{APP_SELECT}
+                 WHERE {LISTED}
+                 ORDER BY COALESCE(dead_since < strftime('%s','now') - ?1, 0), id"
+            );
+
+            let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
 
             let rows = stmt
-                .query_map((), |row| {
-                    Ok(App {
-                        id: row.get(0)?,
-                        url: row.get(1)?,
-                        canister_id: row.get(2)?,
-                        title: row.get(3)?,
-                        description: row.get(4)?,
-                        image_id: row.get(5)?,
-                        author_name: row.get(6)?,
-                        app_name: row.get(7)?,
-                        social_post_url: row.get(8)?,
-                        created_at: row.get(9)?,
-                        updated_at: row.get(10)?,
-                    })
-                })
+                .query_map((DEPRIORITIZE_AFTER_SECONDS,), app_from_row)
                 .map_err(|e| e.to_string())?;
 
             rows.collect::<ic_rusqlite::Result<Vec<_>>>()
@@ -92,54 +76,143 @@ impl AppManager {
         }
 
         with_connection(|conn| {
-            let sql = r#"
-            SELECT
-              id,
-              url,
-              canister_id,
-              title,
-              description,
-              image_id,
-              author_name,
-              app_name,
-              social_post_url,
-              created_at,
-              updated_at
-            FROM app
-            WHERE title LIKE ?1 OR description LIKE ?1 OR app_name LIKE ?1 OR author_name LIKE ?1
-            ORDER BY 
-              CASE 
-                WHEN app_name LIKE ?1 THEN 1
-                WHEN title LIKE ?1 THEN 2
-                WHEN author_name LIKE ?1 THEN 3
-                ELSE 4
-              END,
-              id
-        "#;
+            let sql = format!(
+                "{APP_SELECT}
+                 WHERE (title LIKE ?1 OR description LIKE ?1 OR app_name LIKE ?1 OR author_name LIKE ?1)
+                   AND {LISTED}
+                 ORDER BY
+                   CASE
+                     WHEN app_name LIKE ?1 THEN 1
+                     WHEN title LIKE ?1 THEN 2
+                     WHEN author_name LIKE ?1 THEN 3
+                     ELSE 4
+                   END,
+                   COALESCE(dead_since < strftime('%s','now') - ?2, 0),
+                   id"
+            );
 
             let search_pattern = format!("%{}%", query);
-            let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+            let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+
+            let rows = stmt
+                .query_map((search_pattern, DEPRIORITIZE_AFTER_SECONDS), app_from_row)
+                .map_err(|e| e.to_string())?;
+
+            rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+                .map_err(|e| e.to_string())
+        })
+    }
+
+    /// List the apps by `author`, matched exactly, in submission order.
+    pub fn list_by_author(author: &str) -> Result<Vec<App>, String> {
+        with_connection(|conn| {
+            let sql = format!(
+                "{APP_SELECT}
+                 WHERE author_name = ?1 AND {LISTED}
+                 ORDER BY id"
+            );
+
+            let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+
+            let rows = stmt
+                .query_map((author,), app_from_row)
+                .map_err(|e| e.to_string())?;
+
+            rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+                .map_err(|e| e.to_string())
+        })
+    }
+
+    /// List the most recently created apps, newest first, optionally filtered
+    /// by exact author name and/or a search query.
+    pub fn list_newest(
+        author: Option<&str>,
+        query: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<App>, String> {
+        with_connection(|conn| {
+            let sql = format!(
+                "{APP_SELECT}
+                 WHERE (?1 IS NULL OR author_name = ?1)
+                   AND (?2 IS NULL OR title LIKE ?2 OR description LIKE ?2 OR app_name LIKE ?2 OR author_name LIKE ?2)
+                   AND {LISTED}
+                 ORDER BY created_at DESC, id DESC
+                 LIMIT ?3"
+            );
+
+            let search_pattern = query.map(|q| format!("%{}%", q));
+            let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
 
             let rows = stmt
-                .query_map((search_pattern,), |row| {
-                    Ok(App {
-                        id: row.get(0)?,
-                        url: row.get(1)?,
-                        canister_id: row.get(2)?,
-                        title: row.get(3)?,
-                        description: row.get(4)?,
-                        image_id: row.get(5)?,
-                        author_name: row.get(6)?,
-                        app_name: row.get(7)?,
-                        social_post_url: row.get(8)?,
-                        created_at: row.get(9)?,
-                        updated_at: row.get(10)?,
-                    })
-                })
+                .query_map((author, search_pattern, limit as i64), app_from_row)
                 .map_err(|e| e.to_string())?;
 
             rows.collect::<ic_rusqlite::Result<Vec<_>>>()
                 .map_err(|e| e.to_string())
         })
     }
+
+    /// The app whose URL has the same canonical form as `url`, see
+    /// [`canonical_url::canonicalize`].
+    pub fn find_by_url(url: &str) -> Result<Option<App>, String> {
+        let canonical = canonical_url::canonicalize(url)?;
+        let id = with_connection(|conn| {
+            match conn.query_row(
+                "SELECT id FROM app WHERE canonical_url = ?1",
+                (&canonical,),
+                |row| row.get::<_, i64>(0),
+            ) {
+                Ok(id) => Ok(Some(id)),
+                Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+                Err(e) => Err(e.to_string()),
+            }
+        })?;
+        id.map(Self::get_by_id).transpose()
+    }
+
+    /// Point the app's screenshot at `image_id`.
+    pub fn set_image_id(id: i64, image_id: &str) -> Result<(), String> {
+        with_connection(|conn| {
+            let updated = conn
+                .execute(
+                    "UPDATE app SET image_id = ?2, updated_at = strftime('%s','now') WHERE id = ?1",
+                    (id, image_id),
+                )
+                .map_err(|e| e.to_string())?;
+            if updated == 0 {
+                return Err(format!("No app {id}"));
+            }
+            Ok(())
+        })
+    }
+}
+
+fn app_from_row(row: &Row) -> ic_rusqlite::Result<App> {
+    Ok(App {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        canister_id: row.get(2)?,
+        title: row.get(3)?,
+        description: row.get(4)?,
+        image_id: row.get(5)?,
+        author_name: row.get(6)?,
+        app_name: row.get(7)?,
+        social_post_url: row.get(8)?,
+        created_at: row.get(9)?,
+        updated_at: row.get(10)?,
+        blurhash: row.get(11)?,
+        dominant_color: row.get(12)?,
+        accent_color: row.get(13)?,
+        screenshot_flags: split_flags(row.get(14)?),
+        alive: row.get(15)?,
+        last_seen_alive_at: row.get(16)?,
+        unreachable_since: row.get(17)?,
+    })
+}
+
+/// Quality flags are stored space-separated.
+fn split_flags(flags: Option<String>) -> Vec<String> {
+    flags
+        .map(|flags| flags.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default()
 }
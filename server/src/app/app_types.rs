@@ -13,4 +13,20 @@ pub struct App {
     pub social_post_url: Option<String>,
     pub created_at: i64,
     pub updated_at: i64,
+    /// Placeholder for the screenshot while it loads.
+    pub blurhash: Option<String>,
+    /// Most common colour of the screenshot, as `#rrggbb`.
+    pub dominant_color: Option<String>,
+    /// A saturated colour from the screenshot, or the dominant colour if it
+    /// has none, as `#rrggbb`.
+    pub accent_color: Option<String>,
+    /// Why the screenshot looks broken, if it does: `blank`, `uniform`
+    /// and/or `shared` (the same picture as several other apps).
+    pub screenshot_flags: Vec<String>,
+    /// Whether the app's URL responded when last checked, `None` until the
+    /// first check.
+    pub alive: Option<bool>,
+    pub last_seen_alive_at: Option<i64>,
+    /// When checks started failing, if they still are.
+    pub unreachable_since: Option<i64>,
 }
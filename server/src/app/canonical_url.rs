@@ -0,0 +1,302 @@
+use candid::CandidType;
+use ic_rusqlite::with_connection;
+
+use crate::jobs::{Budget, Step};
+
+/// Query parameters added by ad and social platforms that never change which
+/// page is served. Matched case-insensitively; `utm_*` is matched by prefix.
+const TRACKING_PARAMS: &[&str] = &[
+    "dclid", "fbclid", "gbraid", "gclid", "igshid", "mc_cid", "mc_eid", "msclkid", "ref_src",
+    "twclid", "wbraid", "yclid",
+];
+
+/// An app whose URL is the same page as an earlier app's, see [`backfill`].
+#[derive(CandidType)]
+pub struct DuplicateUrl {
+    pub app_id: i64,
+    pub url: String,
+    pub duplicate_of: i64,
+    pub duplicate_of_url: String,
+    pub canonical_url: String,
+}
+
+/// The canonical form of an app URL, used to tell whether two URLs are the
+/// same app: `https`, a lowercase host without the default port, no trailing
+/// slash, no tracking parameters and no fragment. A `www.` prefix is kept,
+/// as it can be a different site.
+///
+/// `https://MyL-f15.caffeine.xyz/?utm_source=x#top` becomes
+/// `https://myl-f15.caffeine.xyz`. The indexer's `canonicalizeUrl` must
+/// agree; both are tested against `canonical_url_tests.json`.
+pub fn canonicalize(url: &str) -> Result<String, String> {
+    let url = url.trim();
+    let rest = url
+        .split_once("://")
+        .filter(|(scheme, _)| {
+            scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http")
+        })
+        .map(|(_, rest)| rest)
+        .ok_or_else(|| format!("Not an http(s) URL: {url}"))?;
+    let rest = rest.split('#').next().unwrap_or_default();
+
+    let authority_end = rest.find(['/', '?']).unwrap_or(rest.len());
+    let (authority, rest) = rest.split_at(authority_end);
+    // Drop any credentials, they don't pick a different app
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host
+        .strip_suffix(":443")
+        .or_else(|| host.strip_suffix(":80"))
+        .unwrap_or(host)
+        .to_ascii_lowercase();
+    if host.is_empty() {
+        return Err(format!("URL has no host: {url}"));
+    }
+
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let path = path.trim_end_matches('/');
+    let query: Vec<&str> = query
+        .split('&')
+        .filter(|param| !param.is_empty() && !is_tracking_param(param))
+        .collect();
+
+    let mut canonical = format!("https://{host}{path}");
+    if !query.is_empty() {
+        canonical.push('?');
+        canonical.push_str(&query.join("&"));
+    }
+    Ok(canonical)
+}
+
+fn is_tracking_param(param: &str) -> bool {
+    let name = param
+        .split('=')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    name.starts_with("utm_") || TRACKING_PARAMS.contains(&name.as_str())
+}
+
+/// Fill in the canonical URL of the apps after `cursor`, an app ID, that
+/// lack one, in submission order, until the budget runs out. An app whose
+/// canonical URL already belongs to another app is left without one and
+/// recorded as a duplicate of it, and the duplicate is logged. The indexer
+/// now writes canonical URLs into the seed file, so this only fills in apps
+/// seeded before it did. Runs after every deploy as the
+/// [`crate::jobs::BACKFILL_CANONICAL_URLS`] job.
+pub fn backfill(cursor: Option<&str>, budget: &Budget) -> Result<Step, String> {
+    let after = match cursor {
+        Some(cursor) => cursor
+            .parse::<i64>()
+            .map_err(|_| format!("Invalid cursor {cursor}"))?,
+        None => i64::MIN,
+    };
+    let step = with_connection(|conn| {
+        let apps = {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT id, url FROM app
+                     WHERE canonical_url IS NULL
+                       AND id NOT IN (SELECT app_id FROM app_url_duplicate)
+                       AND id > ?1
+                     ORDER BY id",
+                )
+                .map_err(|e| e.to_string())?;
+            let rows = stmt
+                .query_map((after,), |row| {
+                    Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+                })
+                .map_err(|e| e.to_string())?;
+            rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+                .map_err(|e| e.to_string())?
+        };
+
+        for (id, url) in apps {
+            let canonical = match canonicalize(&url) {
+                Ok(canonical) => canonical,
+                Err(e) => {
+                    ic_cdk::println!("Cannot canonicalize the URL of app {id}: {e}");
+                    continue;
+                }
+            };
+            match conn.query_row(
+                "SELECT id FROM app WHERE canonical_url = ?1",
+                (&canonical,),
+                |row| row.get::<_, i64>(0),
+            ) {
+                Ok(original) => {
+                    ic_cdk::println!("App {id} ({url}) duplicates app {original}: {canonical}");
+                    conn.execute(
+                        "INSERT OR IGNORE INTO app_url_duplicate (app_id, duplicate_of, canonical_url)
+                         VALUES (?1, ?2, ?3)",
+                        (id, original, &canonical),
+                    )
+                    .map_err(|e| e.to_string())?;
+                }
+                Err(ic_rusqlite::Error::QueryReturnedNoRows) => {
+                    conn.execute(
+                        "UPDATE app SET canonical_url = ?2 WHERE id = ?1",
+                        (id, &canonical),
+                    )
+                    .map_err(|e| e.to_string())?;
+                }
+                Err(e) => return Err(e.to_string()),
+            }
+            if budget.exhausted() {
+                return Ok(Step::Continue(id.to_string()));
+            }
+        }
+        Ok(Step::Done)
+    })?;
+    if matches!(step, Step::Done) {
+        crate::invalidate_app_data_routes();
+    }
+    Ok(step)
+}
+
+/// Every app found by [`backfill`] to duplicate an earlier one.
+pub fn duplicates() -> Result<Vec<DuplicateUrl>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT d.app_id, a.url, d.duplicate_of, o.url, d.canonical_url
+                 FROM app_url_duplicate d
+                 JOIN app a ON a.id = d.app_id
+                 JOIN app o ON o.id = d.duplicate_of
+                 ORDER BY d.app_id",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((), |row| {
+                Ok(DuplicateUrl {
+                    app_id: row.get(0)?,
+                    url: row.get(1)?,
+                    duplicate_of: row.get(2)?,
+                    duplicate_of_url: row.get(3)?,
+                    canonical_url: row.get(4)?,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::app::app_types::App;
+    use crate::app::AppManager;
+
+    /// Shared with the indexer's tests, so both implementations agree.
+    const VECTORS: &str = include_str!("canonical_url_tests.json");
+
+    #[derive(Deserialize)]
+    struct Vector {
+        url: String,
+        canonical: Option<String>,
+        #[serde(default)]
+        error: bool,
+    }
+
+    #[test]
+    fn shared_vectors() {
+        let vectors: Vec<Vector> = serde_json::from_str(VECTORS).unwrap();
+        for vector in vectors {
+            let result = canonicalize(&vector.url);
+            if vector.error {
+                assert!(result.is_err(), "{} gave {result:?}", vector.url);
+            } else {
+                assert_eq!(result.ok(), vector.canonical, "{}", vector.url);
+            }
+        }
+    }
+
+    #[test]
+    fn default_ports_are_dropped() {
+        assert_eq!(
+            canonicalize("https://a.io:443/x").unwrap(),
+            "https://a.io/x"
+        );
+        assert_eq!(canonicalize("http://a.io:80").unwrap(), "https://a.io");
+        assert_eq!(
+            canonicalize("https://a.io:8443").unwrap(),
+            "https://a.io:8443"
+        );
+    }
+
+    #[test]
+    fn trailing_slashes_are_dropped() {
+        assert_eq!(canonicalize("https://a.io/").unwrap(), "https://a.io");
+        assert_eq!(
+            canonicalize("https://a.io/x//?q=1").unwrap(),
+            "https://a.io/x?q=1"
+        );
+    }
+
+    #[test]
+    fn only_the_host_is_lowercased() {
+        assert_eq!(
+            canonicalize("HTTP://A.IO/Route").unwrap(),
+            "https://a.io/Route"
+        );
+    }
+
+    #[test]
+    fn www_is_kept() {
+        assert_eq!(
+            canonicalize("https://WWW.a.io/").unwrap(),
+            "https://www.a.io"
+        );
+        assert_ne!(
+            canonicalize("https://www.a.io").unwrap(),
+            canonicalize("https://a.io").unwrap()
+        );
+    }
+
+    #[test]
+    fn seeded_apps_have_canonical_urls() {
+        crate::use_test_database();
+        let apps: Vec<(String, Option<String>)> = with_connection(|conn| {
+            let mut stmt = conn.prepare("SELECT url, canonical_url FROM app").unwrap();
+            let rows = stmt
+                .query_map((), |row| Ok((row.get(0)?, row.get(1)?)))
+                .unwrap();
+            rows.collect::<Result<_, _>>().unwrap()
+        });
+        assert!(!apps.is_empty());
+        for (url, canonical_url) in apps {
+            assert_eq!(canonical_url, Some(canonicalize(&url).unwrap()), "{url}");
+        }
+    }
+
+    #[test]
+    fn duplicates_are_not_listed() {
+        crate::use_test_database();
+        let app = AppManager::list().unwrap().remove(1);
+        let author = app.author_name.clone().unwrap();
+        with_connection(|conn| {
+            conn.execute(
+                "UPDATE app SET canonical_url = NULL WHERE id = ?1",
+                (app.id,),
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO app_url_duplicate (app_id, duplicate_of, canonical_url)
+                 VALUES (?1, (SELECT MIN(id) FROM app), 'https://a.io')",
+                (app.id,),
+            )
+            .unwrap();
+        });
+
+        let listed = |apps: Vec<App>| apps.iter().any(|listed| listed.id == app.id);
+        assert!(!listed(AppManager::list().unwrap()));
+        assert!(!listed(AppManager::search(&app.title).unwrap()));
+        assert!(!listed(AppManager::list_by_author(&author).unwrap()));
+        assert!(!listed(AppManager::list_newest(None, None, 1000).unwrap()));
+        assert_eq!(AppManager::get_by_id(app.id).unwrap().id, app.id);
+    }
+}
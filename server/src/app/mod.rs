@@ -1,4 +1,5 @@
 pub mod app_manager;
 pub mod app_types;
+pub mod canonical_url;
 
 pub use app_manager::AppManager;
@@ -0,0 +1,102 @@
+use std::cell::RefCell;
+
+use candid::{CandidType, Deserialize};
+use ic_rusqlite::with_connection;
+
+const DEFAULT_CANONICAL_ORIGIN: &str = "https://january-promptathon.xyz";
+
+const CANONICAL_ORIGIN_KEY: &str = "canonical_origin";
+const TWITTER_SITE_KEY: &str = "twitter_site";
+
+/// Optional canister init/upgrade arguments. Values that are left out keep
+/// their previously persisted setting.
+#[derive(CandidType, Deserialize, Default)]
+pub struct InitArgs {
+    /// Public origin used for canonical URLs and absolute links, e.g.
+    /// `https://january-promptathon.xyz`.
+    pub canonical_origin: Option<String>,
+    /// The site's X/Twitter handle, emitted as `twitter:site`.
+    pub twitter_site: Option<String>,
+}
+
+struct Config {
+    canonical_origin: String,
+    twitter_site: Option<String>,
+}
+
+thread_local! {
+    static CONFIG: RefCell<Config> = RefCell::new(Config {
+        canonical_origin: DEFAULT_CANONICAL_ORIGIN.to_string(),
+        twitter_site: None,
+    });
+}
+
+/// Persist any settings passed as init/upgrade arguments, then load the
+/// settings into memory. Must run after migrations.
+pub fn init(args: Option<InitArgs>) -> Result<(), String> {
+    let args = args.unwrap_or_default();
+
+    if let Some(origin) = args.canonical_origin {
+        let origin = origin.trim().trim_end_matches('/');
+        if !(origin.starts_with("https://") || origin.starts_with("http://"))
+            || origin
+                .split_once("://")
+                .is_some_and(|(_, host)| host.is_empty() || host.contains('/'))
+        {
+            return Err(format!(
+                "canonical_origin must be a scheme and host without a path, got {origin:?}"
+            ));
+        }
+        set(CANONICAL_ORIGIN_KEY, origin)?;
+    }
+
+    if let Some(handle) = args.twitter_site {
+        let handle = handle.trim().trim_start_matches('@');
+        set(TWITTER_SITE_KEY, &format!("@{handle}"))?;
+    }
+
+    let canonical_origin =
+        get(CANONICAL_ORIGIN_KEY)?.unwrap_or_else(|| DEFAULT_CANONICAL_ORIGIN.to_string());
+    let twitter_site = get(TWITTER_SITE_KEY)?;
+
+    CONFIG.with_borrow_mut(|config| {
+        config.canonical_origin = canonical_origin;
+        config.twitter_site = twitter_site;
+    });
+
+    Ok(())
+}
+
+/// The public origin of the site, without a trailing slash.
+pub fn canonical_origin() -> String {
+    CONFIG.with_borrow(|config| config.canonical_origin.clone())
+}
+
+/// The site's X/Twitter handle including the leading `@`, if configured.
+pub fn twitter_site() -> Option<String> {
+    CONFIG.with_borrow(|config| config.twitter_site.clone())
+}
+
+fn get(key: &str) -> Result<Option<String>, String> {
+    with_connection(|conn| {
+        match conn.query_row("SELECT value FROM setting WHERE key = ?1", (key,), |row| {
+            row.get(0)
+        }) {
+            Ok(value) => Ok(Some(value)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+fn set(key: &str, value: &str) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT INTO setting (key, value) VALUES (?1, ?2)
+             ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+            (key, value),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
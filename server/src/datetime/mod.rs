@@ -0,0 +1,51 @@
+/// Break a unix timestamp (seconds) into UTC calendar fields:
+/// `(year, month, day, hour, minute, second)`.
+///
+/// The canister has no timezone database and pulling in `chrono` for a few
+/// timestamp formats is not worth the WASM size, so this uses the
+/// days-to-civil algorithm from Howard Hinnant's date library.
+fn to_civil(secs: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (
+        year,
+        month,
+        day,
+        (secs_of_day / 3_600) as u32,
+        (secs_of_day % 3_600 / 60) as u32,
+        (secs_of_day % 60) as u32,
+    )
+}
+
+/// Format a unix timestamp (seconds) as an RFC 2822 date in UTC, as used by
+/// RSS, e.g. `Fri, 13 Feb 2026 13:28:46 +0000`.
+pub fn format_rfc2822(secs: i64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let (year, month, day, hour, minute, second) = to_civil(secs);
+    // 1970-01-01 was a Thursday
+    let weekday = WEEKDAYS[secs.div_euclid(86_400).rem_euclid(7) as usize];
+    let month = MONTHS[month as usize - 1];
+    format!("{weekday}, {day:02} {month} {year:04} {hour:02}:{minute:02}:{second:02} +0000")
+}
+
+/// Format a unix timestamp (seconds) as an RFC 3339 / W3C datetime in UTC,
+/// e.g. `2026-02-13T13:28:46Z`.
+pub fn format_rfc3339(secs: i64) -> String {
+    let (year, month, day, hour, minute, second) = to_civil(secs);
+    format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+}
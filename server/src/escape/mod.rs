@@ -0,0 +1,91 @@
+/// Escape special XML characters in text content and attribute values.
+pub fn xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Percent-encode a string for use as a URL query parameter value or path
+/// segment. Only the RFC 3986 unreserved characters are left as-is.
+pub fn url_component(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+/// Make serialized JSON safe to embed inside a `<script>` element.
+///
+/// Characters that could close the script element, start an HTML comment or
+/// entity, or terminate a JavaScript string literal are replaced with their
+/// `\uXXXX` escapes, which JSON parsers decode back to the same value.
+pub fn json_for_script(json: &str) -> String {
+    let mut out = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => out.push_str("\\u003c"),
+            '>' => out.push_str("\\u003e"),
+            '&' => out.push_str("\\u0026"),
+            '\u{2028}' => out.push_str("\\u2028"),
+            '\u{2029}' => out.push_str("\\u2029"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Neutralize a URL before it is placed in an `href` or `src` attribute.
+///
+/// HTML escaping alone does not stop `javascript:` or `data:` URLs, so only
+/// `http(s)` and site-relative URLs are passed through; anything else is
+/// replaced with `#`. Protocol-relative URLs (`//host`, or `/\host`, which
+/// browsers read the same way) are not site-relative and are replaced too.
+/// The result still needs HTML escaping.
+pub fn href(url: &str) -> &str {
+    let has_prefix = |prefix: &str| {
+        url.get(..prefix.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+    };
+    let site_relative = url.starts_with('/') && !url.starts_with("//") && !url.starts_with("/\\");
+    if has_prefix("https://") || has_prefix("http://") || site_relative {
+        url
+    } else {
+        "#"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn href_passes_http_and_site_relative_urls() {
+        assert_eq!(href("https://example.com/a?b"), "https://example.com/a?b");
+        assert_eq!(href("HTTP://example.com"), "HTTP://example.com");
+        assert_eq!(href("/app/1"), "/app/1");
+    }
+
+    #[test]
+    fn href_rejects_other_schemes_and_protocol_relative_urls() {
+        for url in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            " javascript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "//evil.example/",
+            "/\\evil.example/",
+            "evil.example",
+            "",
+        ] {
+            assert_eq!(href(url), "#", "{url}");
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use ic_asset_router::{HttpResponse, StatusCode};
+use serde::Serialize;
+
+use crate::app::app_types::App;
+use crate::app::AppManager;
+use crate::images::{self, ImageFormat};
+use crate::{config, datetime, escape, ssr};
+
+/// Registered in the shared XML environment, see [`ssr::render_xml`].
+pub(crate) const TEMPLATES: &[(&str, &str)] = &[
+    ("rss.xml", include_str!("includes/rss.xml")),
+    ("atom.xml", include_str!("includes/atom.xml")),
+];
+
+/// Maximum number of entries included in a feed.
+const MAX_FEED_ITEMS: usize = 50;
+
+const FEED_TITLE: &str = "Caffeine January Promptathon Showcase";
+const FEED_DESCRIPTION: &str = "Newly published apps from the January Caffeine promptathon.";
+
+#[derive(Clone, Copy)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    fn path(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "/feed.xml",
+            FeedFormat::Atom => "/atom.xml",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml",
+            FeedFormat::Atom => "application/atom+xml",
+        }
+    }
+
+    fn format_date(self, secs: i64) -> String {
+        match self {
+            FeedFormat::Rss => datetime::format_rfc2822(secs),
+            FeedFormat::Atom => datetime::format_rfc3339(secs),
+        }
+    }
+}
+
+/// Optional feed filters, taken from the `author` and `q` query parameters.
+#[derive(Default)]
+pub struct FeedFilter<'a> {
+    pub author: Option<&'a str>,
+    pub query: Option<&'a str>,
+}
+
+impl<'a> FeedFilter<'a> {
+    /// Build the filter from the raw query parameters. Blank values are
+    /// ignored.
+    pub fn from_params(author: Option<&'a str>, query: Option<&'a str>) -> Self {
+        let param = |value: Option<&'a str>| value.map(str::trim).filter(|v| !v.is_empty());
+        FeedFilter {
+            author: param(author),
+            query: param(query),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FeedImage {
+    url: String,
+    length: usize,
+}
+
+#[derive(Serialize)]
+struct FeedItem {
+    title: String,
+    link: String,
+    description: String,
+    author: Option<String>,
+    published: String,
+    updated: String,
+    image: Option<FeedImage>,
+}
+
+/// Serve the newest apps matching `filter` as a feed, shared by the
+/// `/feed.xml` and `/atom.xml` routes.
+pub fn response(format: FeedFormat, filter: &FeedFilter) -> HttpResponse<'static> {
+    let apps =
+        AppManager::list_newest(filter.author, filter.query, MAX_FEED_ITEMS).unwrap_or_default();
+
+    match render(format, &apps, filter) {
+        Ok(xml) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), format.content_type().into())])
+            .with_status_code(StatusCode::OK)
+            .with_body(Cow::Owned(xml.into_bytes()))
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(
+                format!("Feed generation failed: {e}").into_bytes(),
+            ))
+            .build(),
+    }
+}
+
+/// Render a feed of `apps`, which are expected to be ordered newest first.
+fn render(format: FeedFormat, apps: &[App], filter: &FeedFilter) -> Result<String, String> {
+    let origin = config::canonical_origin();
+
+    let title = match (filter.author, filter.query) {
+        (Some(author), _) => format!("{FEED_TITLE}: apps by {author}"),
+        (None, Some(query)) => format!("{FEED_TITLE}: apps matching \u{201c}{query}\u{201d}"),
+        (None, None) => FEED_TITLE.to_string(),
+    };
+
+    let mut query_string = Vec::new();
+    if let Some(author) = filter.author {
+        query_string.push(format!("author={}", escape::url_component(author)));
+    }
+    if let Some(query) = filter.query {
+        query_string.push(format!("q={}", escape::url_component(query)));
+    }
+    let self_url = if query_string.is_empty() {
+        format!("{origin}{}", format.path())
+    } else {
+        format!("{origin}{}?{}", format.path(), query_string.join("&"))
+    };
+
+    let items: Vec<FeedItem> = apps
+        .iter()
+        .map(|app| FeedItem {
+            title: escape::xml(app.app_name.as_deref().unwrap_or(&app.title)),
+            link: escape::xml(&format!("{origin}/app/{}", app.id)),
+            description: escape::xml(&app.description),
+            author: app.author_name.as_deref().map(escape::xml),
+            published: format.format_date(app.created_at),
+            updated: format.format_date(app.updated_at),
+            image: app.image_id.as_deref().map(|image_id| FeedImage {
+                url: escape::xml(&format!("{origin}/images/{image_id}_1500.jpg")),
+                length: image_length(image_id),
+            }),
+        })
+        .collect();
+
+    let updated = apps.iter().map(|app| app.updated_at).max();
+
+    // Values are XML-escaped above
+    let template = match format {
+        FeedFormat::Rss => "rss.xml",
+        FeedFormat::Atom => "atom.xml",
+    };
+    let ctx = minijinja::context! {
+        title => escape::xml(&title),
+        description => FEED_DESCRIPTION,
+        site_url => escape::xml(&format!("{origin}/")),
+        self_url => escape::xml(&self_url),
+        // Atom requires a feed-level <updated>, so fall back to the epoch
+        // for empty feeds.
+        updated => match format {
+            FeedFormat::Rss => updated.map(|u| format.format_date(u)),
+            FeedFormat::Atom => Some(format.format_date(updated.unwrap_or(0))),
+        },
+        items => items,
+    };
+    ssr::render_xml(template, &ctx)
+}
+
+/// Size in bytes of the 1500px screenshot, used for the enclosure `length`
+/// attribute, as recorded in the `image` tables. Returns 0 (unknown) if it
+/// has not been rendered yet.
+fn image_length(image_id: &str) -> usize {
+    images::resized_len(image_id, 1500, ImageFormat::Jpeg).unwrap_or(0)
+}
@@ -0,0 +1,273 @@
+//! Frontend assets uploaded at runtime, served in place of the compiled-in
+//! `dist` files at the same paths. Uploads work like the asset canister's
+//! batches: create a batch, upload content in chunks, then commit a list of
+//! assets naming their chunks and SHA-256 hashes.
+
+use std::collections::HashSet;
+
+use candid::{CandidType, Deserialize};
+use ic_rusqlite::with_connection;
+use include_dir::{Dir, DirEntry, File};
+use sha2::{Digest, Sha256};
+
+use crate::ssr;
+
+/// Limit on the total size of a batch.
+const MAX_BATCH_BYTES: usize = 64 * 1024 * 1024;
+/// Batches that are never committed are dropped after a day.
+const BATCH_TTL_SECONDS: i64 = 24 * 60 * 60;
+
+/// One asset of a batch being committed.
+#[derive(CandidType, Deserialize)]
+pub struct CommitAsset {
+    /// Absolute path the asset is served at, e.g. `/assets/index-3f9c.js`.
+    pub path: String,
+    /// Chunks returned by `upload_chunk`, in order.
+    pub chunk_ids: Vec<u64>,
+    /// SHA-256 of the joined chunks.
+    pub sha256: Vec<u8>,
+}
+
+/// Start a batch and return its ID. Also drops stale batches.
+pub fn create_batch() -> Result<u64, String> {
+    with_connection(|conn| {
+        conn.execute(
+            "DELETE FROM asset_chunk WHERE batch_id IN
+             (SELECT id FROM asset_batch WHERE created_at < strftime('%s','now') - ?1)",
+            (BATCH_TTL_SECONDS,),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "DELETE FROM asset_batch WHERE created_at < strftime('%s','now') - ?1",
+            (BATCH_TTL_SECONDS,),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute("INSERT INTO asset_batch DEFAULT VALUES", ())
+            .map_err(|e| e.to_string())?;
+        Ok(conn.last_insert_rowid() as u64)
+    })
+}
+
+/// Store a chunk of content for the batch and return its ID.
+pub fn upload_chunk(batch_id: u64, content: &[u8]) -> Result<u64, String> {
+    with_connection(|conn| {
+        let size: Option<usize> = conn
+            .query_row(
+                "SELECT (SELECT COALESCE(SUM(length(data)), 0) FROM asset_chunk WHERE batch_id = ?1)
+                 FROM asset_batch WHERE id = ?1",
+                (batch_id,),
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|e| match e {
+                ic_rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                e => Err(e.to_string()),
+            })?;
+        let Some(size) = size else {
+            return Err(format!("No batch {batch_id}"));
+        };
+        if size + content.len() > MAX_BATCH_BYTES {
+            return Err(format!("Batch is larger than {MAX_BATCH_BYTES} bytes"));
+        }
+        conn.execute(
+            "INSERT INTO asset_chunk (batch_id, data) VALUES (?1, ?2)",
+            (batch_id, content),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(conn.last_insert_rowid() as u64)
+    })
+}
+
+/// Replace the uploaded frontend with `assets`, all at once. Every asset's
+/// hash is checked, and an uploaded `index.html` must work as the page
+/// shell, before anything changes. Paths left out fall back to the
+/// compiled-in files. The batch is discarded.
+pub fn commit_batch(batch_id: u64, assets: Vec<CommitAsset>) -> Result<(), String> {
+    let mut files = Vec::with_capacity(assets.len());
+    let mut paths = HashSet::new();
+    for asset in assets {
+        if !asset.path.starts_with('/')
+            || asset.path.ends_with('/')
+            || asset.path.split('/').any(|segment| segment == "..")
+        {
+            return Err(format!("Invalid asset path {}", asset.path));
+        }
+        if !paths.insert(asset.path.clone()) {
+            return Err(format!("Duplicate asset path {}", asset.path));
+        }
+
+        let data = join_chunks(batch_id, &asset.chunk_ids)?;
+        if Sha256::digest(&data).as_slice() != asset.sha256.as_slice() {
+            return Err(format!("SHA-256 mismatch for {}", asset.path));
+        }
+        if asset.path == "/index.html" {
+            let shell = std::str::from_utf8(&data)
+                .map_err(|_| "index.html is not valid UTF-8".to_string())?;
+            ssr::check_shell(shell)?;
+        }
+        files.push((asset.path, data));
+    }
+
+    let previous = store(&files)?;
+    with_connection(|conn| {
+        conn.execute("DELETE FROM asset_chunk WHERE batch_id = ?1", (batch_id,))
+            .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM asset_batch WHERE id = ?1", (batch_id,))
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    })?;
+
+    let dropped: Vec<String> = previous
+        .into_iter()
+        .filter(|path| !paths.contains(path))
+        .collect();
+    restore_compiled(&dropped);
+    certify(files);
+
+    // Every page embeds the shell, and may link assets that just changed
+    ssr::reset();
+    ic_asset_router::invalidate_all_dynamic();
+    Ok(())
+}
+
+/// Go back to serving the compiled-in frontend.
+pub fn reset() -> Result<(), String> {
+    let previous = store(&[])?;
+    restore_compiled(&previous);
+    ssr::reset();
+    ic_asset_router::invalidate_all_dynamic();
+    Ok(())
+}
+
+/// Certify the uploaded assets over the compiled-in ones. Call after the
+/// router is set up in `init` and `post_upgrade`.
+pub fn certify_uploaded() -> Result<(), String> {
+    let files = with_connection(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT path, data FROM frontend_asset")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map((), |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| e.to_string())?;
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })?;
+    if !files.is_empty() {
+        certify(files);
+    }
+    Ok(())
+}
+
+/// The uploaded `index.html`, if there is one.
+pub fn shell() -> Result<Option<String>, String> {
+    with_connection(|conn| {
+        match conn.query_row(
+            "SELECT data FROM frontend_asset WHERE path = '/index.html'",
+            (),
+            |row| row.get::<_, Vec<u8>>(0),
+        ) {
+            Ok(data) => String::from_utf8(data)
+                .map(Some)
+                .map_err(|_| "index.html is not valid UTF-8".to_string()),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+/// Replace the stored assets with `files`, returning the paths stored
+/// before.
+fn store(files: &[(String, Vec<u8>)]) -> Result<Vec<String>, String> {
+    with_connection(|conn| {
+        let previous = {
+            let mut stmt = conn
+                .prepare("SELECT path FROM frontend_asset")
+                .map_err(|e| e.to_string())?;
+            let rows = stmt
+                .query_map((), |row| row.get(0))
+                .map_err(|e| e.to_string())?;
+            rows.collect::<ic_rusqlite::Result<Vec<String>>>()
+                .map_err(|e| e.to_string())?
+        };
+
+        conn.execute("DELETE FROM frontend_asset", ())
+            .map_err(|e| e.to_string())?;
+        for (path, data) in files {
+            conn.execute(
+                "INSERT INTO frontend_asset (path, data) VALUES (?1, ?2)",
+                (path, data),
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(previous)
+    })
+}
+
+fn join_chunks(batch_id: u64, chunk_ids: &[u64]) -> Result<Vec<u8>, String> {
+    with_connection(|conn| {
+        let mut data = Vec::new();
+        for chunk_id in chunk_ids {
+            let chunk: Vec<u8> = conn
+                .query_row(
+                    "SELECT data FROM asset_chunk WHERE id = ?1 AND batch_id = ?2",
+                    (chunk_id, batch_id),
+                    |row| row.get(0),
+                )
+                .map_err(|_| format!("No chunk {chunk_id} in batch {batch_id}"))?;
+            data.extend_from_slice(&chunk);
+        }
+        Ok(data)
+    })
+}
+
+/// Certify `files` with the same headers and aliases as compiled-in assets.
+///
+/// The router only certifies a `'static` directory, so the files are leaked.
+/// That keeps a copy of each commit's files on the heap, which is only freed
+/// by the next upgrade; commits are rare and limited to [`MAX_BATCH_BYTES`].
+fn certify(files: Vec<(String, Vec<u8>)>) {
+    let has_shell = files.iter().any(|(path, _)| path == "/index.html");
+    let entries: Vec<DirEntry<'static>> = files
+        .into_iter()
+        .map(|(path, data)| {
+            let path: &'static str = Box::leak(path.into_boxed_str());
+            let data: &'static [u8] = Box::leak(data.into_boxed_slice());
+            DirEntry::File(File::new(&path[1..], data))
+        })
+        .collect();
+    certify_entries(entries);
+
+    // As at setup, `/` is rendered by its route, not served from the shell
+    if has_shell {
+        ic_asset_router::delete_assets(vec!["/"]);
+    }
+}
+
+/// Serve the compiled-in file at each of `paths` again, or nothing where
+/// there is none.
+fn restore_compiled(paths: &[String]) {
+    let (compiled, missing): (Vec<_>, Vec<_>) = paths
+        .iter()
+        .partition(|path| crate::ASSETS_DIR.get_file(&path[1..]).is_some());
+
+    ic_asset_router::delete_assets(missing.iter().map(|path| path.as_str()).collect());
+    let entries: Vec<DirEntry<'static>> = compiled
+        .iter()
+        .filter_map(|path| crate::ASSETS_DIR.get_file(&path[1..]))
+        .map(|file| DirEntry::File(file.clone()))
+        .collect();
+    certify_entries(entries);
+    if paths.iter().any(|path| path == "/index.html") {
+        ic_asset_router::delete_assets(vec!["/"]);
+    }
+}
+
+/// Certify `entries` as a directory at the root. The list itself is leaked
+/// along with the files, as the router takes a `'static` directory.
+fn certify_entries(entries: Vec<DirEntry<'static>>) {
+    if entries.is_empty() {
+        return;
+    }
+    let entries: &'static [DirEntry<'static>] = Box::leak(entries.into_boxed_slice());
+    ic_asset_router::assets::certify_assets(&Dir::new("", entries));
+}
@@ -0,0 +1,222 @@
+use super::SourceType;
+
+/// Remove EXIF, XMP, text and other metadata from an uploaded image,
+/// leaving the image data and colour information untouched. Screenshots
+/// have no orientation to preserve, so EXIF is dropped wholesale.
+pub(super) fn strip(data: &[u8], source_type: SourceType) -> Result<Vec<u8>, String> {
+    match source_type {
+        SourceType::Jpeg => strip_jpeg(data),
+        SourceType::Png => strip_png(data),
+        SourceType::WebP => strip_webp(data),
+    }
+}
+
+/// Drop APP1 (EXIF, XMP), APP13 (IPTC) and comment segments. Everything
+/// from the first scan on is copied as is.
+fn strip_jpeg(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..2]);
+    let mut pos = 2;
+    loop {
+        if pos + 4 > data.len() || data[pos] != 0xFF {
+            return Err("Malformed JPEG".to_string());
+        }
+        let marker = data[pos + 1];
+        if marker == 0xFF {
+            // Fill byte before a marker
+            pos += 1;
+            continue;
+        }
+        if marker == 0xDA || marker == 0xD9 {
+            out.extend_from_slice(&data[pos..]);
+            return Ok(out);
+        }
+        let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = chunk_end(data, pos, &[2, len])
+            .filter(|_| len >= 2)
+            .ok_or("Malformed JPEG")?;
+        if !matches!(marker, 0xE1 | 0xED | 0xFE) {
+            out.extend_from_slice(&data[pos..end]);
+        }
+        pos = end;
+    }
+}
+
+/// Drop EXIF, text and timestamp chunks.
+fn strip_png(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..8]);
+    let mut pos = 8;
+    while pos < data.len() {
+        if pos + 12 > data.len() {
+            return Err("Malformed PNG".to_string());
+        }
+        let len = u32::from_be_bytes(data[pos..pos + 4].try_into().unwrap()) as usize;
+        let chunk_type = &data[pos + 4..pos + 8];
+        // Length, type and CRC take 12 bytes around the chunk data
+        let end = chunk_end(data, pos, &[12, len]).ok_or("Malformed PNG")?;
+        if !matches!(chunk_type, b"eXIf" | b"tEXt" | b"zTXt" | b"iTXt" | b"tIME") {
+            out.extend_from_slice(&data[pos..end]);
+        }
+        if chunk_type == b"IEND" {
+            break;
+        }
+        pos = end;
+    }
+    Ok(out)
+}
+
+/// Drop the EXIF and XMP chunks, clear their flags in the extended header
+/// and fix up the RIFF size.
+fn strip_webp(data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..12]);
+    let mut pos = 12;
+    while pos < data.len() {
+        if pos + 8 > data.len() {
+            return Err("Malformed WebP".to_string());
+        }
+        let fourcc = &data[pos..pos + 4];
+        let len = u32::from_le_bytes(data[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        // Chunks are padded to an even size
+        let end = chunk_end(data, pos, &[8, len, len % 2]).ok_or("Malformed WebP")?;
+        if !matches!(fourcc, b"EXIF" | b"XMP ") {
+            let start = out.len();
+            out.extend_from_slice(&data[pos..end]);
+            if fourcc == b"VP8X" && len > 0 {
+                out[start + 8] &= !(0x08 | 0x04);
+            }
+        }
+        pos = end;
+    }
+    let riff_size = u32::try_from(out.len() - 8).map_err(|_| "WebP too large".to_string())?;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(out)
+}
+
+/// Where a chunk starting at `pos` and made up of `parts` bytes ends, or
+/// `None` if the sum overflows or runs past the end of `data`. Lengths come
+/// from the file, so on wasm32 they can overflow `usize`.
+fn chunk_end(data: &[u8], pos: usize, parts: &[usize]) -> Option<usize> {
+    parts
+        .iter()
+        .try_fold(pos, |end, &part| end.checked_add(part))
+        .filter(|&end| end <= data.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    fn png_chunk(chunk_type: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(chunk_type);
+        chunk.extend_from_slice(data);
+        chunk.extend_from_slice(&[0; 4]);
+        chunk
+    }
+
+    fn webp_chunk(fourcc: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut chunk = fourcc.to_vec();
+        chunk.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        chunk.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    fn webp(chunks: &[Vec<u8>]) -> Vec<u8> {
+        let body = chunks.concat();
+        let mut data = b"RIFF".to_vec();
+        data.extend_from_slice(&(body.len() as u32 + 4).to_le_bytes());
+        data.extend_from_slice(b"WEBP");
+        data.extend_from_slice(&body);
+        data
+    }
+
+    #[test]
+    fn png_text_chunks_are_dropped() {
+        let ihdr = png_chunk(b"IHDR", &[0; 13]);
+        let idat = png_chunk(b"IDAT", &[1, 2, 3]);
+        let iend = png_chunk(b"IEND", &[]);
+        let data = [
+            PNG_SIGNATURE,
+            &ihdr,
+            &png_chunk(b"tEXt", b"Author\0someone"),
+            &idat,
+            &png_chunk(b"eXIf", &[0; 8]),
+            &iend,
+        ]
+        .concat();
+        let stripped = strip(&data, SourceType::Png).unwrap();
+        assert_eq!(stripped, [PNG_SIGNATURE, &ihdr, &idat, &iend].concat());
+    }
+
+    #[test]
+    fn webp_metadata_chunks_and_flags_are_dropped() {
+        let vp8x = webp_chunk(b"VP8X", &[0x0C, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let vp8 = webp_chunk(b"VP8 ", &[1, 2, 3]);
+        let data = webp(&[vp8x.clone(), webp_chunk(b"EXIF", &[0; 5]), vp8.clone()]);
+        let stripped = strip(&data, SourceType::WebP).unwrap();
+
+        let mut expected_vp8x = vp8x;
+        expected_vp8x[8] = 0;
+        assert_eq!(stripped, webp(&[expected_vp8x, vp8]));
+    }
+
+    #[test]
+    fn jpeg_metadata_segments_are_dropped() {
+        let app0 = [0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46];
+        let exif = [0xFF, 0xE1, 0x00, 0x04, 0x45, 0x78];
+        let scan = [0xFF, 0xDA, 0x00, 0x02, 0x11, 0x22, 0xFF, 0xD9];
+        let data = [&[0xFF, 0xD8][..], &app0, &exif, &scan].concat();
+        let stripped = strip(&data, SourceType::Jpeg).unwrap();
+        assert_eq!(stripped, [&[0xFF, 0xD8][..], &app0, &scan].concat());
+    }
+
+    #[test]
+    fn truncated_chunks_are_rejected() {
+        let ihdr = png_chunk(b"IHDR", &[0; 13]);
+        for cut in [1, 4, 11, ihdr.len() - 1] {
+            let data = [PNG_SIGNATURE, &ihdr[..cut]].concat();
+            assert!(strip(&data, SourceType::Png).is_err(), "PNG cut at {cut}");
+        }
+
+        let vp8 = webp_chunk(b"VP8 ", &[1, 2, 3]);
+        for cut in [1, 7, vp8.len() - 1] {
+            let data = webp(&[vp8[..cut].to_vec()]);
+            assert!(strip(&data, SourceType::WebP).is_err(), "WebP cut at {cut}");
+        }
+
+        let app0 = [0xFF, 0xE0, 0x00, 0x10, 0x4A, 0x46];
+        let data = [&[0xFF, 0xD8][..], &app0].concat();
+        assert!(strip(&data, SourceType::Jpeg).is_err());
+    }
+
+    #[test]
+    fn oversized_length_fields_are_rejected() {
+        for len in [u32::MAX, u32::MAX - 11, u32::MAX / 2] {
+            let mut chunk = png_chunk(b"tEXt", b"x");
+            chunk[..4].copy_from_slice(&len.to_be_bytes());
+            let data = [PNG_SIGNATURE, &chunk].concat();
+            assert!(strip(&data, SourceType::Png).is_err(), "PNG length {len}");
+
+            let mut chunk = webp_chunk(b"EXIF", b"xy");
+            chunk[4..8].copy_from_slice(&len.to_le_bytes());
+            let data = webp(&[chunk]);
+            assert!(strip(&data, SourceType::WebP).is_err(), "WebP length {len}");
+        }
+    }
+
+    #[test]
+    fn chunk_end_detects_overflow() {
+        let data = [0; 16];
+        assert_eq!(chunk_end(&data, 4, &[8, 4]), Some(16));
+        assert_eq!(chunk_end(&data, 4, &[8, 5]), None);
+        assert_eq!(chunk_end(&data, 8, &[usize::MAX, 1]), None);
+        assert_eq!(chunk_end(&data, usize::MAX, &[1]), None);
+    }
+}
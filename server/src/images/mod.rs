@@ -0,0 +1,444 @@
+mod metadata;
+mod palette;
+mod quality;
+mod resize;
+mod upload;
+
+use std::io::Cursor;
+
+use ic_rusqlite::with_connection;
+use resvg::tiny_skia::Pixmap;
+use sha2::{Digest, Sha256};
+use zune_core::{colorspace::ColorSpace, options::DecoderOptions};
+use zune_jpeg::JpegDecoder;
+
+use crate::jobs::{Budget, Step};
+
+pub use quality::{quality_report, ScreenshotQuality};
+pub use upload::{commit_upload, create_upload, upload_chunk, StoredImage};
+
+/// Widths a screenshot can be resized to. Anything else is rejected so the
+/// cache cannot be filled with arbitrary sizes.
+pub const WIDTHS: [u32; 6] = [150, 300, 600, 900, 1200, 1500];
+
+const JPEG_QUALITY: u8 = 82;
+/// Width screenshots are shrunk to before computing their palette and
+/// quality statistics.
+const ANALYSIS_WIDTH: u32 = 64;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ImageFormat {
+    Jpeg,
+    WebP,
+}
+
+impl ImageFormat {
+    /// Parse the `format` query parameter.
+    pub fn from_param(param: &str) -> Option<ImageFormat> {
+        match param {
+            "jpeg" | "jpg" => Some(ImageFormat::Jpeg),
+            "webp" => Some(ImageFormat::WebP),
+            _ => None,
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::WebP => "image/webp",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "jpeg",
+            ImageFormat::WebP => "webp",
+        }
+    }
+}
+
+/// The kinds of image accepted as screenshots, told apart by their magic
+/// bytes rather than whatever the uploader claims.
+#[derive(Clone, Copy, PartialEq)]
+enum SourceType {
+    Jpeg,
+    Png,
+    WebP,
+}
+
+impl SourceType {
+    fn sniff(data: &[u8]) -> Option<SourceType> {
+        if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(SourceType::Jpeg)
+        } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(SourceType::Png)
+        } else if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+            Some(SourceType::WebP)
+        } else {
+            None
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            SourceType::Jpeg => "image/jpeg",
+            SourceType::Png => "image/png",
+            SourceType::WebP => "image/webp",
+        }
+    }
+
+    /// The output format that can serve this source without re-encoding.
+    fn as_format(self) -> Option<ImageFormat> {
+        match self {
+            SourceType::Jpeg => Some(ImageFormat::Jpeg),
+            SourceType::Png => None,
+            SourceType::WebP => Some(ImageFormat::WebP),
+        }
+    }
+}
+
+/// A screenshot's original bytes.
+struct Source {
+    data: Vec<u8>,
+    source_type: SourceType,
+}
+
+/// The screenshot `image_id`, resized to `width` and encoded as `format`.
+/// Screenshots are never upscaled, so widths above the source's are served
+/// at the source width. Returns `None` if there is no such screenshot.
+///
+/// Results are persisted along with the source and output dimensions, and
+/// reused until the source changes.
+pub fn resized(image_id: &str, width: u32, format: ImageFormat) -> Result<Option<Vec<u8>>, String> {
+    let Some(source) = source(image_id)? else {
+        return Ok(None);
+    };
+
+    let size = dimensions(&source.data, source.source_type)?;
+    let new_size = scaled(size, width);
+    if new_size == size && source.source_type.as_format() == Some(format) {
+        return Ok(Some(source.data));
+    }
+
+    let source_hash: String = Sha256::digest(&source.data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    if let Some(data) = cached(image_id, &source_hash, new_size.0, format)? {
+        return Ok(Some(data));
+    }
+
+    let pixels = decode(&source.data, source.source_type)?;
+    let pixels = if new_size == size {
+        pixels
+    } else {
+        resize::downscale(&pixels, size, new_size)
+    };
+    let data = encode(&pixels, new_size, format)?;
+    store(image_id, &source_hash, size, new_size, format, &data)?;
+    Ok(Some(data))
+}
+
+/// Size in bytes of what [`resized`] returns, if it is known without
+/// rendering anything: the uploaded original when it is served as is, else
+/// a variant that has already been rendered.
+pub fn resized_len(image_id: &str, width: u32, format: ImageFormat) -> Option<usize> {
+    with_connection(|conn| {
+        conn.query_row(
+            "SELECT length(s.data) FROM stored_image s JOIN image i USING (image_id)
+             WHERE s.image_id = ?1 AND i.width <= ?2 AND s.content_type = ?3
+             UNION ALL
+             SELECT length(v.data) FROM image_variant v JOIN image i USING (image_id)
+             WHERE v.image_id = ?1 AND v.width = min(?2, i.width) AND v.format = ?4",
+            (image_id, width, format.mime_type(), format.name()),
+            |row| row.get(0),
+        )
+        .ok()
+    })
+}
+
+/// Analyse the uploaded app screenshots after `cursor`, an image ID, that
+/// have not been yet, until the budget runs out, then update the quality
+/// flags. Uploads are analysed as they are committed, so this only fills in
+/// analyses added since. Runs as the [`crate::jobs::ANALYZE_IMAGES`] job.
+pub fn analyze_missing(cursor: Option<&str>, budget: &Budget) -> Result<Step, String> {
+    let image_ids = with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT DISTINCT image_id FROM app
+                 JOIN stored_image USING (image_id)
+                 LEFT JOIN image USING (image_id)
+                 WHERE (blurhash IS NULL OR phash IS NULL) AND image_id > ?1
+                 ORDER BY image_id",
+            )
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map((cursor.unwrap_or(""),), |row| row.get::<_, String>(0))
+            .map_err(|e| e.to_string())?;
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })?;
+
+    for image_id in image_ids {
+        if let Err(e) = analyze(&image_id) {
+            ic_cdk::println!("Screenshot analysis failed for {image_id}: {e}");
+        }
+        if budget.exhausted() {
+            return Ok(Step::Continue(image_id));
+        }
+    }
+    quality::update_flags()?;
+    crate::invalidate_app_data_routes();
+    Ok(Step::Done)
+}
+
+/// What is computed once from a screenshot's pixels.
+struct Analysis {
+    palette: palette::Palette,
+    quality: quality::Quality,
+}
+
+/// Analyse and store the screenshot `image_id`.
+fn analyze(image_id: &str) -> Result<(), String> {
+    let Some(source) = source(image_id)? else {
+        return Err("No such screenshot".to_string());
+    };
+    let size = dimensions(&source.data, source.source_type)?;
+    let source_hash: String = Sha256::digest(&source.data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+
+    let analysis = analyze_pixels(&decode(&source.data, source.source_type)?, size);
+    store_analysis(image_id, &source_hash, size, &analysis)
+}
+
+fn analyze_pixels(rgb: &[u8], size: (u32, u32)) -> Analysis {
+    let small_size = scaled(size, ANALYSIS_WIDTH);
+    let small = if small_size == size {
+        rgb.to_vec()
+    } else {
+        resize::downscale(rgb, size, small_size)
+    };
+    Analysis {
+        palette: palette::analyze(&small, small_size),
+        quality: quality::analyze(&small, small_size),
+    }
+}
+
+/// Record the source's dimensions and analysis, dropping variants of a
+/// previous source with the same ID.
+fn store_analysis(
+    image_id: &str,
+    source_hash: &str,
+    (width, height): (u32, u32),
+    analysis: &Analysis,
+) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "DELETE FROM image_variant WHERE image_id IN
+             (SELECT image_id FROM image WHERE image_id = ?1 AND source_hash != ?2)",
+            (image_id, source_hash),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO image (image_id, source_hash, width, height, blurhash, dominant_color,
+                                accent_color, phash, luma_stddev, uniform_fraction)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             ON CONFLICT (image_id) DO UPDATE
+             SET source_hash = excluded.source_hash, width = excluded.width, height = excluded.height,
+                 blurhash = excluded.blurhash, dominant_color = excluded.dominant_color,
+                 accent_color = excluded.accent_color, phash = excluded.phash,
+                 luma_stddev = excluded.luma_stddev, uniform_fraction = excluded.uniform_fraction",
+            (
+                image_id,
+                source_hash,
+                width,
+                height,
+                &analysis.palette.blurhash,
+                &analysis.palette.dominant_color,
+                &analysis.palette.accent_color,
+                &analysis.quality.phash,
+                analysis.quality.luma_stddev,
+                analysis.quality.uniform_fraction,
+            ),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
+
+/// The uploaded original of `image_id`.
+fn source(image_id: &str) -> Result<Option<Source>, String> {
+    let data = with_connection(|conn| {
+        match conn.query_row(
+            "SELECT data FROM stored_image WHERE image_id = ?1",
+            (image_id,),
+            |row| row.get::<_, Vec<u8>>(0),
+        ) {
+            Ok(data) => Ok(Some(data)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })?;
+    let Some(data) = data else {
+        return Ok(None);
+    };
+    let source_type = SourceType::sniff(&data).ok_or("Unrecognized image type")?;
+    Ok(Some(Source { data, source_type }))
+}
+
+/// `size` scaled down to `width`, keeping the aspect ratio.
+fn scaled((width, height): (u32, u32), new_width: u32) -> (u32, u32) {
+    if new_width >= width {
+        (width, height)
+    } else {
+        (new_width, (height * new_width).div_ceil(width).max(1))
+    }
+}
+
+/// Width and height from the image header, without decoding pixels.
+fn dimensions(data: &[u8], source_type: SourceType) -> Result<(u32, u32), String> {
+    match source_type {
+        SourceType::Jpeg => {
+            let mut decoder = JpegDecoder::new(data);
+            decoder
+                .decode_headers()
+                .map_err(|e| format!("JPEG decode error: {e}"))?;
+            let info = decoder.info().ok_or("JPEG has no image info")?;
+            Ok((u32::from(info.width), u32::from(info.height)))
+        }
+        SourceType::Png => {
+            // IHDR is always the first chunk
+            if data.len() < 24 || &data[12..16] != b"IHDR" {
+                return Err("Malformed PNG".to_string());
+            }
+            Ok((
+                u32::from_be_bytes(data[16..20].try_into().unwrap()),
+                u32::from_be_bytes(data[20..24].try_into().unwrap()),
+            ))
+        }
+        SourceType::WebP => image_webp::WebPDecoder::new(Cursor::new(data))
+            .map(|decoder| decoder.dimensions())
+            .map_err(|e| format!("WebP decode error: {e}")),
+    }
+}
+
+/// Decode to interleaved RGB. Transparent areas are composited onto white.
+fn decode(data: &[u8], source_type: SourceType) -> Result<Vec<u8>, String> {
+    match source_type {
+        SourceType::Jpeg => {
+            let options = DecoderOptions::default().jpeg_set_out_colorspace(ColorSpace::RGB);
+            JpegDecoder::new_with_options(data, options)
+                .decode()
+                .map_err(|e| format!("JPEG decode error: {e}"))
+        }
+        SourceType::Png => {
+            // Premultiplied, so the colour is already scaled by alpha
+            let pixmap = Pixmap::decode_png(data).map_err(|e| format!("PNG decode error: {e}"))?;
+            Ok(pixmap
+                .data()
+                .chunks_exact(4)
+                .flat_map(|px| [0, 1, 2].map(|c| px[c] + (255 - px[3])))
+                .collect())
+        }
+        SourceType::WebP => {
+            let mut decoder = image_webp::WebPDecoder::new(Cursor::new(data))
+                .map_err(|e| format!("WebP decode error: {e}"))?;
+            let len = decoder.output_buffer_size().ok_or("WebP image too large")?;
+            let mut buf = vec![0; len];
+            decoder
+                .read_image(&mut buf)
+                .map_err(|e| format!("WebP decode error: {e}"))?;
+            if !decoder.has_alpha() {
+                return Ok(buf);
+            }
+            Ok(buf
+                .chunks_exact(4)
+                .flat_map(|px| {
+                    let alpha = u16::from(px[3]);
+                    [0, 1, 2]
+                        .map(|c| ((u16::from(px[c]) * alpha + 255 * (255 - alpha)) / 255) as u8)
+                })
+                .collect())
+        }
+    }
+}
+
+fn encode(rgb: &[u8], (width, height): (u32, u32), format: ImageFormat) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    match format {
+        ImageFormat::Jpeg => jpeg_encoder::Encoder::new(&mut out, JPEG_QUALITY)
+            .encode(
+                rgb,
+                width as u16,
+                height as u16,
+                jpeg_encoder::ColorType::Rgb,
+            )
+            .map_err(|e| format!("JPEG encode error: {e}"))?,
+        ImageFormat::WebP => image_webp::WebPEncoder::new(&mut out)
+            .encode(rgb, width, height, image_webp::ColorType::Rgb8)
+            .map_err(|e| format!("WebP encode error: {e}"))?,
+    }
+    Ok(out)
+}
+
+fn cached(
+    image_id: &str,
+    source_hash: &str,
+    width: u32,
+    format: ImageFormat,
+) -> Result<Option<Vec<u8>>, String> {
+    with_connection(|conn| {
+        match conn.query_row(
+            "SELECT v.data FROM image_variant v JOIN image i USING (image_id)
+             WHERE v.image_id = ?1 AND i.source_hash = ?2 AND v.width = ?3 AND v.format = ?4",
+            (image_id, source_hash, width, format.name()),
+            |row| row.get(0),
+        ) {
+            Ok(data) => Ok(Some(data)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+/// Record the source's dimensions and persist a variant, dropping variants
+/// and the analysis of a previous source with the same ID.
+fn store(
+    image_id: &str,
+    source_hash: &str,
+    (width, height): (u32, u32),
+    (new_width, new_height): (u32, u32),
+    format: ImageFormat,
+    data: &[u8],
+) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "DELETE FROM image_variant WHERE image_id IN
+             (SELECT image_id FROM image WHERE image_id = ?1 AND source_hash != ?2)",
+            (image_id, source_hash),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO image (image_id, source_hash, width, height) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (image_id) DO UPDATE
+             SET source_hash = excluded.source_hash, width = excluded.width, height = excluded.height,
+                 blurhash = CASE WHEN source_hash = excluded.source_hash THEN blurhash END,
+                 dominant_color = CASE WHEN source_hash = excluded.source_hash THEN dominant_color END,
+                 accent_color = CASE WHEN source_hash = excluded.source_hash THEN accent_color END,
+                 phash = CASE WHEN source_hash = excluded.source_hash THEN phash END,
+                 luma_stddev = CASE WHEN source_hash = excluded.source_hash THEN luma_stddev END,
+                 uniform_fraction = CASE WHEN source_hash = excluded.source_hash THEN uniform_fraction END",
+            (image_id, source_hash, width, height),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT OR REPLACE INTO image_variant (image_id, width, format, height, data)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            (image_id, new_width, format.name(), new_height, data),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
@@ -0,0 +1,152 @@
+use std::f32::consts::PI;
+
+/// BlurHash components across and down. Screenshots are landscape, so more
+/// detail is kept horizontally.
+const COMPONENTS: (usize, usize) = (4, 3);
+const BASE83: &[u8; 83] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// A screenshot's placeholder and colours, see [`crate::app::app_types::App`].
+pub(super) struct Palette {
+    pub blurhash: String,
+    pub dominant_color: String,
+    pub accent_color: String,
+}
+
+/// `rgb` is the screenshot shrunk for analysis. The hash only keeps low
+/// frequencies and the colours are bucketed, so that loses nothing.
+pub(super) fn analyze(rgb: &[u8], size: (u32, u32)) -> Palette {
+    let (dominant, accent) = colors(rgb);
+    Palette {
+        blurhash: blurhash(rgb, size),
+        dominant_color: hex(dominant),
+        accent_color: hex(accent.unwrap_or(dominant)),
+    }
+}
+
+/// Encode as described at <https://github.com/woltapp/blurhash>.
+fn blurhash(rgb: &[u8], (width, height): (u32, u32)) -> String {
+    let (nx, ny) = COMPONENTS;
+    let (width, height) = (width as usize, height as usize);
+    let linear: Vec<f32> = rgb.iter().map(|&v| srgb_to_linear(v)).collect();
+
+    let mut factors = Vec::with_capacity(nx * ny);
+    for j in 0..ny {
+        for i in 0..nx {
+            let mut factor = [0.0f32; 3];
+            for y in 0..height {
+                let basis_y = (PI * j as f32 * y as f32 / height as f32).cos();
+                for x in 0..width {
+                    let basis = basis_y * (PI * i as f32 * x as f32 / width as f32).cos();
+                    let px = &linear[(y * width + x) * 3..][..3];
+                    for c in 0..3 {
+                        factor[c] += basis * px[c];
+                    }
+                }
+            }
+            let normalisation = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+            let scale = normalisation / (width * height) as f32;
+            factors.push(factor.map(|v| v * scale));
+        }
+    }
+
+    let mut hash = String::with_capacity(4 + 2 * nx * ny);
+    base83((nx - 1 + (ny - 1) * 9) as u32, 1, &mut hash);
+
+    let (dc, ac) = factors.split_first().unwrap();
+    let max_value = if ac.is_empty() {
+        base83(0, 1, &mut hash);
+        1.0
+    } else {
+        let actual_max = ac.iter().flatten().fold(0.0f32, |max, v| max.max(v.abs()));
+        let quantised = ((actual_max * 166.0 - 0.5).floor()).clamp(0.0, 82.0) as u32;
+        base83(quantised, 1, &mut hash);
+        (quantised + 1) as f32 / 166.0
+    };
+
+    let [r, g, b] = dc.map(linear_to_srgb);
+    base83((r << 16) | (g << 8) | b, 4, &mut hash);
+    for factor in ac {
+        let [r, g, b] = factor.map(|v| {
+            let v = v / max_value;
+            (v.signum() * v.abs().sqrt() * 9.0 + 9.5)
+                .floor()
+                .clamp(0.0, 18.0) as u32
+        });
+        base83(r * 19 * 19 + g * 19 + b, 2, &mut hash);
+    }
+    hash
+}
+
+fn base83(value: u32, digits: u32, out: &mut String) {
+    for i in (0..digits).rev() {
+        out.push(BASE83[(value / 83u32.pow(i) % 83) as usize] as char);
+    }
+}
+
+fn srgb_to_linear(value: u8) -> f32 {
+    let v = value as f32 / 255.0;
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> u32 {
+    let v = value.clamp(0.0, 1.0);
+    let srgb = if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (srgb * 255.0 + 0.5) as u32
+}
+
+/// The most common colour and, if there is one, the most prominent
+/// saturated colour. Pixels are bucketed at 4 bits per channel and each
+/// bucket is represented by the average of its pixels.
+pub(super) fn colors(rgb: &[u8]) -> ([u8; 3], Option<[u8; 3]>) {
+    // Pixel count and channel sums per bucket
+    let mut buckets = vec![(0u32, [0u32; 3]); 1 << 12];
+    for px in rgb.chunks_exact(3) {
+        let index = (usize::from(px[0] >> 4) << 8)
+            | (usize::from(px[1] >> 4) << 4)
+            | usize::from(px[2] >> 4);
+        let bucket = &mut buckets[index];
+        bucket.0 += 1;
+        for (sum, &v) in bucket.1.iter_mut().zip(px) {
+            *sum += u32::from(v);
+        }
+    }
+    let average = |(count, sums): &(u32, [u32; 3])| sums.map(|sum| (sum / count) as u8);
+
+    let dominant = buckets
+        .iter()
+        .filter(|bucket| bucket.0 > 0)
+        .max_by_key(|bucket| bucket.0)
+        .map(average)
+        .unwrap_or([255, 255, 255]);
+
+    // Weigh frequency by saturation, skipping greys and near-black, so a
+    // small logo beats a large pale background
+    let accent = buckets
+        .iter()
+        .filter(|bucket| bucket.0 > 0)
+        .map(|bucket| (average(bucket), bucket.0))
+        .filter_map(|(color, count)| {
+            let max = *color.iter().max().unwrap();
+            let min = *color.iter().min().unwrap();
+            let saturation = f32::from(max - min) / f32::from(max.max(1));
+            (saturation >= 0.3 && max >= 50)
+                .then_some((color, count as f32 * saturation * saturation))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(color, _)| color);
+
+    (dominant, accent)
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
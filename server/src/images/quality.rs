@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+
+use candid::CandidType;
+use ic_rusqlite::with_connection;
+
+use super::{palette, resize};
+
+/// Luma standard deviation below which a screenshot is taken to be blank.
+const BLANK_MAX_STDDEV: f64 = 6.0;
+/// Share of pixels in the dominant colour above which a screenshot is taken
+/// to show next to nothing, e.g. a login box on an empty page.
+const UNIFORM_MIN_FRACTION: f64 = 0.92;
+/// How far a pixel's channels may be from the dominant colour to count as it.
+const UNIFORM_TOLERANCE: u8 = 24;
+/// Perceptual hashes at most this many bits apart are the same picture.
+const SIMILAR_MAX_DISTANCE: u32 = 6;
+/// Number of apps showing the same picture for it to be taken as a shared
+/// error or placeholder page.
+const SHARED_MIN_APPS: usize = 3;
+
+/// Statistics used to spot screenshots of apps that were down.
+pub(super) struct Quality {
+    /// 64-bit difference hash, as 16 hex digits.
+    pub phash: String,
+    pub luma_stddev: f64,
+    pub uniform_fraction: f64,
+}
+
+/// An app whose screenshot looks broken, see [`quality_report`].
+#[derive(CandidType)]
+pub struct ScreenshotQuality {
+    pub app_id: i64,
+    pub app_name: Option<String>,
+    pub url: String,
+    pub image_id: Option<String>,
+    /// Any of `blank`, `uniform`, `shared`, or `missing` if the app has no
+    /// screenshot that could be analysed.
+    pub flags: Vec<String>,
+    pub luma_stddev: Option<f64>,
+    pub uniform_fraction: Option<f64>,
+    pub phash: Option<String>,
+    /// The other apps showing the same picture, for `shared` screenshots.
+    pub similar_app_ids: Vec<i64>,
+}
+
+/// An analysed screenshot and the apps using it.
+struct Analysed {
+    image_id: String,
+    phash: u64,
+    luma_stddev: f64,
+    uniform_fraction: f64,
+    app_ids: Vec<i64>,
+}
+
+/// An analysed screenshot in use by at least one app.
+struct Assessment {
+    image_id: String,
+    flags: Vec<&'static str>,
+    /// Every app showing the picture, if it is `shared`.
+    similar_app_ids: Vec<i64>,
+}
+
+/// `rgb` is the screenshot shrunk for analysis.
+pub(super) fn analyze(rgb: &[u8], size: (u32, u32)) -> Quality {
+    let luma: Vec<f64> = rgb
+        .chunks_exact(3)
+        .map(|px| 0.299 * f64::from(px[0]) + 0.587 * f64::from(px[1]) + 0.114 * f64::from(px[2]))
+        .collect();
+    let mean = luma.iter().sum::<f64>() / luma.len() as f64;
+    let variance = luma.iter().map(|l| (l - mean).powi(2)).sum::<f64>() / luma.len() as f64;
+
+    let (dominant, _) = palette::colors(rgb);
+    let uniform = rgb
+        .chunks_exact(3)
+        .filter(|px| {
+            px.iter()
+                .zip(dominant)
+                .all(|(&v, d)| v.abs_diff(d) <= UNIFORM_TOLERANCE)
+        })
+        .count();
+
+    Quality {
+        phash: format!("{:016x}", dhash(rgb, size)),
+        luma_stddev: variance.sqrt(),
+        uniform_fraction: uniform as f64 / luma.len() as f64,
+    }
+}
+
+/// Whether each pixel of a 9×8 greyscale thumbnail is darker than its right
+/// neighbour. Robust to scaling and compression, so recaptures of the same
+/// page hash alike.
+fn dhash(rgb: &[u8], size: (u32, u32)) -> u64 {
+    let tiny = resize::downscale(rgb, size, (9, 8));
+    let luma: Vec<u32> = tiny
+        .chunks_exact(3)
+        .map(|px| 299 * u32::from(px[0]) + 587 * u32::from(px[1]) + 114 * u32::from(px[2]))
+        .collect();
+    let mut hash = 0u64;
+    for row in luma.chunks_exact(9) {
+        for pair in row.windows(2) {
+            hash = (hash << 1) | u64::from(pair[0] < pair[1]);
+        }
+    }
+    hash
+}
+
+/// Recompute the flags of every app screenshot. Run whenever screenshots
+/// are analysed or assigned to apps, as a shared picture depends on all of
+/// them.
+pub(super) fn update_flags() -> Result<(), String> {
+    let assessments = assess()?;
+    with_connection(|conn| {
+        conn.execute("UPDATE image SET quality_flags = NULL", ())
+            .map_err(|e| e.to_string())?;
+        for assessment in assessments.iter().filter(|a| !a.flags.is_empty()) {
+            conn.execute(
+                "UPDATE image SET quality_flags = ?2 WHERE image_id = ?1",
+                (&assessment.image_id, assessment.flags.join(" ")),
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    })
+}
+
+/// Every app whose screenshot is missing, blank, nearly a single colour, or
+/// the same picture as several other apps', so it can be recaptured.
+pub fn quality_report() -> Result<Vec<ScreenshotQuality>, String> {
+    let assessments: HashMap<String, Assessment> = assess()?
+        .into_iter()
+        .map(|assessment| (assessment.image_id.clone(), assessment))
+        .collect();
+
+    let apps = with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, app_name, url, image_id, phash, luma_stddev, uniform_fraction
+                 FROM app LEFT JOIN image USING (image_id)
+                 ORDER BY id",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((), |row| {
+                Ok(ScreenshotQuality {
+                    app_id: row.get(0)?,
+                    app_name: row.get(1)?,
+                    url: row.get(2)?,
+                    image_id: row.get(3)?,
+                    flags: Vec::new(),
+                    phash: row.get(4)?,
+                    luma_stddev: row.get(5)?,
+                    uniform_fraction: row.get(6)?,
+                    similar_app_ids: Vec::new(),
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })?;
+
+    Ok(apps
+        .into_iter()
+        .filter_map(|mut app| {
+            match app.image_id.as_ref().and_then(|id| assessments.get(id)) {
+                Some(assessment) => {
+                    app.flags = assessment.flags.iter().map(|f| f.to_string()).collect();
+                    app.similar_app_ids = assessment
+                        .similar_app_ids
+                        .iter()
+                        .copied()
+                        .filter(|&id| id != app.app_id)
+                        .collect();
+                }
+                None => app.flags = vec!["missing".to_string()],
+            }
+            (!app.flags.is_empty()).then_some(app)
+        })
+        .collect())
+}
+
+/// Flag every analysed screenshot in use, see [`flag`].
+fn assess() -> Result<Vec<Assessment>, String> {
+    let images = with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT image_id, phash, luma_stddev, uniform_fraction, group_concat(app.id)
+                 FROM image JOIN app USING (image_id)
+                 WHERE phash IS NOT NULL
+                 GROUP BY image_id
+                 ORDER BY image_id",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((), |row| {
+                Ok(Analysed {
+                    image_id: row.get(0)?,
+                    phash: u64::from_str_radix(&row.get::<_, String>(1)?, 16).unwrap_or_default(),
+                    luma_stddev: row.get(2)?,
+                    uniform_fraction: row.get(3)?,
+                    app_ids: row
+                        .get::<_, String>(4)?
+                        .split(',')
+                        .filter_map(|id| id.parse().ok())
+                        .collect(),
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })?;
+    Ok(flag(images))
+}
+
+/// Flag `images`. Taken in order, each screenshot joins the first group
+/// whose first member's hash is within [`SIMILAR_MAX_DISTANCE`] of its own,
+/// or starts a group. Comparing against the first member, rather than any
+/// member, stops a run of slightly different pictures from chaining into
+/// one group.
+fn flag(images: Vec<Analysed>) -> Vec<Assessment> {
+    // The first member of each group
+    let mut firsts: Vec<u64> = Vec::new();
+    let groups: Vec<usize> = images
+        .iter()
+        .map(|image| {
+            match firsts
+                .iter()
+                .position(|first| (first ^ image.phash).count_ones() <= SIMILAR_MAX_DISTANCE)
+            {
+                Some(group) => group,
+                None => {
+                    firsts.push(image.phash);
+                    firsts.len() - 1
+                }
+            }
+        })
+        .collect();
+
+    let mut group_apps = vec![Vec::new(); firsts.len()];
+    for (&group, image) in groups.iter().zip(&images) {
+        group_apps[group].extend(&image.app_ids);
+    }
+
+    images
+        .into_iter()
+        .zip(groups)
+        .map(|(image, group)| {
+            let mut flags = Vec::new();
+            if image.luma_stddev < BLANK_MAX_STDDEV {
+                flags.push("blank");
+            } else if image.uniform_fraction >= UNIFORM_MIN_FRACTION {
+                flags.push("uniform");
+            }
+            let group_apps = &group_apps[group];
+            let similar_app_ids = if group_apps.len() >= SHARED_MIN_APPS {
+                flags.push("shared");
+                let mut ids = group_apps.clone();
+                ids.sort();
+                ids
+            } else {
+                Vec::new()
+            };
+            Assessment {
+                image_id: image.image_id,
+                flags,
+                similar_app_ids,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: (u32, u32) = (64, 40);
+
+    fn image(size: (u32, u32), pixel: impl Fn(u32, u32) -> [u8; 3]) -> Vec<u8> {
+        (0..size.1)
+            .flat_map(|y| (0..size.0).map(move |x| (x, y)))
+            .flat_map(|(x, y)| pixel(x, y))
+            .collect()
+    }
+
+    fn flags_of(rgb: &[u8]) -> Vec<&'static str> {
+        let quality = analyze(rgb, SIZE);
+        let analysed = Analysed {
+            image_id: "a".to_string(),
+            phash: u64::from_str_radix(&quality.phash, 16).unwrap(),
+            luma_stddev: quality.luma_stddev,
+            uniform_fraction: quality.uniform_fraction,
+            app_ids: vec![1],
+        };
+        flag(vec![analysed]).remove(0).flags
+    }
+
+    fn analysed(image_id: &str, phash: u64, app_ids: &[i64]) -> Analysed {
+        Analysed {
+            image_id: image_id.to_string(),
+            phash,
+            luma_stddev: 50.0,
+            uniform_fraction: 0.2,
+            app_ids: app_ids.to_vec(),
+        }
+    }
+
+    #[test]
+    fn blank_image() {
+        // Off-white with faint noise, as a page that failed to load
+        let rgb = image(SIZE, |x, y| {
+            let v = 240 + ((x * 7 + y * 3) % 5) as u8;
+            [v, v, v]
+        });
+        assert_eq!(flags_of(&rgb), ["blank"]);
+    }
+
+    #[test]
+    fn near_uniform_image() {
+        // A small dark box on a white page
+        let rgb = image(SIZE, |x, y| {
+            if (28..36).contains(&x) && (15..25).contains(&y) {
+                [20, 20, 60]
+            } else {
+                [255, 255, 255]
+            }
+        });
+        let quality = analyze(&rgb, SIZE);
+        assert!(quality.luma_stddev >= BLANK_MAX_STDDEV);
+        assert!(quality.uniform_fraction >= UNIFORM_MIN_FRACTION);
+        assert_eq!(flags_of(&rgb), ["uniform"]);
+    }
+
+    #[test]
+    fn varied_image_is_not_flagged() {
+        let rgb = image(SIZE, |x, y| {
+            [(x * 4) as u8, (y * 6) as u8, ((x + y) * 2) as u8]
+        });
+        assert!(flags_of(&rgb).is_empty());
+    }
+
+    #[test]
+    fn identical_hashes_of_three_apps_are_shared() {
+        let assessments = flag(vec![
+            analysed("a", 0xdead_beef, &[3]),
+            analysed("b", 0xdead_beef, &[1]),
+            analysed("c", 0xdead_beef, &[2]),
+            analysed("d", !0xdead_beef, &[4]),
+        ]);
+        for assessment in &assessments[..3] {
+            assert_eq!(assessment.flags, ["shared"]);
+            assert_eq!(assessment.similar_app_ids, [1, 2, 3]);
+        }
+        assert!(assessments[3].flags.is_empty());
+        assert!(assessments[3].similar_app_ids.is_empty());
+    }
+
+    #[test]
+    fn one_picture_of_three_apps_is_shared() {
+        let assessments = flag(vec![
+            analysed("a", 0xff, &[1, 2]),
+            analysed("b", 0xff, &[3]),
+        ]);
+        assert!(assessments.iter().all(|a| a.flags == ["shared"]));
+    }
+
+    #[test]
+    fn two_apps_are_not_shared() {
+        let assessments = flag(vec![analysed("a", 0xff, &[1]), analysed("b", 0xff, &[2])]);
+        assert!(assessments.iter().all(|a| a.flags.is_empty()));
+    }
+
+    #[test]
+    fn groups_do_not_chain() {
+        // Each hash is 4 bits from the one before, so only neighbours are
+        // within the distance of each other
+        let assessments = flag(vec![
+            analysed("a", 0x0000, &[1]),
+            analysed("b", 0x000f, &[2]),
+            analysed("c", 0x00ff, &[3]),
+            analysed("d", 0x0fff, &[4]),
+        ]);
+        assert!(assessments.iter().all(|a| a.flags.is_empty()));
+    }
+}
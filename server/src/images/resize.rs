@@ -0,0 +1,59 @@
+/// Downscale an interleaved RGB image by averaging the source pixels each
+/// destination pixel covers. Sharper than bilinear sampling at the large
+/// ratios screenshots are shrunk by, and free of its aliasing.
+pub(super) fn downscale(
+    src: &[u8],
+    (width, height): (u32, u32),
+    (new_width, new_height): (u32, u32),
+) -> Vec<u8> {
+    let columns = weights(width, new_width);
+    let rows = weights(height, new_height);
+    let width = width as usize;
+    let new_width = new_width as usize;
+
+    // Horizontal pass, keeping full precision for the vertical one
+    let mut narrow = vec![0.0f32; new_width * height as usize * 3];
+    for (src_row, out_row) in src
+        .chunks_exact(width * 3)
+        .zip(narrow.chunks_exact_mut(new_width * 3))
+    {
+        for (out, column) in out_row.chunks_exact_mut(3).zip(&columns) {
+            for &(x, weight) in column {
+                for c in 0..3 {
+                    out[c] += src_row[x * 3 + c] as f32 * weight;
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::with_capacity(new_width * new_height as usize * 3);
+    for row in &rows {
+        for i in 0..new_width * 3 {
+            let value: f32 = row
+                .iter()
+                .map(|&(y, weight)| narrow[y * new_width * 3 + i] * weight)
+                .sum();
+            out.push(value.round().clamp(0.0, 255.0) as u8);
+        }
+    }
+    out
+}
+
+/// For each of `new_len` destination pixels, the source pixels it covers
+/// and their share of it.
+fn weights(len: u32, new_len: u32) -> Vec<Vec<(usize, f32)>> {
+    let scale = len as f32 / new_len as f32;
+    (0..new_len)
+        .map(|i| {
+            let start = i as f32 * scale;
+            let end = (start + scale).min(len as f32);
+            (start.floor() as usize..end.ceil() as usize)
+                .map(|j| {
+                    let overlap = end.min(j as f32 + 1.0) - start.max(j as f32);
+                    (j, overlap / scale)
+                })
+                .filter(|&(_, weight)| weight > 0.0)
+                .collect()
+        })
+        .collect()
+}
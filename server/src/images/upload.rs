@@ -0,0 +1,158 @@
+use candid::CandidType;
+use ic_rusqlite::with_connection;
+use sha2::{Digest, Sha256};
+
+use crate::app::AppManager;
+
+use super::{analyze_pixels, decode, dimensions, metadata, quality, store_analysis, SourceType};
+
+/// Uploads that grow past this are rejected as chunks arrive.
+const MAX_IMAGE_BYTES: usize = 8 * 1024 * 1024;
+const MAX_DIMENSION: u32 = 4096;
+/// Uploads that are never committed are dropped after a day.
+const UPLOAD_TTL_SECONDS: i64 = 24 * 60 * 60;
+
+/// A screenshot stored by [`commit_upload`].
+#[derive(CandidType)]
+pub struct StoredImage {
+    /// Derived from the image content, so uploading the same image twice
+    /// yields the same ID.
+    pub image_id: String,
+    pub content_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub size: u64,
+}
+
+/// Start an upload and return its ID. Also drops stale uploads.
+pub fn create_upload() -> Result<u64, String> {
+    with_connection(|conn| {
+        conn.execute(
+            "DELETE FROM image_upload_chunk WHERE upload_id IN
+             (SELECT id FROM image_upload WHERE created_at < strftime('%s','now') - ?1)",
+            (UPLOAD_TTL_SECONDS,),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "DELETE FROM image_upload WHERE created_at < strftime('%s','now') - ?1",
+            (UPLOAD_TTL_SECONDS,),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute("INSERT INTO image_upload DEFAULT VALUES", ())
+            .map_err(|e| e.to_string())?;
+        Ok(conn.last_insert_rowid() as u64)
+    })
+}
+
+/// Append `chunk` to the upload. Chunks are joined in the order they arrive.
+pub fn upload_chunk(upload_id: u64, chunk: &[u8]) -> Result<(), String> {
+    with_connection(|conn| {
+        let size: Option<usize> = conn
+            .query_row(
+                "SELECT (SELECT COALESCE(SUM(length(data)), 0) FROM image_upload_chunk WHERE upload_id = ?1)
+                 FROM image_upload WHERE id = ?1",
+                (upload_id,),
+                |row| row.get(0),
+            )
+            .map(Some)
+            .or_else(|e| match e {
+                ic_rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                e => Err(e.to_string()),
+            })?;
+        let Some(size) = size else {
+            return Err(format!("No upload {upload_id}"));
+        };
+        if size + chunk.len() > MAX_IMAGE_BYTES {
+            return Err(format!("Image is larger than {MAX_IMAGE_BYTES} bytes"));
+        }
+        conn.execute(
+            "INSERT INTO image_upload_chunk (upload_id, data) VALUES (?1, ?2)",
+            (upload_id, chunk),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
+
+/// Finish the upload: check that it is a JPEG, PNG or WebP of at most
+/// 4096×4096 that decodes, strip its metadata and store it along with its
+/// BlurHash, colours and quality statistics. If `app_id` is
+/// given, that app's screenshot is switched to the new image.
+///
+/// The upload is discarded whether or not it is valid.
+pub fn commit_upload(upload_id: u64, app_id: Option<i64>) -> Result<StoredImage, String> {
+    if let Some(app_id) = app_id {
+        AppManager::get_by_id(app_id).map_err(|_| format!("No app {app_id}"))?;
+    }
+
+    let chunks = with_connection(|conn| {
+        let exists: bool = conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM image_upload WHERE id = ?1)",
+                (upload_id,),
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())?;
+        if !exists {
+            return Err(format!("No upload {upload_id}"));
+        }
+
+        let mut stmt = conn
+            .prepare("SELECT data FROM image_upload_chunk WHERE upload_id = ?1 ORDER BY id")
+            .map_err(|e| e.to_string())?;
+        let chunks = stmt
+            .query_map((upload_id,), |row| row.get::<_, Vec<u8>>(0))
+            .map_err(|e| e.to_string())?
+            .collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())?;
+
+        conn.execute(
+            "DELETE FROM image_upload_chunk WHERE upload_id = ?1",
+            (upload_id,),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute("DELETE FROM image_upload WHERE id = ?1", (upload_id,))
+            .map_err(|e| e.to_string())?;
+        Ok(chunks)
+    })?;
+    let data = chunks.concat();
+
+    let source_type = SourceType::sniff(&data)
+        .ok_or("Unsupported image type, upload a JPEG, PNG or WebP image")?;
+    let data = metadata::strip(&data, source_type)?;
+    let (width, height) = dimensions(&data, source_type)?;
+    if width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Err(format!(
+            "Image is {width}x{height}, the limit is {MAX_DIMENSION}x{MAX_DIMENSION}"
+        ));
+    }
+    let analysis = analyze_pixels(&decode(&data, source_type)?, (width, height));
+
+    let hash = Sha256::digest(&data);
+    let source_hash: String = hash.iter().map(|b| format!("{b:02x}")).collect();
+    let image_id = source_hash[..32].to_string();
+
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT OR IGNORE INTO stored_image (image_id, content_type, data) VALUES (?1, ?2, ?3)",
+            (&image_id, source_type.mime_type(), &data),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })?;
+    store_analysis(&image_id, &source_hash, (width, height), &analysis)?;
+
+    if let Some(app_id) = app_id {
+        AppManager::set_image_id(app_id, &image_id)?;
+        quality::update_flags()?;
+        crate::invalidate_app_data_routes();
+    }
+
+    Ok(StoredImage {
+        image_id,
+        content_type: source_type.mime_type().to_string(),
+        width,
+        height,
+        size: data.len() as u64,
+    })
+}
@@ -0,0 +1,258 @@
+//! Periodic maintenance jobs run on the global timer.
+//!
+//! Each job works through its items in slices. A slice stops once it has
+//! used the job's instruction budget and returns a cursor, which is
+//! persisted so the next slice, possibly after an upgrade, carries on from
+//! there. Once a pass is complete the job sleeps for its interval.
+//!
+//! Each timer tick runs one slice, of the job that has been due longest, so
+//! jobs that are due together take turns.
+//!
+//! A trap rolls back the whole timer call, including the re-armed timer, so
+//! jobs should return errors rather than panic.
+
+use candid::CandidType;
+use ic_rusqlite::with_connection;
+
+use crate::app::canonical_url;
+use crate::{images, ogimage};
+
+/// Name of the job that renders the OG images linked from page metadata.
+pub const WARM_OG_IMAGES: &str = "warm_og_images";
+/// Name of the job that analyses screenshots uploaded before their analysis
+/// existed.
+pub const ANALYZE_IMAGES: &str = "analyze_images";
+/// Name of the job that fills in the canonical URLs of apps seeded without
+/// one.
+pub const BACKFILL_CANONICAL_URLS: &str = "backfill_canonical_urls";
+
+/// Wait before retrying a job whose slice failed.
+const RETRY_AFTER_SECONDS: i64 = 10 * 60;
+
+/// A job in the [`JOBS`] registry.
+pub struct Job {
+    pub name: &'static str,
+    /// Time from the end of one complete pass to the start of the next.
+    pub interval_seconds: i64,
+    /// Instructions a slice may use before yielding. A slice always
+    /// processes at least one item, so keep items well below the 40B limit
+    /// on a single message.
+    pub instruction_budget: u64,
+    /// Process the items after `cursor`, or from the start if it is `None`.
+    pub run: fn(cursor: Option<&str>, budget: &Budget) -> Result<Step, String>,
+}
+
+/// What a slice of a job got through.
+pub enum Step {
+    /// Items remain after the given cursor.
+    Continue(String),
+    /// The pass is complete.
+    Done,
+}
+
+/// Instructions a slice may use, counted from when it starts.
+pub struct Budget {
+    start: u64,
+    limit: u64,
+}
+
+impl Budget {
+    pub fn exhausted(&self) -> bool {
+        ic_cdk::api::instruction_counter() - self.start >= self.limit
+    }
+}
+
+/// A job's state, for controllers.
+#[derive(CandidType)]
+pub struct JobStatus {
+    pub name: String,
+    pub interval_seconds: i64,
+    pub instruction_budget: u64,
+    /// Set while a pass is under way.
+    pub cursor: Option<String>,
+    /// Seconds since the Unix epoch, as are the other times.
+    pub next_run_at: i64,
+    pub last_run_at: Option<i64>,
+    pub last_completed_at: Option<i64>,
+    pub last_instructions: Option<u64>,
+    pub last_error: Option<String>,
+    /// Completed passes since install.
+    pub passes: u64,
+}
+
+static JOBS: &[Job] = &[
+    Job {
+        name: WARM_OG_IMAGES,
+        interval_seconds: 24 * 60 * 60,
+        instruction_budget: 10_000_000_000,
+        run: ogimage::warm,
+    },
+    Job {
+        name: ANALYZE_IMAGES,
+        interval_seconds: 24 * 60 * 60,
+        instruction_budget: 10_000_000_000,
+        run: images::analyze_missing,
+    },
+    Job {
+        name: BACKFILL_CANONICAL_URLS,
+        interval_seconds: 24 * 60 * 60,
+        instruction_budget: 10_000_000_000,
+        run: canonical_url::backfill,
+    },
+];
+
+/// Add any newly registered jobs, due immediately. Call in `init` and
+/// `post_upgrade`, before arming the timer.
+pub fn init() -> Result<(), String> {
+    with_connection(|conn| {
+        for job in JOBS {
+            conn.execute(
+                "INSERT OR IGNORE INTO job (name, next_run_at) VALUES (?1, strftime('%s','now'))",
+                (job.name,),
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    })
+}
+
+/// When the next job is due, in nanoseconds since the epoch.
+pub fn next_run_at() -> Option<u64> {
+    JOBS.iter()
+        .filter_map(|job| state(job.name).ok().flatten())
+        .map(|(_, next_run_at)| next_run_at.max(0) as u64 * 1_000_000_000)
+        .min()
+}
+
+/// Run a slice of the job that has been due longest, if any, and return
+/// whether one ran. Called from the global timer. A single slice per call
+/// keeps the timer message well within the 40B instruction limit, and jobs
+/// still due are picked up on the next tick, which is armed right away.
+pub fn run_due() -> bool {
+    let now = (ic_cdk::api::time() / 1_000_000_000) as i64;
+    let due = JOBS
+        .iter()
+        .filter_map(|job| match state(job.name) {
+            Ok(Some((cursor, next_run_at))) if next_run_at <= now => {
+                Some((job, cursor, next_run_at))
+            }
+            Ok(_) => None,
+            Err(e) => {
+                ic_cdk::println!("Job {} could not be loaded: {e}", job.name);
+                None
+            }
+        })
+        .min_by_key(|(_, _, next_run_at)| *next_run_at);
+    let Some((job, cursor, _)) = due else {
+        return false;
+    };
+    if let Err(e) = run_slice(job, cursor) {
+        ic_cdk::println!("Job {} could not be recorded: {e}", job.name);
+    }
+    true
+}
+
+/// Run `name` on the next timer tick: continue the current pass, or start
+/// a new one. With `restart`, a pass under way is abandoned and started
+/// again from the beginning.
+pub fn trigger(name: &str, restart: bool) -> Result<(), String> {
+    if !JOBS.iter().any(|job| job.name == name) {
+        return Err(format!("No job {name}"));
+    }
+    with_connection(|conn| {
+        conn.execute(
+            "UPDATE job SET next_run_at = strftime('%s','now'),
+                            cursor = CASE WHEN ?2 THEN NULL ELSE cursor END
+             WHERE name = ?1",
+            (name, restart),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })?;
+    crate::schedule_global_timer();
+    Ok(())
+}
+
+/// The state of every registered job.
+pub fn statuses() -> Result<Vec<JobStatus>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT cursor, next_run_at, last_run_at, last_completed_at, last_instructions,
+                        last_error, passes
+                 FROM job WHERE name = ?1",
+            )
+            .map_err(|e| e.to_string())?;
+
+        JOBS.iter()
+            .map(|job| {
+                stmt.query_row((job.name,), |row| {
+                    Ok(JobStatus {
+                        name: job.name.to_string(),
+                        interval_seconds: job.interval_seconds,
+                        instruction_budget: job.instruction_budget,
+                        cursor: row.get(0)?,
+                        next_run_at: row.get(1)?,
+                        last_run_at: row.get(2)?,
+                        last_completed_at: row.get(3)?,
+                        last_instructions: row.get(4)?,
+                        last_error: row.get(5)?,
+                        passes: row.get(6)?,
+                    })
+                })
+                .map_err(|e| format!("Job {}: {e}", job.name))
+            })
+            .collect()
+    })
+}
+
+fn state(name: &str) -> Result<Option<(Option<String>, i64)>, String> {
+    with_connection(|conn| {
+        match conn.query_row(
+            "SELECT cursor, next_run_at FROM job WHERE name = ?1",
+            (name,),
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ) {
+            Ok(state) => Ok(Some(state)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+/// Run one slice of `job` and record how it went.
+fn run_slice(job: &Job, cursor: Option<String>) -> Result<(), String> {
+    let budget = Budget {
+        start: ic_cdk::api::instruction_counter(),
+        limit: job.instruction_budget,
+    };
+    let result = (job.run)(cursor.as_deref(), &budget);
+    let instructions = ic_cdk::api::instruction_counter() - budget.start;
+
+    with_connection(|conn| {
+        let (sql, params) = match &result {
+            Ok(Step::Continue(next)) => (
+                "UPDATE job SET cursor = ?2, next_run_at = strftime('%s','now'), last_error = NULL",
+                (job.name, Some(next.as_str()), 0),
+            ),
+            Ok(Step::Done) => (
+                "UPDATE job SET cursor = NULL, next_run_at = strftime('%s','now') + ?3,
+                                last_completed_at = strftime('%s','now'), last_error = NULL,
+                                passes = passes + 1",
+                (job.name, None, job.interval_seconds),
+            ),
+            Err(e) => (
+                "UPDATE job SET last_error = ?2, next_run_at = strftime('%s','now') + ?3",
+                (job.name, Some(e.as_str()), RETRY_AFTER_SECONDS),
+            ),
+        };
+        conn.execute(
+            &format!(
+                "{sql}, last_run_at = strftime('%s','now'), last_instructions = ?4 WHERE name = ?1"
+            ),
+            (params.0, params.1, params.2, instructions as i64),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
@@ -0,0 +1,67 @@
+use serde_json::{json, Value};
+
+use crate::app::app_types::App;
+use crate::{config, datetime};
+
+/// schema.org `SoftwareApplication` markup for an `/app/:id` page.
+pub fn software_application(app: &App) -> Value {
+    let origin = config::canonical_origin();
+
+    let mut data = json!({
+        "@context": "https://schema.org",
+        "@type": "SoftwareApplication",
+        "name": app.app_name.as_deref().unwrap_or(&app.title),
+        "headline": app.title,
+        "description": app.description,
+        "url": app.url,
+        "mainEntityOfPage": format!("{origin}/app/{}", app.id),
+        "applicationCategory": "WebApplication",
+        "operatingSystem": "Web",
+        "dateCreated": datetime::format_rfc3339(app.created_at),
+        "dateModified": datetime::format_rfc3339(app.updated_at),
+    });
+
+    if let Some(image_id) = &app.image_id {
+        data["screenshot"] = json!(format!("{origin}/images/{image_id}_1500.jpg"));
+    }
+    if let Some(author_name) = &app.author_name {
+        data["author"] = json!({
+            "@type": "Person",
+            "name": author_name,
+        });
+    }
+
+    data
+}
+
+/// schema.org `CollectionPage` markup for the home page, listing every app
+/// as an `ItemList`.
+pub fn collection_page(title: &str, description: &str, apps: &[App]) -> Value {
+    let origin = config::canonical_origin();
+
+    let items: Vec<Value> = apps
+        .iter()
+        .enumerate()
+        .map(|(i, app)| {
+            json!({
+                "@type": "ListItem",
+                "position": i + 1,
+                "url": format!("{origin}/app/{}", app.id),
+                "name": app.app_name.as_deref().unwrap_or(&app.title),
+            })
+        })
+        .collect();
+
+    json!({
+        "@context": "https://schema.org",
+        "@type": "CollectionPage",
+        "name": title,
+        "description": description,
+        "url": format!("{origin}/"),
+        "mainEntity": {
+            "@type": "ItemList",
+            "numberOfItems": items.len(),
+            "itemListElement": items,
+        },
+    })
+}
@@ -1,7 +1,21 @@
 mod app;
+mod config;
+mod datetime;
+mod escape;
+mod feed;
+mod frontend;
+mod images;
+mod jobs;
+mod json_ld;
+mod link_health;
+mod oembed;
 mod ogimage;
+mod page_meta;
 mod routes;
 mod seeds;
+mod sitemap;
+mod ssr;
+mod stats;
 
 mod route_tree {
     include!(concat!(env!("OUT_DIR"), "/__route_tree.rs"));
@@ -21,6 +35,42 @@ fn run_migrations_and_seeds() {
         ic_sql_migrate::sqlite::migrate(conn, MIGRATIONS).unwrap();
         ic_sql_migrate::sqlite::seed(conn, seeds::SEEDS).unwrap();
     });
+    invalidate_app_data_routes();
+}
+
+/// Give the calling test thread its own in-memory database, migrated and
+/// seeded.
+#[cfg(test)]
+pub(crate) fn use_test_database() {
+    // Before the first use of the connection, which opens it eagerly
+    ic_rusqlite::set_connection_config(ic_rusqlite::ConnectionConfig {
+        db_file_name: ":memory:".to_string(),
+        db_file_mount_id: None,
+        ..Default::default()
+    });
+    close_connection();
+    with_connection(|mut conn| {
+        let conn: &mut Connection = &mut conn;
+        ic_sql_migrate::sqlite::migrate(conn, MIGRATIONS).unwrap();
+        ic_sql_migrate::sqlite::seed(conn, seeds::SEEDS).unwrap();
+    });
+}
+
+/// Drop certified responses that are derived from the `app` table so they
+/// are regenerated on next request. Call after every write to app data.
+pub fn invalidate_app_data_routes() {
+    ic_asset_router::invalidate_path("/");
+    ic_asset_router::invalidate_path("/search");
+    ic_asset_router::invalidate_prefix("/api/");
+    ic_asset_router::invalidate_prefix("/app/");
+    ic_asset_router::invalidate_prefix("/author/");
+    ic_asset_router::invalidate_prefix("/og/");
+    ic_asset_router::invalidate_path("/sitemap.xml");
+    ic_asset_router::invalidate_path("/feed.xml");
+    ic_asset_router::invalidate_path("/atom.xml");
+    ic_asset_router::invalidate_prefix("/sitemap/");
+    ic_asset_router::invalidate_prefix("/stats/");
+    stats::reset();
 }
 
 fn setup_and_certify() {
@@ -41,12 +91,20 @@ fn setup_and_certify() {
             .delete_assets(vec!["/"])
             .build();
     });
+    // Uploaded frontend assets take precedence over the compiled-in ones
+    frontend::certify_uploaded().unwrap();
 }
 
 #[init]
-fn init() {
+fn init(args: Option<config::InitArgs>) {
     run_migrations_and_seeds();
+    config::init(args).unwrap();
     setup_and_certify();
+    jobs::init().unwrap();
+    // Also arms the global timer, which is cleared by upgrades
+    jobs::trigger(jobs::WARM_OG_IMAGES, true).unwrap();
+    jobs::trigger(jobs::ANALYZE_IMAGES, true).unwrap();
+    jobs::trigger(jobs::BACKFILL_CANONICAL_URLS, true).unwrap();
 }
 
 #[pre_upgrade]
@@ -55,9 +113,52 @@ fn pre_upgrade() {
 }
 
 #[post_upgrade]
-fn post_upgrade() {
+fn post_upgrade(args: Option<config::InitArgs>) {
     run_migrations_and_seeds();
+    config::init(args).unwrap();
     setup_and_certify();
+    jobs::init().unwrap();
+    // Passes under way carry on from their cursors. Also arms the global
+    // timer, which is cleared by upgrades
+    jobs::trigger(jobs::WARM_OG_IMAGES, false).unwrap();
+    jobs::trigger(jobs::ANALYZE_IMAGES, false).unwrap();
+    jobs::trigger(jobs::BACKFILL_CANONICAL_URLS, false).unwrap();
+}
+
+/// Entry point for the canister's global timer, which runs scheduled jobs
+/// and starts link-health checks.
+#[export_name = "canister_global_timer"]
+extern "C" fn canister_global_timer() {
+    ic_cdk::futures::in_executor_context(|| {
+        // One job slice or one link-check batch per tick, each well within
+        // the instruction limit. Whatever else is due runs on the next tick
+        if !jobs::run_due() {
+            link_health::run_due();
+        }
+        schedule_global_timer();
+    });
+}
+
+/// Arm the global timer for whatever is due first: the next job, or the
+/// next link-health check. There is a single global timer, so everything
+/// that uses it goes through here.
+pub fn schedule_global_timer() {
+    let now = ic_cdk::api::time();
+    let deadline = [jobs::next_run_at(), link_health::next_check_at()]
+        .into_iter()
+        .flatten()
+        .min();
+    if let Some(deadline) = deadline {
+        ic_cdk::api::global_timer_set(deadline.max(now));
+    }
+}
+
+/// Outcall transform for link-health checks.
+#[query]
+fn transform_link_check(
+    args: ic_cdk::management_canister::TransformArgs,
+) -> ic_cdk::management_canister::HttpRequestResult {
+    link_health::transform(args)
 }
 
 #[query]
@@ -68,11 +169,200 @@ pub fn http_request(req: HttpRequest) -> HttpResponse {
 
 #[update]
 fn http_request_update(req: HttpRequest) -> HttpResponse {
-    route_tree::ROUTES.with(|routes| ic_asset_router::http_request_update(req, routes))
+    let path = req.get_path().ok();
+    let mut response =
+        route_tree::ROUTES.with(|routes| ic_asset_router::http_request_update(req, routes));
+    if response.status_code().is_server_error() {
+        uncache_error(path.as_deref(), &mut response);
+    }
+    response
+}
+
+/// Where the router caches the response shared by every unmatched path.
+const NOT_FOUND_PATH: &str = "/__not_found";
+
+/// The router certifies and caches every response a handler returns, errors
+/// included. Drop a server error from the cache, so the next request renders
+/// again, and keep browsers and gateways from caching it either.
+fn uncache_error(path: Option<&str>, response: &mut HttpResponse) {
+    if let Some(path) = path {
+        ic_asset_router::invalidate_path(path);
+    }
+    // The error may have come from the not-found page, cached once for all
+    // unmatched paths
+    ic_asset_router::invalidate_path(NOT_FOUND_PATH);
+    let headers = response.headers_mut();
+    headers.retain(|(name, _)| !name.eq_ignore_ascii_case("cache-control"));
+    headers.push(("Cache-Control".into(), "no-store".into()));
+}
+
+/// Instruction cost of rendering an app's OG image with a freshly built
+/// renderer and with the shared one. Controllers only.
+#[query]
+fn benchmark_og_render(app_id: i64, runs: u32) -> Result<ogimage::RenderBenchmark, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can run benchmarks".to_string());
+    }
+    let app = app::AppManager::get_by_id(app_id)?;
+    ogimage::benchmark(
+        &app,
+        ogimage::OgVariant::Landscape,
+        ogimage::OgFormat::Png,
+        runs,
+    )
+}
+
+/// The state of every scheduled job. Controllers only.
+#[query]
+fn list_jobs() -> Result<Vec<jobs::JobStatus>, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can list jobs".to_string());
+    }
+    jobs::statuses()
+}
+
+/// Run the job `name` on the next timer tick, continuing the pass under
+/// way unless `restart` is set. Controllers only.
+#[update]
+fn run_job(name: String, restart: bool) -> Result<(), String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can run jobs".to_string());
+    }
+    jobs::trigger(&name, restart)
+}
+
+/// Upload a new version of an OG card template or of the background image.
+/// It is validated and stored, but not used until activated; try it first at
+/// `/og/preview?template=<preview_key>`. Controllers only.
+#[update]
+async fn upload_og_template(
+    name: String,
+    data: Vec<u8>,
+) -> Result<ogimage::OgTemplateVersion, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload OG templates".to_string());
+    }
+    // Unguessable, since previews are served to anyone who has the key
+    let random = ic_cdk::management_canister::raw_rand()
+        .await
+        .map_err(|e| format!("raw_rand failed: {e}"))?;
+    let preview_key: String = random.iter().map(|b| format!("{b:02x}")).collect();
+    ogimage::upload_template(&name, data, &preview_key)
+}
+
+/// Switch the OG template or background `name` to an uploaded version, or
+/// back to the built-in one with `null`. Controllers only.
+#[update]
+fn activate_og_template(name: String, version: Option<u32>) -> Result<(), String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can activate OG templates".to_string());
+    }
+    ogimage::activate_template(&name, version)
+}
+
+/// Every uploaded OG template and background version. Controllers only.
+#[query]
+fn list_og_templates() -> Result<Vec<ogimage::OgTemplateVersion>, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can list OG templates".to_string());
+    }
+    ogimage::list_templates()
+}
+
+/// Start a screenshot upload. Send the image with `upload_image_chunk`, in
+/// as many chunks as the ingress limit requires, then `commit_image_upload`.
+/// Controllers only.
+#[update]
+fn create_image_upload() -> Result<u64, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload images".to_string());
+    }
+    images::create_upload()
+}
+
+/// Append a chunk to a screenshot upload. Controllers only.
+#[update]
+fn upload_image_chunk(upload_id: u64, chunk: Vec<u8>) -> Result<(), String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload images".to_string());
+    }
+    images::upload_chunk(upload_id, &chunk)
+}
+
+/// Validate and store an uploaded screenshot, optionally making it the
+/// screenshot of `app_id`. Controllers only.
+#[update]
+fn commit_image_upload(upload_id: u64, app_id: Option<i64>) -> Result<images::StoredImage, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload images".to_string());
+    }
+    images::commit_upload(upload_id, app_id)
+}
+
+/// Apps whose screenshot is missing or looks broken: blank, nearly a single
+/// colour, or the same picture as several other apps. Controllers only.
+#[query]
+fn screenshot_quality_report() -> Result<Vec<images::ScreenshotQuality>, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can view the screenshot report".to_string());
+    }
+    images::quality_report()
+}
+
+/// Apps whose URL is the same page as an earlier app's, once canonicalized.
+/// They are recorded by the `backfill_canonical_urls` job. Controllers only.
+#[query]
+fn duplicate_app_urls() -> Result<Vec<app::canonical_url::DuplicateUrl>, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can view duplicate app URLs".to_string());
+    }
+    app::canonical_url::duplicates()
+}
+
+/// Start a frontend asset batch. Upload content with `upload_asset_chunk`,
+/// then replace the served frontend with `commit_asset_batch`. Controllers
+/// only.
+#[update]
+fn create_asset_batch() -> Result<u64, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload assets".to_string());
+    }
+    frontend::create_batch()
+}
+
+/// Add a chunk of asset content to a batch and return its ID. Controllers
+/// only.
+#[update]
+fn upload_asset_chunk(batch_id: u64, content: Vec<u8>) -> Result<u64, String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload assets".to_string());
+    }
+    frontend::upload_chunk(batch_id, &content)
+}
+
+/// Check and serve a batch's assets in place of the current frontend.
+/// Controllers only.
+#[update]
+fn commit_asset_batch(batch_id: u64, assets: Vec<frontend::CommitAsset>) -> Result<(), String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can upload assets".to_string());
+    }
+    frontend::commit_batch(batch_id, assets)
+}
+
+/// Drop uploaded frontend assets and serve the compiled-in build again.
+/// Controllers only.
+#[update]
+fn reset_frontend_assets() -> Result<(), String> {
+    if !ic_cdk::api::is_controller(&ic_cdk::api::msg_caller()) {
+        return Err("Only controllers can reset assets".to_string());
+    }
+    frontend::reset()
 }
 
-/// Get the base URL for serving images. Images are bundled in the canister,
-/// so this returns an empty string for canister-relative paths.
+/// Get the base URL for serving images. Screenshots are served by the
+/// canister itself under `/images/`, so this returns an empty string for
+/// canister-relative paths.
 pub fn get_image_base_url() -> String {
     String::new()
 }
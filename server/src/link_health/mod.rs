@@ -0,0 +1,468 @@
+//! Periodic checks, made with HTTP outcalls, that each app's URL still
+//! responds. Only the latest check of each app is kept, along with when the
+//! app was last seen alive and since when it has been unreachable.
+
+use std::cell::Cell;
+
+use ic_cdk::management_canister::{
+    http_request, transform_context_from_query, HttpHeader, HttpMethod, HttpRequestArgs,
+    HttpRequestResult, TransformArgs,
+};
+use ic_rusqlite::with_connection;
+
+/// How often each app is checked.
+const CHECK_INTERVAL_SECONDS: i64 = 24 * 60 * 60;
+/// Apps checked per timer tick, one after another. The rest are picked up
+/// by the next tick, so a run survives upgrades.
+const BATCH_SIZE: i64 = 20;
+/// Checks are `HEAD` requests, so this only needs to fit the headers.
+/// Larger responses fail the outcall and count as unreachable.
+const MAX_RESPONSE_BYTES: u64 = 16 * 1024;
+/// Apps unreachable for longer than this are listed after the others.
+pub const DEPRIORITIZE_AFTER_SECONDS: i64 = 7 * 24 * 60 * 60;
+/// Wait after an outcall could not be made at all, e.g. for lack of cycles.
+const RETRY_AFTER_NANOS: u64 = 60 * 60 * 1_000_000_000;
+
+thread_local! {
+    static RUNNING: Cell<bool> = const { Cell::new(false) };
+    /// Nanoseconds since the epoch before which no checks are made.
+    static PAUSED_UNTIL: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The outcome of one check.
+struct Check {
+    status_code: Option<u16>,
+    latency_ms: u64,
+    error: Option<String>,
+}
+
+impl Check {
+    /// The outcome of an outcall that got a response.
+    fn answered(response: &HttpRequestResult, latency_ms: u64) -> Check {
+        Check {
+            status_code: u16::try_from(&response.status.0).ok(),
+            latency_ms,
+            error: None,
+        }
+    }
+
+    /// The outcome of an outcall that got no response, e.g. because the
+    /// host did not resolve or the connection was refused.
+    fn unreachable(error: String, latency_ms: u64) -> Check {
+        Check {
+            status_code: None,
+            latency_ms,
+            error: Some(error),
+        }
+    }
+
+    /// Whether the server answered. Refusing a `HEAD` request or an
+    /// anonymous client still means the app is up.
+    fn is_alive(&self) -> bool {
+        self.status_code
+            .is_some_and(|code| (200..400).contains(&code) || matches!(code, 401 | 403 | 405 | 429))
+    }
+}
+
+/// When the next check is due, in nanoseconds since the epoch, or `None`
+/// while a batch is running or if there are no apps.
+pub fn next_check_at() -> Option<u64> {
+    if RUNNING.get() {
+        return None;
+    }
+    let due: Option<i64> = with_connection(|conn| {
+        conn.query_row(
+            "SELECT MIN(COALESCE(checked_at + ?1, 0))
+             FROM app LEFT JOIN link_check ON link_check.app_id = app.id",
+            (CHECK_INTERVAL_SECONDS,),
+            |row| row.get(0),
+        )
+        .ok()
+        .flatten()
+    });
+    due.map(|seconds| (seconds.max(0) as u64 * 1_000_000_000).max(PAUSED_UNTIL.get()))
+}
+
+/// Check the apps that are due, in the background. Does nothing while a
+/// batch is running. Call from the global timer; the timer is re-armed when
+/// the batch is done.
+pub fn run_due() {
+    if RUNNING.get() || ic_cdk::api::time() < PAUSED_UNTIL.get() {
+        return;
+    }
+    let apps = match due_apps() {
+        Ok(apps) => apps,
+        Err(e) => {
+            ic_cdk::println!("Link check failed: {e}");
+            return;
+        }
+    };
+    if apps.is_empty() {
+        return;
+    }
+
+    RUNNING.set(true);
+    ic_cdk::futures::spawn(async move {
+        let mut changed = false;
+        for (app_id, url) in apps {
+            let check = match check(&url).await {
+                Ok(check) => check,
+                Err(e) => {
+                    // Not the app's fault, e.g. out of cycles, so retry later
+                    ic_cdk::println!("Link check could not be made for app {app_id}: {e}");
+                    PAUSED_UNTIL.set(ic_cdk::api::time() + RETRY_AFTER_NANOS);
+                    break;
+                }
+            };
+            match record(app_id, &check) {
+                Ok(flipped) => changed |= flipped,
+                Err(e) => ic_cdk::println!("Link check failed for app {app_id}: {e}"),
+            }
+        }
+        RUNNING.set(false);
+        if changed {
+            crate::invalidate_app_data_routes();
+        }
+        crate::schedule_global_timer();
+    });
+}
+
+/// Transform for the check outcalls. Replicas must agree on the response,
+/// so everything but the status is dropped: headers such as `Date` and
+/// `Set-Cookie` differ between replicas.
+pub fn transform(args: TransformArgs) -> HttpRequestResult {
+    HttpRequestResult {
+        status: args.response.status,
+        headers: Vec::new(),
+        body: Vec::new(),
+    }
+}
+
+fn due_apps() -> Result<Vec<(i64, String)>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT app.id, app.url
+                 FROM app LEFT JOIN link_check ON link_check.app_id = app.id
+                 WHERE checked_at IS NULL OR checked_at <= strftime('%s','now') - ?1
+                 ORDER BY checked_at IS NOT NULL, checked_at, app.id
+                 LIMIT ?2",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((CHECK_INTERVAL_SECONDS, BATCH_SIZE), |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// Send a `HEAD` request to `url`. Latency includes the time the subnet
+/// takes to agree on the response, so it is only useful for comparisons.
+/// Fails only if the outcall could not be made; an unreachable app is a
+/// [`Check`] without a status code.
+async fn check(url: &str) -> Result<Check, String> {
+    let args = HttpRequestArgs {
+        transform: Some(transform_context_from_query(
+            "transform_link_check".to_string(),
+            Vec::new(),
+        )),
+        ..request(url)
+    };
+    let started = ic_cdk::api::time();
+    let result = http_request(&args).await;
+    let latency_ms = (ic_cdk::api::time() - started) / 1_000_000;
+    match result {
+        Ok(response) => Ok(Check::answered(&response, latency_ms)),
+        Err(ic_cdk::call::Error::CallRejected(e)) => {
+            Ok(Check::unreachable(e.to_string(), latency_ms))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// The outcall [`check`] makes for `url`, but for the transform, which
+/// names this canister.
+fn request(url: &str) -> HttpRequestArgs {
+    HttpRequestArgs {
+        url: url.to_string(),
+        max_response_bytes: Some(MAX_RESPONSE_BYTES),
+        method: HttpMethod::HEAD,
+        headers: vec![HttpHeader {
+            name: "User-Agent".to_string(),
+            value: "promptathon-showcase-link-check".to_string(),
+        }],
+        body: None,
+        transform: None,
+    }
+}
+
+/// Store the check, returning whether the app went up or down.
+fn record(app_id: i64, check: &Check) -> Result<bool, String> {
+    let alive = check.is_alive();
+    with_connection(|conn| {
+        let was_alive: Option<bool> = conn
+            .query_row(
+                "SELECT dead_since IS NULL FROM link_check WHERE app_id = ?1",
+                (app_id,),
+                |row| row.get(0),
+            )
+            .ok();
+        conn.execute(
+            "INSERT INTO link_check (app_id, status_code, latency_ms, error, checked_at,
+                                     last_alive_at, dead_since)
+             VALUES (?1, ?2, ?3, ?4, strftime('%s','now'),
+                     CASE WHEN ?5 THEN strftime('%s','now') END,
+                     CASE WHEN ?5 THEN NULL ELSE strftime('%s','now') END)
+             ON CONFLICT (app_id) DO UPDATE
+             SET status_code = excluded.status_code, latency_ms = excluded.latency_ms,
+                 error = excluded.error, checked_at = excluded.checked_at,
+                 last_alive_at = COALESCE(excluded.last_alive_at, last_alive_at),
+                 dead_since = CASE WHEN ?5 THEN NULL ELSE COALESCE(dead_since, excluded.dead_since) END",
+            (
+                app_id,
+                check.status_code,
+                check.latency_ms as i64,
+                &check.error,
+                alive,
+            ),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(was_alive != Some(alive))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    use super::*;
+
+    fn check_with_status(status_code: Option<u16>) -> Check {
+        Check {
+            status_code,
+            latency_ms: 100,
+            error: None,
+        }
+    }
+
+    fn response(status: u16, headers: &[(&str, &str)], body: &[u8]) -> HttpRequestResult {
+        HttpRequestResult {
+            status: status.into(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| HttpHeader {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+            body: body.to_vec(),
+        }
+    }
+
+    /// `(last_alive_at, dead_since)` of the app's check.
+    fn liveness(app_id: i64) -> (Option<i64>, Option<i64>) {
+        with_connection(|conn| {
+            conn.query_row(
+                "SELECT last_alive_at, dead_since FROM link_check WHERE app_id = ?1",
+                (app_id,),
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+        })
+    }
+
+    fn first_app_id() -> i64 {
+        with_connection(|conn| {
+            conn.query_row("SELECT MIN(id) FROM app", (), |row| row.get(0))
+                .unwrap()
+        })
+    }
+
+    /// Serve one connection per status in `statuses`, answering each with
+    /// that status, and return the server's URL along with the request
+    /// heads it received.
+    fn stub_server(statuses: Vec<u16>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/app", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            statuses
+                .into_iter()
+                .map(|status| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut head = String::new();
+                    let mut reader = BufReader::new(&stream);
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                        head.push_str(&line);
+                    }
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Stub\r\nDate: Mon, 19 Oct 2026 12:00:00 GMT\r\n\
+                         Set-Cookie: session=abc\r\nContent-Length: 5\r\n\r\nhello"
+                    )
+                    .unwrap();
+                    head
+                })
+                .collect()
+        });
+        (url, server)
+    }
+
+    /// Make the outcall `args` describes from the test process, as the
+    /// replicas would.
+    fn fetch(args: &HttpRequestArgs) -> Result<HttpRequestResult, String> {
+        let address = args
+            .url
+            .strip_prefix("http://")
+            .and_then(|rest| rest.split('/').next())
+            .ok_or("Only http:// URLs are supported")?;
+        let path = &args.url["http://".len() + address.len()..];
+        let method = match args.method {
+            HttpMethod::GET => "GET",
+            HttpMethod::POST => "POST",
+            HttpMethod::HEAD => "HEAD",
+        };
+
+        let mut stream = TcpStream::connect(address).map_err(|e| e.to_string())?;
+        write!(stream, "{method} {path} HTTP/1.1\r\nHost: {address}\r\n").unwrap();
+        for header in &args.headers {
+            write!(stream, "{}: {}\r\n", header.name, header.value).unwrap();
+        }
+        write!(stream, "Connection: close\r\n\r\n").unwrap();
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).map_err(|e| e.to_string())?;
+        let split = raw
+            .windows(4)
+            .position(|w| w == b"\r\n\r\n")
+            .ok_or("Incomplete response")?;
+        let head = String::from_utf8_lossy(&raw[..split]).to_string();
+        let mut lines = head.split("\r\n");
+        let status: u16 = lines
+            .next()
+            .and_then(|line| line.split(' ').nth(1))
+            .and_then(|code| code.parse().ok())
+            .ok_or("Bad status line")?;
+        let headers: Vec<(&str, &str)> = lines.filter_map(|line| line.split_once(": ")).collect();
+        Ok(response(status, &headers, &raw[split + 4..]))
+    }
+
+    #[test]
+    fn alive_statuses() {
+        for code in [200, 204, 301, 302, 304, 401, 403, 405, 429] {
+            assert!(check_with_status(Some(code)).is_alive(), "{code}");
+        }
+        for code in [100, 400, 404, 410, 500, 502, 503] {
+            assert!(!check_with_status(Some(code)).is_alive(), "{code}");
+        }
+        assert!(!check_with_status(None).is_alive());
+    }
+
+    #[test]
+    fn transform_keeps_only_the_status() {
+        let transformed = transform(TransformArgs {
+            response: response(
+                301,
+                &[("Date", "Mon, 19 Oct 2026 12:00:00 GMT"), ("Location", "/")],
+                b"Moved",
+            ),
+            context: Vec::new(),
+        });
+        assert_eq!(transformed, response(301, &[], b""));
+    }
+
+    #[test]
+    fn record_keeps_last_alive_at_and_dead_since() {
+        crate::use_test_database();
+        let app_id = first_app_id();
+
+        // First check: up
+        assert!(record(app_id, &check_with_status(Some(200))).unwrap());
+        let (last_alive_at, dead_since) = liveness(app_id);
+        assert!(last_alive_at.is_some());
+        assert_eq!(dead_since, None);
+
+        // Pretend the app was last alive long ago, then it goes down
+        with_connection(|conn| {
+            conn.execute(
+                "UPDATE link_check SET last_alive_at = 1000 WHERE app_id = ?1",
+                (app_id,),
+            )
+            .unwrap()
+        });
+        assert!(record(app_id, &check_with_status(Some(503))).unwrap());
+        let (last_alive_at, dead_since) = liveness(app_id);
+        assert_eq!(last_alive_at, Some(1000));
+        assert!(dead_since.is_some());
+
+        // Still down: neither time moves
+        with_connection(|conn| {
+            conn.execute(
+                "UPDATE link_check SET dead_since = 2000 WHERE app_id = ?1",
+                (app_id,),
+            )
+            .unwrap()
+        });
+        assert!(!record(app_id, &Check::unreachable("refused".to_string(), 5)).unwrap());
+        assert_eq!(liveness(app_id), (Some(1000), Some(2000)));
+
+        // Back up
+        assert!(record(app_id, &check_with_status(Some(405))).unwrap());
+        let (last_alive_at, dead_since) = liveness(app_id);
+        assert!(last_alive_at.is_some_and(|at| at > 2000));
+        assert_eq!(dead_since, None);
+    }
+
+    #[test]
+    fn checks_a_local_server() {
+        crate::use_test_database();
+        let app_id = first_app_id();
+        let (url, server) = stub_server(vec![405, 503]);
+        let args = request(&url);
+
+        let outcome = |args: &HttpRequestArgs| match fetch(args) {
+            Ok(response) => {
+                let response = transform(TransformArgs {
+                    response,
+                    context: Vec::new(),
+                });
+                assert!(response.headers.is_empty() && response.body.is_empty());
+                Check::answered(&response, 1)
+            }
+            Err(e) => Check::unreachable(e, 1),
+        };
+
+        // Refusing HEAD still means the app is up
+        let check = outcome(&args);
+        assert_eq!(check.status_code, Some(405));
+        assert!(record(app_id, &check).unwrap());
+
+        let check = outcome(&args);
+        assert_eq!(check.status_code, Some(503));
+        assert!(record(app_id, &check).unwrap());
+        assert!(liveness(app_id).1.is_some());
+
+        let heads = server.join().unwrap();
+        for head in &heads {
+            assert!(head.starts_with("HEAD /app HTTP/1.1\r\n"), "{head}");
+            assert!(head.contains("User-Agent: promptathon-showcase-link-check\r\n"));
+        }
+
+        // Nothing listens on the port any more
+        let check = outcome(&args);
+        assert_eq!(check.status_code, None);
+        assert!(check.error.is_some());
+        assert!(!record(app_id, &check).unwrap());
+    }
+}
@@ -0,0 +1,98 @@
+use serde::Serialize;
+
+use crate::app::app_types::App;
+use crate::{config, ssr};
+
+pub(crate) const EMBED_CARD_TEMPLATE: &str = include_str!("includes/embed_card.html");
+
+const PROVIDER_NAME: &str = "Caffeine January Promptathon Showcase";
+
+/// Default size of the embed card iframe. Consumers may ask for a smaller
+/// card through `maxwidth` / `maxheight`.
+const CARD_WIDTH: u32 = 560;
+const CARD_HEIGHT: u32 = 180;
+
+/// An oEmbed 1.0 `rich` response.
+#[derive(Serialize)]
+pub struct OEmbedResponse {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub version: &'static str,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    pub provider_name: &'static str,
+    pub provider_url: String,
+    pub html: String,
+    pub width: u32,
+    pub height: u32,
+    pub thumbnail_url: String,
+    pub thumbnail_width: u32,
+    pub thumbnail_height: u32,
+}
+
+/// Extract the app id from an `/app/:id` page URL on this site.
+///
+/// Both `http` and `https` are accepted, as is a trailing slash, query string
+/// or fragment. Returns `None` for URLs on other hosts or other paths.
+pub fn parse_app_url(url: &str) -> Option<i64> {
+    let origin = config::canonical_origin();
+    let host = origin.split_once("://").map_or(origin.as_str(), |(_, h)| h);
+
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let path = rest.strip_prefix(host)?;
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let id = path.strip_prefix("/app/")?.trim_end_matches('/');
+
+    id.parse().ok()
+}
+
+/// Build the oEmbed response for an app, honoring the consumer's
+/// `maxwidth` / `maxheight` limits.
+pub fn response(app: &App, max_width: Option<u32>, max_height: Option<u32>) -> OEmbedResponse {
+    let origin = config::canonical_origin();
+    let width = max_width.map_or(CARD_WIDTH, |w| w.min(CARD_WIDTH));
+    let height = max_height.map_or(CARD_HEIGHT, |h| h.min(CARD_HEIGHT));
+
+    let html = format!(
+        r#"<iframe src="{origin}/embed/app/{}" width="{width}" height="{height}" style="border:0;border-radius:12px;overflow:hidden" loading="lazy" title="{}"></iframe>"#,
+        app.id,
+        crate::escape::xml(display_name(app)),
+    );
+
+    OEmbedResponse {
+        kind: "rich",
+        version: "1.0",
+        title: display_name(app).to_string(),
+        author_name: app.author_name.clone(),
+        provider_name: PROVIDER_NAME,
+        provider_url: format!("{origin}/"),
+        html,
+        width,
+        height,
+        thumbnail_url: format!("{origin}/app/{}/og-thumb.jpg", app.id),
+        thumbnail_width: 600,
+        thumbnail_height: 315,
+    }
+}
+
+/// Render the standalone HTML embed card served at `/embed/app/:id`.
+pub fn render_card(app: &App) -> Result<String, String> {
+    let origin = config::canonical_origin();
+
+    let ctx = minijinja::context! {
+        origin => origin,
+        page_url => format!("{origin}/app/{}", app.id),
+        name => display_name(app),
+        title => app.title,
+        author_name => app.author_name,
+        image_url => app.image_id.as_ref().map(|id| format!("{origin}/images/{id}_300.jpg")),
+    };
+    ssr::render_embed_card(&ctx)
+}
+
+fn display_name(app: &App) -> &str {
+    app.app_name.as_deref().unwrap_or(&app.title)
+}
@@ -0,0 +1,87 @@
+use ic_rusqlite::with_connection;
+
+/// How long a search card is reused, in seconds.
+const SEARCH_CARD_TTL_SECONDS: i64 = 24 * 60 * 60;
+/// Most search cards kept at once; the oldest are dropped beyond this.
+const MAX_SEARCH_CARDS: i64 = 500;
+
+/// Look up a persisted image. Returns `None` if there is none, or if it was
+/// rendered from different inputs.
+pub fn get(app_id: i64, file_name: &str, input_hash: &str) -> Result<Option<Vec<u8>>, String> {
+    with_connection(|conn| {
+        match conn.query_row(
+            "SELECT data FROM og_image WHERE app_id = ?1 AND file_name = ?2 AND input_hash = ?3",
+            (app_id, file_name, input_hash),
+            |row| row.get(0),
+        ) {
+            Ok(data) => Ok(Some(data)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+/// Persist an image, replacing any stale copy of the same file.
+pub fn put(app_id: i64, file_name: &str, input_hash: &str, data: &[u8]) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT INTO og_image (app_id, file_name, input_hash, data) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT (app_id, file_name) DO UPDATE
+             SET input_hash = excluded.input_hash, data = excluded.data",
+            (app_id, file_name, input_hash, data),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
+
+/// Look up the persisted search card for `query`. Returns `None` if there
+/// is none, or if it is stale or older than [`SEARCH_CARD_TTL_SECONDS`].
+pub fn get_search_card(query: &str, input_hash: &str) -> Result<Option<Vec<u8>>, String> {
+    with_connection(|conn| {
+        match conn.query_row(
+            "SELECT data FROM search_card
+             WHERE query = ?1 AND input_hash = ?2 AND rendered_at > strftime('%s','now') - ?3",
+            (query, input_hash, SEARCH_CARD_TTL_SECONDS),
+            |row| row.get(0),
+        ) {
+            Ok(data) => Ok(Some(data)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })
+}
+
+/// Persist a search card, then drop expired cards and all but the newest
+/// [`MAX_SEARCH_CARDS`].
+pub fn put_search_card(query: &str, input_hash: &str, data: &[u8]) -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute(
+            "INSERT INTO search_card (query, input_hash, data, rendered_at)
+             VALUES (?1, ?2, ?3, strftime('%s','now'))
+             ON CONFLICT (query) DO UPDATE
+             SET input_hash = excluded.input_hash, data = excluded.data,
+                 rendered_at = excluded.rendered_at",
+            (query, input_hash, data),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "DELETE FROM search_card
+             WHERE rendered_at <= strftime('%s','now') - ?1
+                OR query NOT IN (
+                    SELECT query FROM search_card ORDER BY rendered_at DESC LIMIT ?2
+                )",
+            (SEARCH_CARD_TTL_SECONDS, MAX_SEARCH_CARDS),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })
+}
+
+/// Drop every persisted image, e.g. after a template change made them stale.
+pub fn clear() -> Result<(), String> {
+    with_connection(|conn| {
+        conn.execute_batch("DELETE FROM og_image; DELETE FROM search_card;")
+            .map_err(|e| e.to_string())
+    })
+}
@@ -0,0 +1,292 @@
+use crate::app::app_types::App;
+use crate::images::{self, ImageFormat};
+
+use super::text_layout::{self, Alignment, TextBox};
+use super::{
+    base64_encode, cache, escape_lines, sha256_hex, with_renderer, Card, OgFormat, Renderer,
+    RENDER_VERSION,
+};
+
+/// Templates for the cards of pages that are not about a single app.
+pub(super) const TEMPLATES: [(&str, &str); 2] = [
+    ("collection", include_str!("includes/og_collection.svg")),
+    ("author", include_str!("includes/og_author.svg")),
+];
+
+/// These cards are only linked from page metadata, so unlike app cards they
+/// come in a single size and format.
+const SIZE: (u32, u32) = (1200, 630);
+const FORMAT: OgFormat = OgFormat::Png;
+
+/// Left edge of the text, and its width when it spans the whole card.
+const TEXT_X: f32 = 55.0;
+const FULL_WIDTH: f32 = 1090.0;
+
+const MAX_TILES: usize = 9;
+const MAX_APP_LINES: usize = 4;
+/// Longest query a search card is rendered for, in characters.
+const MAX_QUERY_CHARS: usize = 40;
+
+/// The home page card: `title`, the number of submissions and a mosaic of
+/// the newest screenshots.
+pub fn home_card(title: &str, apps: &[App]) -> Result<Vec<u8>, String> {
+    let mut newest: Vec<&App> = apps.iter().collect();
+    newest.sort_by_key(|app| std::cmp::Reverse(app.created_at));
+    let subtitle = match apps.len() {
+        1 => "1 app submitted".to_string(),
+        n => format!("{n} apps submitted"),
+    };
+    with_renderer(|renderer| {
+        renderer.draw(
+            renderer.collection_card(None, title, &subtitle, &newest),
+            FORMAT,
+        )
+    })
+}
+
+/// The form of a search query its card is rendered and persisted for:
+/// whitespace collapsed, ASCII lowercased like SQLite's `LIKE` matching, and
+/// cut to [`MAX_QUERY_CHARS`], so variants of a query share one card.
+pub fn search_card_query(query: &str) -> String {
+    let query: String = query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase()
+        .chars()
+        .take(MAX_QUERY_CHARS)
+        .collect();
+    query.trim_end().to_string()
+}
+
+/// The card for `/search?q=`: the query, the number of results and a mosaic
+/// of the top results' screenshots. `query` is normalized by
+/// [`search_card_query`]. Queries without results get the generic search
+/// card, so only queries that find something cost a render of their own, and
+/// each card is persisted for reuse, see [`cache::put_search_card`].
+pub fn search_card(query: &str, apps: &[App]) -> Result<Vec<u8>, String> {
+    let query = if apps.is_empty() { "" } else { query };
+    let image_ids: Vec<&str> = apps
+        .iter()
+        .filter_map(|app| app.image_id.as_deref())
+        .take(MAX_TILES)
+        .collect();
+    let fingerprint = with_renderer(|renderer| Ok(renderer.fingerprint.clone()))?;
+    let input_hash = sha256_hex(&[
+        RENDER_VERSION.to_string().as_bytes(),
+        fingerprint.as_bytes(),
+        query.as_bytes(),
+        apps.len().to_string().as_bytes(),
+        image_ids.join(",").as_bytes(),
+    ]);
+    if let Some(data) = cache::get_search_card(query, &input_hash)? {
+        return Ok(data);
+    }
+
+    let results: Vec<&App> = apps.iter().collect();
+    let data = with_renderer(|renderer| {
+        let card = if query.is_empty() {
+            renderer.collection_card(
+                None,
+                "Search",
+                "Find apps submitted to the January Caffeine promptathon",
+                &[],
+            )
+        } else {
+            let subtitle = match apps.len() {
+                1 => "1 app found".to_string(),
+                n => format!("{n} apps found"),
+            };
+            renderer.collection_card(
+                Some("SEARCH RESULTS"),
+                &format!("\u{201c}{query}\u{201d}"),
+                &subtitle,
+                &results,
+            )
+        };
+        renderer.draw(card, FORMAT)
+    })?;
+    cache::put_search_card(query, &input_hash, &data)?;
+    Ok(data)
+}
+
+/// The card for `/author/:name`, listing the author's app names.
+pub fn author_card(author: &str, apps: &[App]) -> Result<Vec<u8>, String> {
+    let app_names: Vec<&str> = apps
+        .iter()
+        .map(|app| app.app_name.as_deref().unwrap_or(&app.title))
+        .collect();
+    with_renderer(|renderer| renderer.draw(renderer.author_card(author, &app_names), FORMAT))
+}
+
+impl Renderer {
+    /// A heading and subtitle on the left, with screenshots of up to
+    /// [`MAX_TILES`] of `apps` on the right. Without screenshots the text
+    /// spans the full width.
+    pub(super) fn collection_card(
+        &self,
+        label: Option<&str>,
+        heading: &str,
+        subtitle: &str,
+        apps: &[&App],
+    ) -> Card {
+        let tiles: Vec<String> = apps
+            .iter()
+            .filter_map(|app| app.image_id.as_deref().and_then(thumbnail))
+            .take(MAX_TILES)
+            .map(|jpeg| format!("data:image/jpeg;base64,{}", base64_encode(&jpeg)))
+            .collect();
+
+        let max_width = if tiles.is_empty() { FULL_WIDTH } else { 470.0 };
+        // The label takes the first line, so the heading gets one fewer
+        let (heading_y, heading_max_lines) = match label {
+            Some(_) => (150.0, 2),
+            None => (100.0, 3),
+        };
+        let heading = text_layout::layout(
+            &self.name_fonts,
+            heading,
+            &TextBox {
+                max_width,
+                max_lines: heading_max_lines,
+                max_size: 64.0,
+                min_size: 40.0,
+            },
+        );
+        let subtitle = text_layout::layout(
+            &self.title_fonts,
+            subtitle,
+            &TextBox {
+                max_width,
+                max_lines: 2,
+                max_size: 36.0,
+                min_size: 26.0,
+            },
+        );
+        let heading_align = heading.align(TEXT_X, max_width);
+        let subtitle_align = subtitle.align(TEXT_X, max_width);
+        let subtitle_y = heading_y
+            + heading.lines.len().saturating_sub(1) as f32 * heading.size * 1.15
+            + subtitle.size * 1.75;
+
+        let ctx = minijinja::context! {
+            label => label,
+            label_y => 80.0,
+            heading_size => heading.size,
+            heading_x => heading_align.x,
+            heading_anchor => heading_align.anchor,
+            heading_y => heading_y,
+            heading_lines => escape_lines(heading.lines),
+            subtitle_size => subtitle.size,
+            subtitle_x => subtitle_align.x,
+            subtitle_anchor => subtitle_align.anchor,
+            subtitle_y => subtitle_y,
+            subtitle_lines => escape_lines(subtitle.lines),
+            background_data_uri => self.background_data_uri,
+            tiles => tiles,
+        };
+        Card {
+            template: "collection",
+            ctx,
+            size: SIZE,
+        }
+    }
+
+    /// The author's name with up to [`MAX_APP_LINES`] lines of app names
+    /// below it, the last one summarizing the rest when they do not fit.
+    pub(super) fn author_card(&self, author: &str, app_names: &[&str]) -> Card {
+        let author = text_layout::layout(
+            &self.name_fonts,
+            author,
+            &TextBox {
+                max_width: FULL_WIDTH,
+                max_lines: 1,
+                max_size: 64.0,
+                min_size: 40.0,
+            },
+        );
+        let author_align = author.align(TEXT_X, FULL_WIDTH);
+
+        let app_box = TextBox {
+            max_width: FULL_WIDTH,
+            max_lines: 1,
+            max_size: 34.0,
+            min_size: 34.0,
+        };
+        let shown = if app_names.len() > MAX_APP_LINES {
+            MAX_APP_LINES - 1
+        } else {
+            app_names.len()
+        };
+        let more_count = app_names.len() - shown;
+        // Each app name is aligned on its own, since they mix scripts
+        let mut app_lines = Vec::new();
+        let mut app_aligns = Vec::new();
+        for name in &app_names[..shown] {
+            let laid_out = text_layout::layout(&self.title_fonts, name, &app_box);
+            for line in laid_out.lines.iter() {
+                app_lines.push(line.clone());
+                app_aligns.push(laid_out.align(TEXT_X, FULL_WIDTH));
+            }
+        }
+        if more_count > 0 {
+            app_lines.push(format!("+ {more_count} more"));
+            app_aligns.push(Alignment {
+                x: TEXT_X,
+                anchor: "start",
+            });
+        }
+
+        let ctx = minijinja::context! {
+            author_size => author.size,
+            author_x => author_align.x,
+            author_anchor => author_align.anchor,
+            author => escape_lines(author.lines).concat(),
+            app_size => app_box.max_size,
+            app_lines => escape_lines(app_lines),
+            app_aligns => app_aligns,
+            more_count => more_count,
+            background_data_uri => self.background_data_uri,
+        };
+        Card {
+            template: "author",
+            ctx,
+            size: SIZE,
+        }
+    }
+}
+
+/// The 300px JPEG screenshot for `image_id`, if there is one.
+fn thumbnail(image_id: &str) -> Option<Vec<u8>> {
+    images::resized(image_id, 300, ImageFormat::Jpeg)
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_card_queries_are_normalized() {
+        assert_eq!(search_card_query("  Pixel   ART "), "pixel art");
+        assert_eq!(search_card_query("Погода"), "Погода");
+        assert_eq!(search_card_query(" \t "), "");
+        let long = search_card_query(&"word ".repeat(20));
+        assert_eq!(long.chars().count(), MAX_QUERY_CHARS - 1);
+        assert!(!long.ends_with(' '));
+    }
+
+    #[test]
+    fn queries_without_results_share_the_generic_card() {
+        crate::use_test_database();
+        let generic = search_card("", &[]).unwrap();
+        assert_eq!(search_card("no such app", &[]).unwrap(), generic);
+        let cached: Vec<String> = ic_rusqlite::with_connection(|conn| {
+            let mut stmt = conn.prepare("SELECT query FROM search_card").unwrap();
+            let rows = stmt.query_map((), |row| row.get(0)).unwrap();
+            rows.collect::<Result<_, _>>().unwrap()
+        });
+        assert_eq!(cached, [""]);
+    }
+}
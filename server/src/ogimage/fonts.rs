@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use resvg::usvg::{fontdb, FallbackSelectionFn};
+use ttf_parser::Face;
+
+static SOHNE_BREIT_HALBFETT: &[u8] = include_bytes!("includes/SohneBreit-Halbfett.otf");
+static SOHNE_LEICHT: &[u8] = include_bytes!("includes/Sohne-Leicht.otf");
+
+// Fallbacks for characters Söhne does not cover. DejaVu Sans covers Latin
+// Extended, Greek, Cyrillic, Arabic and Hebrew; Noto Emoji covers emoji.
+static DEJAVU_SANS_BOLD: &[u8] = include_bytes!("includes/DejaVuSans-Bold.ttf");
+static DEJAVU_SANS: &[u8] = include_bytes!("includes/DejaVuSans.ttf");
+static NOTO_EMOJI: &[u8] = include_bytes!("includes/NotoEmoji-Regular.ttf");
+
+// Noto Sans CJK JP covers Chinese, Japanese and Korean. It is subset by
+// `includes/subset-cjk-fonts.sh`; `build.rs` sets `cjk_font` once it has run.
+#[cfg(cjk_font)]
+static NOTO_SANS_CJK_BOLD: Option<&[u8]> =
+    Some(include_bytes!("includes/NotoSansCJKjp-Bold-Subset.otf"));
+#[cfg(cjk_font)]
+static NOTO_SANS_CJK: Option<&[u8]> =
+    Some(include_bytes!("includes/NotoSansCJKjp-Regular-Subset.otf"));
+#[cfg(not(cjk_font))]
+static NOTO_SANS_CJK_BOLD: Option<&[u8]> = None;
+#[cfg(not(cjk_font))]
+static NOTO_SANS_CJK: Option<&[u8]> = None;
+
+/// Fallback families in order of preference when faces are equally close in
+/// weight.
+const FALLBACK_FAMILIES: [&str; 3] = ["DejaVu Sans", "Noto Sans CJK JP", "Noto Emoji"];
+const EMOJI_FAMILY: &str = "Noto Emoji";
+
+/// A Söhne cut and the fallbacks drawn in its place, in the order the
+/// renderer picks them. Used to measure text the way it will be drawn.
+pub(super) struct FontChain {
+    primary: Face<'static>,
+    text: Face<'static>,
+    cjk: Option<Face<'static>>,
+    emoji: Face<'static>,
+}
+
+impl FontChain {
+    /// Söhne Breit Halbfett, for app names.
+    pub fn name() -> Result<Self, String> {
+        Ok(FontChain {
+            primary: parse_face(SOHNE_BREIT_HALBFETT)?,
+            text: parse_face(DEJAVU_SANS_BOLD)?,
+            cjk: NOTO_SANS_CJK_BOLD.map(parse_face).transpose()?,
+            emoji: parse_face(NOTO_EMOJI)?,
+        })
+    }
+
+    /// Söhne Leicht, for subtitles.
+    pub fn title() -> Result<Self, String> {
+        Ok(FontChain {
+            primary: parse_face(SOHNE_LEICHT)?,
+            text: parse_face(DEJAVU_SANS)?,
+            cjk: NOTO_SANS_CJK.map(parse_face).transpose()?,
+            emoji: parse_face(NOTO_EMOJI)?,
+        })
+    }
+
+    /// The face `c` is drawn with, mirroring [`select_fallback`]. Characters
+    /// no bundled font covers would be drawn as the primary face's
+    /// `.notdef`, so text is passed through [`FontChain::drawable`] first.
+    pub fn face_for(&self, c: char) -> &Face<'static> {
+        if self.primary.glyph_index(c).is_some() {
+            return &self.primary;
+        }
+        let fallbacks = if is_emoji(c) {
+            [Some(&self.emoji), Some(&self.text), self.cjk.as_ref()]
+        } else {
+            [Some(&self.text), self.cjk.as_ref(), Some(&self.emoji)]
+        };
+        fallbacks
+            .into_iter()
+            .flatten()
+            .find(|face| face.glyph_index(c).is_some())
+            .unwrap_or(&self.primary)
+    }
+
+    /// Whether any face in the chain has a glyph for `c`.
+    pub fn covers(&self, c: char) -> bool {
+        [
+            Some(&self.primary),
+            Some(&self.text),
+            self.cjk.as_ref(),
+            Some(&self.emoji),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|face| face.glyph_index(c).is_some())
+    }
+
+    /// `text` without the characters no face covers, so they are left out
+    /// rather than drawn as boxes.
+    pub fn drawable(&self, text: &str) -> String {
+        text.chars().filter(|&c| self.covers(c)).collect()
+    }
+
+    /// Whether `text` has anything visible left once undrawable characters
+    /// are removed.
+    pub fn draws_any(&self, text: &str) -> bool {
+        text.chars().any(|c| !c.is_whitespace() && self.covers(c))
+    }
+
+    pub fn primary(&self) -> &Face<'static> {
+        &self.primary
+    }
+}
+
+/// The font database with every bundled font (WASM has no system fonts).
+pub(super) fn database() -> Arc<fontdb::Database> {
+    let mut fontdb = fontdb::Database::new();
+    for data in [
+        SOHNE_BREIT_HALBFETT,
+        SOHNE_LEICHT,
+        DEJAVU_SANS_BOLD,
+        DEJAVU_SANS,
+        NOTO_EMOJI,
+    ]
+    .into_iter()
+    .chain(NOTO_SANS_CJK_BOLD)
+    .chain(NOTO_SANS_CJK)
+    {
+        fontdb.load_font_data(data.to_vec());
+    }
+    Arc::new(fontdb)
+}
+
+/// Pick the fallback face for a character the current face lacks. Only the
+/// bundled fallback fonts are considered, emoji go to the emoji font first,
+/// and otherwise the face closest in weight wins, so bold names stay bold,
+/// with ties going to the family listed first in [`FALLBACK_FAMILIES`].
+///
+/// Shaping, including Arabic joining and right-to-left reordering, is done
+/// by usvg once a face is picked.
+pub(super) fn select_fallback() -> FallbackSelectionFn<'static> {
+    Box::new(|c, exclude_fonts, fontdb| {
+        let base = fontdb.face(*exclude_fonts.first()?)?;
+        let emoji = is_emoji(c);
+        fontdb
+            .faces()
+            .filter(|face| {
+                let family = face.families.first().map(|(name, _)| name.as_str());
+                family.is_some_and(|family| FALLBACK_FAMILIES.contains(&family))
+                    && !exclude_fonts.contains(&face.id)
+                    && has_char(fontdb, face.id, c)
+            })
+            .min_by_key(|face| {
+                let family = face.families[0].0.as_str();
+                (
+                    (family == EMOJI_FAMILY) != emoji,
+                    face.weight.0.abs_diff(base.weight.0),
+                    FALLBACK_FAMILIES.iter().position(|&f| f == family),
+                )
+            })
+            .map(|face| face.id)
+    })
+}
+
+/// Whether `c` is in one of the Unicode blocks that are mostly emoji.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{2600}'..='\u{27BF}'
+            | '\u{2B00}'..='\u{2BFF}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+fn has_char(fontdb: &fontdb::Database, id: fontdb::ID, c: char) -> bool {
+    fontdb
+        .with_face_data(id, |data, index| {
+            Face::parse(data, index).is_ok_and(|face| face.glyph_index(c).is_some())
+        })
+        .unwrap_or(false)
+}
+
+fn parse_face(data: &'static [u8]) -> Result<Face<'static>, String> {
+    Face::parse(data, 0).map_err(|e| format!("Font parse error: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The renderer's fallback picks a face that has the glyph for every
+    /// character the chains consider drawable, in each script with a
+    /// bundled font.
+    #[test]
+    fn fallback_selection_covers_drawable_characters() {
+        let mut fontdb = database();
+        let select = select_fallback();
+        for (family, weight, chain) in [
+            ("Sohne Breit", 600, FontChain::name().unwrap()),
+            ("Sohne", 300, FontChain::title().unwrap()),
+        ] {
+            let primary = fontdb
+                .query(&fontdb::Query {
+                    families: &[fontdb::Family::Name(family)],
+                    weight: fontdb::Weight(weight),
+                    ..Default::default()
+                })
+                .unwrap();
+            let text = "Crème Погода Ψηφιακός مرحبا שלום 🚀❤ 寿司";
+            for c in chain.drawable(text).chars().filter(|c| !c.is_whitespace()) {
+                if has_char(&fontdb, primary, c) {
+                    continue;
+                }
+                let fallback = select(c, &[primary], &mut fontdb);
+                assert!(
+                    fallback.is_some_and(|id| has_char(&fontdb, id, c)),
+                    "no fallback for {c:?} under {family}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#!/bin/sh
+# Generates the CJK fallback fonts for OG images: Noto Sans CJK JP Bold and
+# Regular cut down to kana, Hangul, CJK punctuation, fullwidth forms and the
+# ideographs of JIS X 0208, GB 2312 and KS X 1001. The full fonts are 16 MB
+# each, far too large to embed in the canister.
+#
+# Requires curl and fonttools (pip install fonttools).
+set -eu
+cd "$(dirname "$0")"
+
+base=https://github.com/notofonts/noto-cjk/raw/main/Sans
+work=$(mktemp -d)
+trap 'rm -rf "$work"' EXIT
+
+# Every character encodable in the legacy Japanese, Simplified Chinese and
+# Korean character sets, which covers the ideographs in everyday use.
+python3 - > "$work/chars.txt" <<'PY'
+import sys
+
+chars = set()
+for codec in ("euc_jp", "gb2312", "euc_kr"):
+    for lead in range(0xA1, 0xFF):
+        for trail in range(0xA1, 0xFF):
+            try:
+                chars.update(bytes([lead, trail]).decode(codec))
+            except UnicodeDecodeError:
+                pass
+sys.stdout.write("".join(sorted(chars)))
+PY
+
+curl -fsSL "$base/LICENSE" -o NotoSansCJK-OFL.txt
+for weight in Bold Regular; do
+    curl -fsSL "$base/OTF/Japanese/NotoSansCJKjp-$weight.otf" -o "$work/$weight.otf"
+    pyftsubset "$work/$weight.otf" \
+        --text-file="$work/chars.txt" \
+        --unicodes=U+3000-30FF,U+3130-318F,U+31F0-31FF,U+AC00-D7A3,U+FF00-FFEF \
+        --layout-features='*' \
+        --name-IDs='*' \
+        --output-file="NotoSansCJKjp-$weight-Subset.otf"
+done
@@ -1,90 +1,442 @@
-use std::sync::Arc;
+mod cache;
+mod cards;
+mod fonts;
+mod templates;
+mod text_layout;
+mod variant;
 
-use minijinja::Environment;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::app::app_types::App;
+use crate::app::AppManager;
+use crate::images::{self, ImageFormat};
+use crate::jobs::{Budget, Step};
+use crate::{escape, ssr};
+use candid::CandidType;
+use fonts::FontChain;
+use ic_asset_router::{HttpResponse, StatusCode};
+use minijinja::{Environment, ErrorKind};
 use resvg::{
     tiny_skia::{self, Pixmap},
-    usvg::{fontdb, Options, Tree},
+    usvg::{FontResolver, Options, Tree},
+};
+use sha2::{Digest, Sha256};
+use text_layout::LaidOutText;
+
+pub use cards::{author_card, home_card, search_card, search_card_query};
+pub use templates::{
+    activate_template, list_templates, preview, upload_template, OgTemplateVersion,
 };
+pub use variant::{OgFormat, OgVariant};
 
-static OGIMAGE_TEMPLATE: &str = include_str!("includes/ogimage_template.svg");
 static BG_IMAGE_DATA: &[u8] = include_bytes!("includes/og-background.png");
-static FONT_DATA: &[u8] = include_bytes!("includes/SohneBreit-Halbfett.otf");
-static FONT_DATA_LIGHT: &[u8] = include_bytes!("includes/Sohne-Leicht.otf");
 
-/// Render an OG image PNG for the given app.
+/// Part of every cache key. Bump when a code change alters rendered output,
+/// so persisted images are not reused. Template and background changes are
+/// picked up automatically since the renderer's fingerprint is hashed too.
+const RENDER_VERSION: u32 = 3;
+
+/// The images linked from page metadata and oEmbed responses, rendered
+/// ahead of time after each deploy so crawlers never wait on a render.
+const PRERENDERED: &[(OgVariant, OgFormat)] = &[
+    (OgVariant::Landscape, OgFormat::Png),
+    (OgVariant::Thumbnail, OgFormat::Jpeg),
+];
+
+/// Everything that is the same for every render: the uploaded templates, the
+/// font database and the background image. Built on first render, and
+/// rebuilt when an uploaded template is activated. The compiled-in templates
+/// live in the shared XML environment, see [`ssr::render_xml`].
+struct Renderer {
+    /// Only the active uploads; a template not found here is compiled in.
+    uploads: Environment<'static>,
+    options: Options<'static>,
+    name_fonts: FontChain,
+    title_fonts: FontChain,
+    background_data_uri: String,
+    /// Hash of every template source and the background image.
+    fingerprint: String,
+}
+
+/// A card ready to be drawn: the template to render, its context and the
+/// pixel size of the image.
+struct Card {
+    template: &'static str,
+    ctx: minijinja::Value,
+    size: (u32, u32),
+}
+
+impl Renderer {
+    /// A renderer using the active uploaded templates and background.
+    fn new() -> Result<Self, String> {
+        Self::with_uploads(templates::active()?)
+    }
+
+    /// A renderer using the compiled-in templates and background, each
+    /// replaced by the upload of the same name in `uploads` if there is one.
+    fn with_uploads(uploads: Vec<(String, Vec<u8>)>) -> Result<Self, String> {
+        // Templates are registered without an extension to keep auto-escaping
+        // off; text values are XML-escaped before rendering.
+        let mut env = Environment::new();
+        let mut parts: Vec<&[u8]> = vec![BG_IMAGE_DATA];
+        for (_, source) in builtin_templates() {
+            parts.push(source.as_bytes());
+        }
+        for (name, data) in &uploads {
+            parts.push(name.as_bytes());
+            parts.push(data);
+        }
+        let fingerprint = sha256_hex(&parts);
+
+        let mut background = BG_IMAGE_DATA;
+        for (name, data) in &uploads {
+            if name == templates::BACKGROUND {
+                background = data;
+                continue;
+            }
+            let source = std::str::from_utf8(data)
+                .map_err(|e| format!("Template {name} is not UTF-8: {e}"))?;
+            env.add_template_owned(name.clone(), source.to_string())
+                .map_err(|e| format!("Template parse error: {e}"))?;
+        }
+
+        let options = Options {
+            font_family: "Sohne Breit".to_string(),
+            fontdb: fonts::database(),
+            font_resolver: FontResolver {
+                select_fallback: fonts::select_fallback(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Renderer {
+            uploads: env,
+            options,
+            name_fonts: FontChain::name()?,
+            title_fonts: FontChain::title()?,
+            background_data_uri: format!("data:image/png;base64,{}", base64_encode(background)),
+            fingerprint,
+        })
+    }
+}
+
+/// Name and source of every compiled-in card template, by upload name.
+pub(crate) fn builtin_templates() -> impl Iterator<Item = (&'static str, &'static str)> {
+    OgVariant::ALL_TEMPLATES.into_iter().chain(cards::TEMPLATES)
+}
+
+/// The name a compiled-in card template is registered under in the shared
+/// XML environment.
+pub(crate) fn builtin_template_name(name: &str) -> String {
+    format!("og_{name}.svg")
+}
+
+thread_local! {
+    static RENDERER: RefCell<Option<Rc<Renderer>>> = const { RefCell::new(None) };
+}
+
+/// Render an app's OG image, reusing the copy persisted in SQLite when it
+/// was rendered from the same inputs. Fresh renders are persisted.
+pub fn render_for_app(app: &App, variant: OgVariant, format: OgFormat) -> Result<Vec<u8>, String> {
+    let app_name = app.app_name.as_deref().unwrap_or(&app.title);
+    // Show the AI-generated title as subtitle, but only if it differs from app_name
+    let app_title = (app.title != app_name).then_some(app.title.as_str());
+    let image_id = app.image_id.as_deref();
+
+    let file_name = variant.file_name(format);
+    let fingerprint = with_renderer(|renderer| Ok(renderer.fingerprint.clone()))?;
+    let input_hash = sha256_hex(&[
+        RENDER_VERSION.to_string().as_bytes(),
+        fingerprint.as_bytes(),
+        file_name.as_bytes(),
+        app_name.as_bytes(),
+        app_title.unwrap_or_default().as_bytes(),
+        image_id.unwrap_or_default().as_bytes(),
+    ]);
+
+    if let Some(data) = cache::get(app.id, &file_name, &input_hash)? {
+        return Ok(data);
+    }
+    let data = render(variant, format, app_name, app_title, image_id)?;
+    cache::put(app.id, &file_name, &input_hash, &data)?;
+    Ok(data)
+}
+
+/// Render the pre-rendered images of the apps after `cursor`, an app ID,
+/// until the budget runs out. Images that are already persisted are skipped
+/// cheaply. Runs as the [`crate::jobs::WARM_OG_IMAGES`] job.
+pub fn warm(cursor: Option<&str>, budget: &Budget) -> Result<Step, String> {
+    let after = match cursor {
+        Some(cursor) => cursor
+            .parse::<i64>()
+            .map_err(|_| format!("Invalid cursor {cursor}"))?,
+        None => i64::MIN,
+    };
+    let mut apps: Vec<App> = AppManager::list()?
+        .into_iter()
+        .filter(|app| app.id > after)
+        .collect();
+    apps.sort_by_key(|app| app.id);
+
+    for app in apps {
+        for &(variant, format) in PRERENDERED {
+            if let Err(e) = render_for_app(&app, variant, format) {
+                ic_cdk::println!("OG image pre-render failed for app {}: {e}", app.id);
+            }
+        }
+        if budget.exhausted() {
+            return Ok(Step::Continue(app.id.to_string()));
+        }
+    }
+    Ok(Step::Done)
+}
+
+/// Render an OG image for the given app.
 ///
+/// - `variant` / `format`: card layout and image encoding
 /// - `app_name`: display name shown at the top of the image
 /// - `app_title`: shorter title shown below the app name (e.g. AI-generated title)
+/// - `image_id`: the app's screenshot, composited into the card. Falls back to
+///   a text-only card when missing or not bundled.
 ///
-/// Returns the PNG bytes, or an error string.
-pub fn render(app_name: &str, app_title: Option<&str>) -> Result<Vec<u8>, String> {
-    // Build the background data URI (static, always present)
-    let background_data_uri = format!("data:image/png;base64,{}", base64_encode(BG_IMAGE_DATA));
-
-    // Render the SVG template with MiniJinja.
-    // Text values must be XML-escaped since they're inserted into SVG (XML).
-    let mut env = Environment::new();
-    env.add_template("og", OGIMAGE_TEMPLATE)
-        .map_err(|e| format!("Template parse error: {e}"))?;
-    let tmpl = env.get_template("og").unwrap();
-    let safe_name = xml_escape(app_name);
-    let safe_title = app_title.map(|t| truncate(t, 80)).map(|t| xml_escape(&t));
-    let ctx = minijinja::context! {
-        app_name => safe_name,
-        app_title => safe_title,
-        background_data_uri => background_data_uri,
+/// Returns the encoded image bytes, or an error string.
+pub fn render(
+    variant: OgVariant,
+    format: OgFormat,
+    app_name: &str,
+    app_title: Option<&str>,
+    image_id: Option<&str>,
+) -> Result<Vec<u8>, String> {
+    with_renderer(|renderer| {
+        renderer.draw(
+            renderer.app_card(variant, app_name, app_title, image_id),
+            format,
+        )
+    })
+}
+
+/// Turn a rendered image into a response. A render error is served as a
+/// plain-text 500.
+pub fn image_response(image: Result<Vec<u8>, String>, format: OgFormat) -> HttpResponse<'static> {
+    match image {
+        Ok(image_bytes) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), format.mime_type().into())])
+            .with_status_code(StatusCode::OK)
+            .with_body(Cow::Owned(image_bytes))
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(
+                format!("OG image generation failed: {e}").into_bytes(),
+            ))
+            .build(),
+    }
+}
+
+fn with_renderer<T>(f: impl FnOnce(&Renderer) -> Result<T, String>) -> Result<T, String> {
+    let renderer = match RENDERER.with_borrow(Clone::clone) {
+        Some(renderer) => renderer,
+        None => {
+            let renderer = Rc::new(Renderer::new()?);
+            RENDERER.set(Some(renderer.clone()));
+            renderer
+        }
     };
-    let svg_str = tmpl
-        .render(ctx)
-        .map_err(|e| format!("Template render error: {e}"))?;
-
-    // Set up fontdb with a bundled font (WASM has no system fonts)
-    let mut fontdb = fontdb::Database::new();
-    fontdb.load_font_data(FONT_DATA.to_vec());
-    fontdb.load_font_data(FONT_DATA_LIGHT.to_vec());
-
-    let options = Options {
-        font_family: "Sohne Breit".to_string(),
-        fontdb: Arc::new(fontdb),
-        ..Default::default()
+    f(&renderer)
+}
+
+/// Drop the shared renderer so the next render picks up newly activated
+/// templates.
+fn reset_renderer() {
+    RENDERER.set(None);
+}
+
+impl Renderer {
+    fn app_card(
+        &self,
+        variant: OgVariant,
+        app_name: &str,
+        app_title: Option<&str>,
+        image_id: Option<&str>,
+    ) -> Card {
+        let screenshot_data_uri = image_id
+            .and_then(screenshot)
+            .map(|jpeg| format!("data:image/jpeg;base64,{}", base64_encode(&jpeg)));
+
+        // A name the bundled fonts cannot draw at all, e.g. one in CJK, gives
+        // way to the subtitle rather than leaving the card without a name
+        let (app_name, app_title) = match app_title {
+            Some(title) if !self.name_fonts.draws_any(app_name) => (title, None),
+            _ => (app_name, app_title),
+        };
+
+        let layout = variant.layout(screenshot_data_uri.is_some());
+        let name = text_layout::layout(&self.name_fonts, app_name, &layout.name);
+        let title = match (app_title, &layout.title) {
+            (Some(title), Some(text_box)) => {
+                text_layout::layout(&self.title_fonts, title, text_box)
+            }
+            _ => LaidOutText {
+                size: 0.0,
+                lines: vec![],
+                rtl: false,
+            },
+        };
+        let name_align = name.align(layout.text_x, layout.name.max_width);
+        let title_align = title.align(
+            layout.text_x,
+            layout
+                .title
+                .as_ref()
+                .map_or(0.0, |text_box| text_box.max_width),
+        );
+        let title_y = layout.name_y
+            + name.lines.len().saturating_sub(1) as f32 * name.size * 1.15
+            + title.size * 1.75;
+
+        // Text values must be XML-escaped since they're inserted into SVG (XML)
+        let (width, height) = variant.size();
+        let ctx = minijinja::context! {
+            width => width,
+            height => height,
+            name_size => name.size,
+            name_x => name_align.x,
+            name_anchor => name_align.anchor,
+            name_y => layout.name_y,
+            name_lines => escape_lines(name.lines),
+            title_size => title.size,
+            title_x => title_align.x,
+            title_anchor => title_align.anchor,
+            title_y => title_y,
+            title_lines => escape_lines(title.lines),
+            background_data_uri => self.background_data_uri,
+            screenshot_data_uri => screenshot_data_uri,
+        };
+        Card {
+            template: variant.template().0,
+            ctx,
+            size: variant.size(),
+        }
+    }
+
+    /// Render a card's SVG template with MiniJinja.
+    fn svg(&self, card: &Card) -> Result<String, String> {
+        match self.uploads.get_template(card.template) {
+            Ok(tmpl) => tmpl
+                .render(&card.ctx)
+                .map_err(|e| format!("Template render error: {e}")),
+            Err(e) if e.kind() == ErrorKind::TemplateNotFound => {
+                ssr::render_xml(&builtin_template_name(card.template), &card.ctx)
+            }
+            Err(e) => Err(format!("Template lookup error: {e}")),
+        }
+    }
+
+    /// Render a card's SVG and rasterize it into an image in `format`.
+    fn draw(&self, card: Card, format: OgFormat) -> Result<Vec<u8>, String> {
+        let svg_str = self.svg(&card)?;
+        let (width, height) = card.size;
+
+        // Parse SVG and render to a pixmap
+        let tree =
+            Tree::from_str(&svg_str, &self.options).map_err(|e| format!("SVG parse error: {e}"))?;
+
+        let mut pixmap =
+            Pixmap::new(width, height).ok_or_else(|| "Failed to create Pixmap".to_string())?;
+
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+        format.encode(&pixmap)
+    }
+}
+
+/// Instruction counts for OG image rendering, measured by [`benchmark`].
+#[derive(CandidType)]
+pub struct RenderBenchmark {
+    /// One render with a renderer built for it, fonts, uploaded templates
+    /// and background included, as every render paid before they were
+    /// shared.
+    pub fresh_render_instructions: u64,
+    /// Average cost of one render with the shared renderer.
+    pub shared_render_instructions: u64,
+}
+
+/// Measure the instruction cost of rendering `app`'s image `runs` times
+/// with the shared renderer, then once with a freshly built one, bypassing
+/// the persisted cache.
+pub fn benchmark(
+    app: &App,
+    variant: OgVariant,
+    format: OgFormat,
+    runs: u32,
+) -> Result<RenderBenchmark, String> {
+    let app_name = app.app_name.as_deref().unwrap_or(&app.title);
+    let app_title = (app.title != app_name).then_some(app.title.as_str());
+    let image_id = app.image_id.as_deref();
+    let runs = runs.max(1);
+
+    let render = |renderer: &Renderer| {
+        renderer.draw(
+            renderer.app_card(variant, app_name, app_title, image_id),
+            format,
+        )
     };
 
-    // Parse SVG and render to PNG
-    let tree = Tree::from_str(&svg_str, &options).map_err(|e| format!("SVG parse error: {e}"))?;
+    // One render before measuring, so the shared renderer and templates are
+    // already built and only the renders are counted
+    with_renderer(render)?;
+    let start = ic_cdk::api::performance_counter(0);
+    for _ in 0..runs {
+        with_renderer(render)?;
+    }
+    let shared_render_instructions =
+        (ic_cdk::api::performance_counter(0) - start) / u64::from(runs);
 
-    let mut pixmap = Pixmap::new(1200, 630).ok_or_else(|| "Failed to create Pixmap".to_string())?;
+    let start = ic_cdk::api::performance_counter(0);
+    render(&Renderer::new()?)?;
+    let fresh_render_instructions = ic_cdk::api::performance_counter(0) - start;
 
-    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    Ok(RenderBenchmark {
+        fresh_render_instructions,
+        shared_render_instructions,
+    })
+}
 
-    pixmap
-        .encode_png()
-        .map_err(|e| format!("PNG encode error: {e}"))
+/// The 1500px JPEG screenshot for `image_id`, if there is one.
+fn screenshot(image_id: &str) -> Option<Vec<u8>> {
+    images::resized(image_id, 1500, ImageFormat::Jpeg)
+        .ok()
+        .flatten()
 }
 
-/// Escape special XML characters in text content.
-fn xml_escape(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&apos;")
+/// XML-escape each line of laid-out text.
+fn escape_lines(lines: Vec<String>) -> Vec<String> {
+    lines.iter().map(|l| escape::xml(l)).collect()
 }
 
-/// Truncate a string to `max` characters on a char boundary, appending "..." if truncated.
-fn truncate(s: &str, max: usize) -> String {
-    if s.chars().count() <= max {
-        s.to_string()
-    } else {
-        let truncated: String = s.chars().take(max - 1).collect();
-        format!("{truncated}…")
+/// Hex-encoded SHA-256 of `parts`, each followed by a NUL separator.
+fn sha256_hex(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+        hasher.update([0]);
     }
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
 }
 
 /// Simple base64 encoder (no external dependency needed).
 fn base64_encode(data: &[u8]) -> String {
     const CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
 
-    let mut result = String::with_capacity((data.len() + 2) / 3 * 4);
+    let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
     let chunks = data.chunks(3);
 
     for chunk in chunks {
@@ -112,3 +464,66 @@ fn base64_encode(data: &[u8]) -> String {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn renderer() -> Renderer {
+        Renderer::with_uploads(Vec::new()).unwrap()
+    }
+
+    fn ctx_str(card: &Card, key: &str) -> String {
+        card.ctx.get_attr(key).unwrap().to_string()
+    }
+
+    #[cfg(cjk_font)]
+    #[test]
+    fn cjk_names_are_drawn() {
+        let renderer = renderer();
+        for variant in OgVariant::ALL {
+            let card = renderer.app_card(variant, "寿司タイマー", Some("天气预报"), None);
+            let drawn = |key| -> String {
+                ctx_str(&card, key)
+                    .chars()
+                    .filter(|c| !c.is_ascii())
+                    .collect()
+            };
+            assert_eq!(drawn("name_lines"), "寿司タイマー");
+            assert_eq!(drawn("title_lines"), "天气预报");
+            let svg = renderer.svg(&card).unwrap();
+            Tree::from_str(&svg, &renderer.options).unwrap();
+        }
+    }
+
+    #[test]
+    fn undrawable_name_gives_way_to_the_title() {
+        let renderer = renderer();
+        let card = renderer.app_card(
+            OgVariant::Landscape,
+            "\u{10000}\u{10001}",
+            Some("Sushi timer"),
+            None,
+        );
+        assert_eq!(ctx_str(&card, "name_lines"), r#"["Sushi timer"]"#);
+        assert_eq!(ctx_str(&card, "title_lines"), "[]");
+    }
+
+    #[test]
+    fn right_to_left_names_end_at_the_right_edge() {
+        let renderer = renderer();
+        for variant in OgVariant::ALL {
+            let layout = variant.layout(false);
+            let card = renderer.app_card(variant, "שלום עולם", Some("مرحبا بالعالم"), None);
+            assert_eq!(ctx_str(&card, "name_anchor"), "end");
+            let name_x = card.ctx.get_attr("name_x").unwrap();
+            assert_eq!(
+                f32::try_from(name_x).unwrap(),
+                layout.text_x + layout.name.max_width
+            );
+            let svg = renderer.svg(&card).unwrap();
+            assert!(svg.contains("text-anchor=\"end\""), "{svg}");
+            Tree::from_str(&svg, &renderer.options).unwrap();
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use candid::CandidType;
+use ic_rusqlite::with_connection;
+use resvg::tiny_skia::Pixmap;
+use resvg::usvg::{roxmltree, Tree};
+
+use crate::app::app_types::App;
+use crate::app::AppManager;
+
+use super::{cards, Card, OgFormat, OgVariant, Renderer};
+
+/// Name under which background images are uploaded. Every other name is a
+/// template name, e.g. `landscape`.
+pub(super) const BACKGROUND: &str = "background";
+
+const MAX_TEMPLATE_BYTES: usize = 64 * 1024;
+/// Leaves room for the rest of the upload call within the 2 MiB ingress limit.
+const MAX_BACKGROUND_BYTES: usize = 1536 * 1024;
+const MAX_BACKGROUND_PIXELS: u32 = 4096;
+
+/// An uploaded template or background image version.
+#[derive(CandidType)]
+pub struct OgTemplateVersion {
+    pub name: String,
+    pub version: u32,
+    pub size: u64,
+    pub active: bool,
+    /// Seconds since the Unix epoch.
+    pub created_at: i64,
+    /// Pass as `template` to `/og/preview` to try this version before
+    /// activating it.
+    pub preview_key: String,
+}
+
+/// Validate and store a new version of the template or background `name`.
+/// The new version is not active until [`activate_template`] is called.
+///
+/// Templates must be UTF-8, at most 64 KiB, and render to SVG that usvg can
+/// parse and that references nothing but data URIs and fragments within the
+/// document. Backgrounds must be PNGs of at most 1.5 MiB and 4096×4096.
+pub fn upload_template(
+    name: &str,
+    data: Vec<u8>,
+    preview_key: &str,
+) -> Result<OgTemplateVersion, String> {
+    if name == BACKGROUND {
+        validate_background(&data)?;
+    } else {
+        validate_template(name, &data)?;
+    }
+
+    with_connection(|conn| {
+        let version: u32 = conn
+            .query_row(
+                "SELECT COALESCE(MAX(version), 0) + 1 FROM og_template WHERE name = ?1",
+                (name,),
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO og_template (name, version, data, preview_key) VALUES (?1, ?2, ?3, ?4)",
+            (name, version, &data, preview_key),
+        )
+        .map_err(|e| e.to_string())?;
+        conn.query_row(
+            "SELECT created_at FROM og_template WHERE name = ?1 AND version = ?2",
+            (name, version),
+            |row| row.get(0),
+        )
+        .map(|created_at| OgTemplateVersion {
+            name: name.to_string(),
+            version,
+            size: data.len() as u64,
+            active: false,
+            created_at,
+            preview_key: preview_key.to_string(),
+        })
+        .map_err(|e| e.to_string())
+    })
+}
+
+/// Make `version` the active version of the template or background `name`,
+/// or go back to the compiled-in copy if `version` is `None`.
+///
+/// Rendered cards are dropped from the persisted cache and the certified
+/// responses, and pre-rendering starts over.
+pub fn activate_template(name: &str, version: Option<u32>) -> Result<(), String> {
+    with_connection(|conn| {
+        if let Some(version) = version {
+            let exists: bool = conn
+                .query_row(
+                    "SELECT EXISTS (SELECT 1 FROM og_template WHERE name = ?1 AND version = ?2)",
+                    (name, version),
+                    |row| row.get(0),
+                )
+                .map_err(|e| e.to_string())?;
+            if !exists {
+                return Err(format!("No version {version} of OG template {name}"));
+            }
+        }
+        conn.execute(
+            "UPDATE og_template SET active = (version IS ?2) WHERE name = ?1",
+            (name, version),
+        )
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+    })?;
+
+    super::reset_renderer();
+    super::cache::clear()?;
+    crate::invalidate_app_data_routes();
+    crate::jobs::trigger(crate::jobs::WARM_OG_IMAGES, true)?;
+    Ok(())
+}
+
+/// Every uploaded version, newest first within each name.
+pub fn list_templates() -> Result<Vec<OgTemplateVersion>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT name, version, length(data), active, created_at, preview_key
+                 FROM og_template
+                 ORDER BY name, version DESC",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((), |row| {
+                Ok(OgTemplateVersion {
+                    name: row.get(0)?,
+                    version: row.get(1)?,
+                    size: row.get(2)?,
+                    active: row.get(3)?,
+                    created_at: row.get(4)?,
+                    preview_key: row.get(5)?,
+                })
+            })
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// Render a sample card with the version whose preview key is `key`, in
+/// place of the active version of the same name. The card shows `name` and
+/// `title`, and screenshots of the first apps. Returns `None` if no version
+/// has that key.
+pub fn preview(key: &str, name: &str, title: &str) -> Result<Option<Vec<u8>>, String> {
+    let upload = with_connection(|conn| {
+        match conn.query_row(
+            "SELECT name, data FROM og_template WHERE preview_key = ?1",
+            (key,),
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)),
+        ) {
+            Ok(upload) => Ok(Some(upload)),
+            Err(ic_rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.to_string()),
+        }
+    })?;
+    let Some((template, data)) = upload else {
+        return Ok(None);
+    };
+
+    let apps = AppManager::list().unwrap_or_default();
+    let renderer = renderer_with(&template, data)?;
+    let card = renderer.sample_card(&template, name, title, &apps)?;
+    renderer.draw(card, OgFormat::Png).map(Some)
+}
+
+/// Name and contents of the active version of every template or background
+/// that has one.
+pub(super) fn active() -> Result<Vec<(String, Vec<u8>)>, String> {
+    with_connection(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT name, data FROM og_template WHERE active ORDER BY name")
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map((), |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| e.to_string())?;
+
+        rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// A renderer using the active versions, with `data` in place of `name`.
+fn renderer_with(name: &str, data: Vec<u8>) -> Result<Renderer, String> {
+    let mut uploads: Vec<_> = active()?
+        .into_iter()
+        .filter(|(active_name, _)| active_name != name)
+        .collect();
+    uploads.push((name.to_string(), data));
+    Renderer::with_uploads(uploads)
+}
+
+impl Renderer {
+    /// A card drawn with the template `template`, filled in with sample
+    /// text and the screenshots of `apps`. Background uploads are shown on
+    /// the default app card.
+    fn sample_card(
+        &self,
+        template: &str,
+        name: &str,
+        title: &str,
+        apps: &[App],
+    ) -> Result<Card, String> {
+        let image_id = apps.iter().find_map(|app| app.image_id.as_deref());
+        let template = if template == BACKGROUND {
+            OgVariant::Landscape.template().0
+        } else {
+            template
+        };
+
+        if let Some(variant) = OgVariant::ALL
+            .into_iter()
+            .find(|variant| variant.template().0 == template)
+        {
+            return Ok(self.app_card(variant, name, Some(title), image_id));
+        }
+        match template {
+            "collection" => {
+                let apps: Vec<&App> = apps.iter().collect();
+                Ok(self.collection_card(None, name, title, &apps))
+            }
+            "author" => Ok(self.author_card(name, &[title])),
+            _ => Err(format!("Unknown OG template {template}")),
+        }
+    }
+}
+
+fn validate_template(name: &str, data: &[u8]) -> Result<(), String> {
+    let is_known = OgVariant::ALL_TEMPLATES
+        .into_iter()
+        .chain(cards::TEMPLATES)
+        .any(|(known, _)| known == name);
+    if !is_known {
+        return Err(format!("Unknown OG template {name}"));
+    }
+    if data.len() > MAX_TEMPLATE_BYTES {
+        return Err(format!(
+            "Template is {} bytes, the limit is {MAX_TEMPLATE_BYTES}",
+            data.len()
+        ));
+    }
+
+    let source = std::str::from_utf8(data).map_err(|e| format!("Template is not UTF-8: {e}"))?;
+    check_source_references(source)?;
+
+    // Render a sample card, with screenshots if there are any, and check the
+    // SVG it produces
+    let apps = AppManager::list().unwrap_or_default();
+    let renderer = renderer_with(name, data.to_vec())?;
+    let card = renderer.sample_card(name, "Sample name", "Sample title", &apps)?;
+    let svg = renderer.svg(&card)?;
+    check_references(&svg)?;
+    Tree::from_str(&svg, &renderer.options).map_err(|e| format!("SVG parse error: {e}"))?;
+    Ok(())
+}
+
+fn validate_background(data: &[u8]) -> Result<(), String> {
+    if data.len() > MAX_BACKGROUND_BYTES {
+        return Err(format!(
+            "Background is {} bytes, the limit is {MAX_BACKGROUND_BYTES}",
+            data.len()
+        ));
+    }
+    let pixmap = Pixmap::decode_png(data).map_err(|e| format!("PNG decode error: {e}"))?;
+    if pixmap.width() > MAX_BACKGROUND_PIXELS || pixmap.height() > MAX_BACKGROUND_PIXELS {
+        return Err(format!(
+            "Background is {}x{}, the limit is {MAX_BACKGROUND_PIXELS}x{MAX_BACKGROUND_PIXELS}",
+            pixmap.width(),
+            pixmap.height()
+        ));
+    }
+    Ok(())
+}
+
+/// Reject SVG that refers to anything outside itself: `href`s other than
+/// data URIs and `#fragment`s, `url(…)` references other than fragments, and
+/// CSS `@import`s. The renderer has nothing to fetch them from, so they
+/// would at best be dropped silently.
+fn check_references(svg: &str) -> Result<(), String> {
+    let doc = roxmltree::Document::parse(svg).map_err(|e| format!("SVG parse error: {e}"))?;
+
+    for node in doc.descendants() {
+        for attr in node.attributes() {
+            let value = attr.value().trim_start();
+            if attr.name() == "href" && !(value.starts_with('#') || value.starts_with("data:")) {
+                return Err(format!("External reference in href: {value}"));
+            }
+            check_css_references(attr.value())?;
+        }
+        if node.is_text() {
+            check_css_references(node.text().unwrap_or_default())?;
+        }
+    }
+    Ok(())
+}
+
+/// Check the template source for the references [`check_references`]
+/// rejects, including in branches the sample card does not take. A reference
+/// may only be filled in from a context variable, such as
+/// `href="{{ screenshot_data_uri }}"`, never from an expression that could
+/// build a URL.
+fn check_source_references(source: &str) -> Result<(), String> {
+    if source.contains("@import") {
+        return Err("CSS @import is not allowed".to_string());
+    }
+    for (i, _) in source.match_indices("href") {
+        let rest = source[i + 4..].trim_start();
+        if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim_start().trim_start_matches(['"', '\'']);
+            check_source_target("href", value, true)?;
+        }
+    }
+    for (i, _) in source.match_indices("url(") {
+        let value = source[i + 4..].trim_start_matches([' ', '"', '\'']);
+        check_source_target("url()", value, false)?;
+    }
+    Ok(())
+}
+
+fn check_source_target(kind: &str, value: &str, allow_data: bool) -> Result<(), String> {
+    if value.starts_with('#') || (allow_data && value.starts_with("data:")) {
+        return Ok(());
+    }
+    if let Some(expr) = value.strip_prefix("{{") {
+        let end = expr.find("}}").unwrap_or(expr.len());
+        let expr = expr[..end].trim_matches(['-', '+']).trim();
+        let is_variable = !expr.is_empty()
+            && expr
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+        if is_variable {
+            return Ok(());
+        }
+    }
+    let end = value
+        .find(['"', '\'', ')', ' ', '>'])
+        .unwrap_or(value.len());
+    Err(format!("External reference in {kind}: {}", &value[..end]))
+}
+
+fn check_css_references(css: &str) -> Result<(), String> {
+    if css.contains("@import") {
+        return Err("CSS @import is not allowed".to_string());
+    }
+    for (i, _) in css.match_indices("url(") {
+        let target = css[i + 4..].trim_start_matches([' ', '"', '\'']);
+        if !target.starts_with('#') {
+            let end = target.find(')').unwrap_or(target.len());
+            return Err(format!("External reference in url(): {}", &target[..end]));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiled_in_templates_pass_the_source_check() {
+        for (name, source) in super::super::builtin_templates() {
+            check_source_references(source).unwrap_or_else(|e| panic!("{name}: {e}"));
+        }
+    }
+
+    #[test]
+    fn source_check_finds_references_in_untaken_branches() {
+        for source in [
+            r#"{% if false %}<image href="https://example.com/a.png"/>{% endif %}"#,
+            r#"{% if false %}<image xlink:href='//example.com/a.png'/>{% endif %}"#,
+            r#"<image href = "{{ 'https://example.com/a.png' }}"/>"#,
+            r#"<image href="{{ base ~ '/a.png' }}"/>"#,
+            r#"<image href="https://example.com{{ path }}"/>"#,
+            r#"{% if false %}<rect fill="url(https://example.com/p.svg#p)"/>{% endif %}"#,
+            r#"<rect fill="url(data:image/svg+xml,abc)"/>"#,
+            "<style>{% if false %}@import 'https://example.com/a.css';{% endif %}</style>",
+        ] {
+            assert!(check_source_references(source).is_err(), "{source}");
+        }
+    }
+
+    #[test]
+    fn source_check_allows_fragments_data_uris_and_variables() {
+        check_source_references(
+            r##"<image href="#a"/><image xlink:href="data:image/png;base64,AA"/>
+               <image xlink:href="{{ screenshot_data_uri }}"/>
+               <image xlink:href="{{- tile -}}"/>
+               <rect clip-path="url(#clip-{{loop.index0}})" fill="url('#g')"/>"##,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn rendered_check_rejects_external_references() {
+        assert!(check_references(r#"<svg xmlns="http://www.w3.org/2000/svg"><image href="https://example.com/a.png"/></svg>"#).is_err());
+        assert!(check_references(
+            r##"<svg xmlns="http://www.w3.org/2000/svg"><rect fill="url(#g)"/></svg>"##
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,254 @@
+use serde::Serialize;
+
+use super::fonts::FontChain;
+
+const ELLIPSIS: char = '…';
+
+/// The area a block of text has to fit in, and how far its font size may
+/// shrink to get there.
+pub struct TextBox {
+    pub max_width: f32,
+    pub max_lines: usize,
+    pub max_size: f32,
+    pub min_size: f32,
+}
+
+/// Text broken into lines that fit a [`TextBox`] at `size`.
+pub struct LaidOutText {
+    pub size: f32,
+    pub lines: Vec<String>,
+    /// Whether the text reads right to left, see [`LaidOutText::align`].
+    pub rtl: bool,
+}
+
+/// Where a block of text is anchored: `x` and the SVG `text-anchor` to draw
+/// it with.
+#[derive(Serialize)]
+pub struct Alignment {
+    pub x: f32,
+    pub anchor: &'static str,
+}
+
+impl LaidOutText {
+    /// Align the text in a box starting at `left`: left-to-right text starts
+    /// at its left edge, right-to-left text ends at its right edge.
+    pub fn align(&self, left: f32, max_width: f32) -> Alignment {
+        if self.rtl {
+            Alignment {
+                x: left + max_width,
+                anchor: "end",
+            }
+        } else {
+            Alignment {
+                x: left,
+                anchor: "start",
+            }
+        }
+    }
+}
+
+/// Lay out `text` in the fonts `fonts` to fit `text_box`. Characters none
+/// of the fonts cover are left out.
+///
+/// Tries the largest font size first and shrinks in 2px steps until the
+/// wrapped text fits in `max_lines`. If it still does not fit at `min_size`,
+/// the last line is cut and ends with an ellipsis.
+pub fn layout(fonts: &FontChain, text: &str, text_box: &TextBox) -> LaidOutText {
+    let metrics = Metrics { fonts };
+    let text = fonts.drawable(text);
+    let rtl = is_rtl(&text);
+
+    let mut size = text_box.max_size;
+    loop {
+        let max_units = metrics.to_units(text_box.max_width, size);
+        let mut lines = wrap(&metrics, &text, max_units);
+        if lines.len() <= text_box.max_lines {
+            return LaidOutText { size, lines, rtl };
+        }
+        if size - 2.0 < text_box.min_size {
+            lines.truncate(text_box.max_lines);
+            if let Some(last) = lines.last_mut() {
+                *last = ellipsize(&metrics, last, max_units);
+            }
+            return LaidOutText { size, lines, rtl };
+        }
+        size -= 2.0;
+    }
+}
+
+/// Whether the first letter of `text` is from a right-to-left script, i.e.
+/// Hebrew or Arabic, which sets the direction of the whole block.
+fn is_rtl(text: &str) -> bool {
+    text.chars().find(|c| c.is_alphabetic()).is_some_and(|c| {
+        matches!(
+            c,
+            '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}'
+        )
+    })
+}
+
+struct Metrics<'a> {
+    fonts: &'a FontChain,
+}
+
+impl Metrics<'_> {
+    /// Horizontal advance of `s` in the primary face's units. Each character
+    /// is measured in the face that draws it, scaled to the primary face's
+    /// em.
+    fn width(&self, s: &str) -> f32 {
+        let units_per_em = self.fonts.primary().units_per_em() as f32;
+        s.chars()
+            .map(|c| {
+                let face = self.fonts.face_for(c);
+                let glyph = face.glyph_index(c).unwrap_or_default();
+                let advance = face.glyph_hor_advance(glyph).unwrap_or(0) as f32;
+                advance * units_per_em / face.units_per_em() as f32
+            })
+            .sum()
+    }
+
+    /// Convert a width in pixels at font `size` to font units.
+    fn to_units(&self, px: f32, size: f32) -> f32 {
+        px * self.fonts.primary().units_per_em() as f32 / size
+    }
+}
+
+/// Greedily break `text` into lines no wider than `max_units`, breaking on
+/// whitespace. Words wider than a whole line are broken between characters.
+fn wrap(metrics: &Metrics, text: &str, max_units: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{current} {word}")
+        };
+        if metrics.width(&candidate) <= max_units {
+            current = candidate;
+            continue;
+        }
+
+        if !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        for c in word.chars() {
+            current.push(c);
+            if metrics.width(&current) > max_units && current.chars().count() > 1 {
+                current.pop();
+                lines.push(std::mem::replace(&mut current, c.to_string()));
+            }
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Shorten `line` until it fits in `max_units` with a trailing ellipsis.
+fn ellipsize(metrics: &Metrics, line: &str, max_units: f32) -> String {
+    let mut cut = line.trim_end().to_string();
+    loop {
+        let candidate = format!("{cut}{ELLIPSIS}");
+        if cut.is_empty() || metrics.width(&candidate) <= max_units {
+            return candidate;
+        }
+        cut.pop();
+        cut.truncate(cut.trim_end().len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAME_BOX: TextBox = TextBox {
+        max_width: 505.0,
+        max_lines: 2,
+        max_size: 64.0,
+        min_size: 40.0,
+    };
+
+    /// Every character left in the lines has a glyph in the face that draws
+    /// it, so none is drawn as `.notdef`.
+    fn assert_no_notdef(fonts: &FontChain, laid_out: &LaidOutText) {
+        for c in laid_out.lines.iter().flat_map(|line| line.chars()) {
+            assert!(
+                fonts.face_for(c).glyph_index(c).is_some(),
+                "{c:?} (U+{:04X}) would be drawn as .notdef",
+                c as u32
+            );
+        }
+    }
+
+    fn lay_out(text: &str) -> (LaidOutText, LaidOutText) {
+        let name = layout(&FontChain::name().unwrap(), text, &NAME_BOX);
+        let title = layout(&FontChain::title().unwrap(), text, &NAME_BOX);
+        (name, title)
+    }
+
+    #[test]
+    fn scripts_with_bundled_fonts_are_drawn_in_full() {
+        for text in [
+            "Crème Brûlée Café",
+            "Погода на завтра",
+            "Ψηφιακός Κήπος",
+            "مرحبا بالعالم",
+            "שלום עולם",
+            "Rocket 🚀 Launch ❤",
+        ] {
+            let (name, title) = lay_out(text);
+            assert_no_notdef(&FontChain::name().unwrap(), &name);
+            assert_no_notdef(&FontChain::title().unwrap(), &title);
+            assert_eq!(name.lines.join(" "), text);
+            assert_eq!(title.lines.join(" "), text);
+        }
+    }
+
+    #[cfg(cjk_font)]
+    #[test]
+    fn cjk_text_is_drawn_in_full() {
+        for text in [
+            "寿司タイマー",
+            "天气预报",
+            "台灣電影資料庫",
+            "한국어 노트",
+            "한국어 Notes 🚀 日本語",
+        ] {
+            let (name, title) = lay_out(text);
+            assert_no_notdef(&FontChain::name().unwrap(), &name);
+            assert_no_notdef(&FontChain::title().unwrap(), &title);
+            assert_eq!(name.lines.join(" "), text);
+            assert_eq!(title.lines.join(" "), text);
+        }
+    }
+
+    #[test]
+    fn characters_without_a_font_are_left_out() {
+        let (name, title) = lay_out("\u{10000}\u{10001}\u{10002}");
+        assert!(name.lines.is_empty());
+        assert!(title.lines.is_empty());
+
+        let (name, title) = lay_out("\u{10000} Notes 🚀");
+        assert_no_notdef(&FontChain::name().unwrap(), &name);
+        assert_no_notdef(&FontChain::title().unwrap(), &title);
+        assert_eq!(name.lines.join(" "), "Notes 🚀");
+    }
+
+    #[test]
+    fn right_to_left_text_is_right_aligned() {
+        for text in ["שלום עולם", "مرحبا بالعالم", "«שלום» world"] {
+            let (name, _) = lay_out(text);
+            let align = name.align(55.0, 505.0);
+            assert_eq!((align.x, align.anchor), (560.0, "end"), "{text}");
+        }
+        for text in ["Hello עולם", "Погода", "123 abc"] {
+            let (name, _) = lay_out(text);
+            let align = name.align(55.0, 505.0);
+            assert_eq!((align.x, align.anchor), (55.0, "start"), "{text}");
+        }
+    }
+}
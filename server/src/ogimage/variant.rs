@@ -0,0 +1,244 @@
+use resvg::tiny_skia::Pixmap;
+
+use super::text_layout::TextBox;
+
+/// Card layouts, each with its own SVG template and pixel size.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OgVariant {
+    /// 1200×630, the Open Graph default used by most platforms, and by X
+    /// for `summary_large_image` cards.
+    Landscape,
+    /// 1080×1080, for platforms that crop previews to a square.
+    Square,
+    /// 600×315, a small card for thumbnails and oEmbed consumers.
+    Thumbnail,
+}
+
+/// Encodings an OG image can be served in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OgFormat {
+    Png,
+    Jpeg,
+    /// Lossless WebP.
+    WebP,
+}
+
+/// Where the text goes on a card.
+pub(super) struct Layout {
+    /// Left edge of the name and title boxes.
+    pub text_x: f32,
+    pub name: TextBox,
+    /// Baseline of the first line of the app name.
+    pub name_y: f32,
+    /// `None` if the variant has no room for the subtitle.
+    pub title: Option<TextBox>,
+}
+
+const JPEG_QUALITY: u8 = 85;
+
+impl OgVariant {
+    pub(super) const ALL: [OgVariant; 3] = [
+        OgVariant::Landscape,
+        OgVariant::Square,
+        OgVariant::Thumbnail,
+    ];
+
+    /// Parse an image file name like `og.png` or `og-square.jpg`.
+    pub fn from_file_name(name: &str) -> Option<(OgVariant, OgFormat)> {
+        let (stem, extension) = name.rsplit_once('.')?;
+        let variant = OgVariant::ALL.into_iter().find(|v| v.stem() == stem)?;
+        let format = OgFormat::ALL
+            .into_iter()
+            .find(|f| f.extension() == extension)?;
+        Some((variant, format))
+    }
+
+    /// The file name this variant is served under in `format`.
+    pub fn file_name(self, format: OgFormat) -> String {
+        format!("{}.{}", self.stem(), format.extension())
+    }
+
+    fn stem(self) -> &'static str {
+        match self {
+            OgVariant::Landscape => "og",
+            OgVariant::Square => "og-square",
+            OgVariant::Thumbnail => "og-thumb",
+        }
+    }
+
+    pub fn size(self) -> (u32, u32) {
+        match self {
+            OgVariant::Landscape => (1200, 630),
+            OgVariant::Square => (1080, 1080),
+            OgVariant::Thumbnail => (600, 315),
+        }
+    }
+
+    pub(super) const ALL_TEMPLATES: [(&'static str, &'static str); 3] = [
+        ("landscape", include_str!("includes/og_landscape.svg")),
+        ("square", include_str!("includes/og_square.svg")),
+        ("thumbnail", include_str!("includes/og_thumbnail.svg")),
+    ];
+
+    /// Name and source of the SVG template this variant is drawn from.
+    pub(super) fn template(self) -> (&'static str, &'static str) {
+        let index = match self {
+            OgVariant::Landscape => 0,
+            OgVariant::Square => 1,
+            OgVariant::Thumbnail => 2,
+        };
+        Self::ALL_TEMPLATES[index]
+    }
+
+    pub(super) fn layout(self, has_screenshot: bool) -> Layout {
+        match (self, has_screenshot) {
+            // Text in the left column, screenshot on the right
+            (OgVariant::Landscape, true) => Layout {
+                text_x: 55.0,
+                name: TextBox {
+                    max_width: 505.0,
+                    max_lines: 2,
+                    max_size: 64.0,
+                    min_size: 40.0,
+                },
+                name_y: 100.0,
+                title: Some(TextBox {
+                    max_width: 505.0,
+                    max_lines: 3,
+                    max_size: 40.0,
+                    min_size: 26.0,
+                }),
+            },
+            (OgVariant::Landscape, false) => Layout {
+                text_x: 55.0,
+                name: TextBox {
+                    max_width: 1090.0,
+                    max_lines: 2,
+                    max_size: 64.0,
+                    min_size: 40.0,
+                },
+                name_y: 100.0,
+                title: Some(TextBox {
+                    max_width: 1090.0,
+                    max_lines: 2,
+                    max_size: 40.0,
+                    min_size: 26.0,
+                }),
+            },
+            // Text below the screenshot
+            (OgVariant::Square, true) => Layout {
+                text_x: 60.0,
+                name: TextBox {
+                    max_width: 960.0,
+                    max_lines: 2,
+                    max_size: 64.0,
+                    min_size: 44.0,
+                },
+                name_y: 590.0,
+                title: Some(TextBox {
+                    max_width: 960.0,
+                    max_lines: 2,
+                    max_size: 36.0,
+                    min_size: 28.0,
+                }),
+            },
+            (OgVariant::Square, false) => Layout {
+                text_x: 60.0,
+                name: TextBox {
+                    max_width: 960.0,
+                    max_lines: 3,
+                    max_size: 88.0,
+                    min_size: 52.0,
+                },
+                name_y: 150.0,
+                title: Some(TextBox {
+                    max_width: 960.0,
+                    max_lines: 4,
+                    max_size: 44.0,
+                    min_size: 30.0,
+                }),
+            },
+            // Name only, over the bottom of the screenshot
+            (OgVariant::Thumbnail, true) => Layout {
+                text_x: 28.0,
+                name: TextBox {
+                    max_width: 544.0,
+                    max_lines: 1,
+                    max_size: 36.0,
+                    min_size: 24.0,
+                },
+                name_y: 285.0,
+                title: None,
+            },
+            (OgVariant::Thumbnail, false) => Layout {
+                text_x: 28.0,
+                name: TextBox {
+                    max_width: 544.0,
+                    max_lines: 2,
+                    max_size: 40.0,
+                    min_size: 28.0,
+                },
+                name_y: 64.0,
+                title: None,
+            },
+        }
+    }
+}
+
+impl OgFormat {
+    const ALL: [OgFormat; 3] = [OgFormat::Png, OgFormat::Jpeg, OgFormat::WebP];
+
+    fn extension(self) -> &'static str {
+        match self {
+            OgFormat::Png => "png",
+            OgFormat::Jpeg => "jpg",
+            OgFormat::WebP => "webp",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            OgFormat::Png => "image/png",
+            OgFormat::Jpeg => "image/jpeg",
+            OgFormat::WebP => "image/webp",
+        }
+    }
+
+    pub(super) fn encode(self, pixmap: &Pixmap) -> Result<Vec<u8>, String> {
+        let mut out = Vec::new();
+        match self {
+            OgFormat::Png => {
+                return pixmap
+                    .encode_png()
+                    .map_err(|e| format!("PNG encode error: {e}"))
+            }
+            OgFormat::Jpeg => jpeg_encoder::Encoder::new(&mut out, JPEG_QUALITY)
+                .encode(
+                    &rgb(pixmap),
+                    pixmap.width() as u16,
+                    pixmap.height() as u16,
+                    jpeg_encoder::ColorType::Rgb,
+                )
+                .map_err(|e| format!("JPEG encode error: {e}"))?,
+            OgFormat::WebP => image_webp::WebPEncoder::new(&mut out)
+                .encode(
+                    &rgb(pixmap),
+                    pixmap.width(),
+                    pixmap.height(),
+                    image_webp::ColorType::Rgb8,
+                )
+                .map_err(|e| format!("WebP encode error: {e}"))?,
+        }
+        Ok(out)
+    }
+}
+
+/// Drop the alpha channel. Cards are fully opaque, so the pixmap's
+/// premultiplied RGBA is already plain RGB.
+fn rgb(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .data()
+        .chunks_exact(4)
+        .flat_map(|px| [px[0], px[1], px[2]])
+        .collect()
+}
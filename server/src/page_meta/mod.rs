@@ -0,0 +1,173 @@
+use serde::Serialize;
+
+use crate::app::app_types::App;
+use crate::{config, escape, json_ld};
+
+pub const SITE_NAME: &str = "Caffeine January Promptathon Showcase";
+
+/// Social preview image for a page, with the dimensions crawlers need to lay
+/// out the card before fetching it.
+#[derive(Serialize)]
+pub struct OgImage {
+    pub url: String,
+    pub mime_type: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub alt: String,
+}
+
+impl OgImage {
+    /// A generated 1200×630 PNG card served at `path`, e.g. `/og/home.png`.
+    pub fn card(path: &str, alt: &str) -> Self {
+        OgImage {
+            url: format!("{}{path}", config::canonical_origin()),
+            mime_type: "image/png",
+            width: 1200,
+            height: 630,
+            alt: alt.to_string(),
+        }
+    }
+
+    /// The home page card, also used by pages without a card of their own.
+    fn site_default() -> Self {
+        OgImage::card("/og/home.png", SITE_NAME)
+    }
+}
+
+/// Everything a server-rendered page needs in its `<head>`: title,
+/// description, canonical URL, Open Graph and Twitter card tags, and
+/// optional structured data and oEmbed discovery link.
+///
+/// All URLs are absolute, built from the configured canonical origin, since
+/// crawlers reject relative `og:image` and canonical URLs.
+#[derive(Serialize)]
+pub struct PageMeta {
+    pub site_name: &'static str,
+    pub title: String,
+    pub description: String,
+    /// `None` for pages that should not be indexed, e.g. 404 pages.
+    pub canonical_url: Option<String>,
+    pub noindex: bool,
+    pub og_type: &'static str,
+    pub og_image: OgImage,
+    pub twitter_card: &'static str,
+    pub twitter_site: Option<String>,
+    pub twitter_creator: Option<String>,
+    /// schema.org structured data, rendered as a JSON-LD `<script>`.
+    pub json_ld: Option<serde_json::Value>,
+    pub oembed_url: Option<String>,
+}
+
+impl PageMeta {
+    /// Metadata for an indexable page at `path` using the site-wide image.
+    pub fn new(path: &str, title: &str, description: &str) -> Self {
+        PageMeta {
+            site_name: SITE_NAME,
+            title: title.to_string(),
+            description: description.to_string(),
+            canonical_url: Some(format!("{}{path}", config::canonical_origin())),
+            noindex: false,
+            og_type: "website",
+            og_image: OgImage::site_default(),
+            twitter_card: "summary_large_image",
+            twitter_site: config::twitter_site(),
+            twitter_creator: None,
+            json_ld: None,
+            oembed_url: None,
+        }
+    }
+
+    /// Metadata for a page that should not be indexed and has no canonical URL.
+    pub fn noindex(title: &str, description: &str) -> Self {
+        PageMeta {
+            canonical_url: None,
+            noindex: true,
+            ..PageMeta::new("/", title, description)
+        }
+    }
+
+    /// Metadata for an `/app/:id` page.
+    pub fn for_app(app: &App) -> Self {
+        let origin = config::canonical_origin();
+        let page_url = format!("{origin}/app/{}", app.id);
+        let name = app.app_name.as_deref().unwrap_or(&app.title);
+
+        PageMeta {
+            og_type: "article",
+            og_image: OgImage {
+                url: format!("{origin}/app/{}/og.png", app.id),
+                mime_type: "image/png",
+                width: 1200,
+                height: 630,
+                alt: match &app.author_name {
+                    Some(author) => format!("{name} by {author}"),
+                    None => name.to_string(),
+                },
+            },
+            twitter_creator: app.social_post_url.as_deref().and_then(twitter_handle),
+            json_ld: Some(json_ld::software_application(app)),
+            oembed_url: Some(format!(
+                "{origin}/oembed?url={}&format=json",
+                escape::url_component(&page_url)
+            )),
+            ..PageMeta::new(&format!("/app/{}", app.id), &app.title, &app.description)
+        }
+    }
+
+    /// Metadata for an `/author/:name` page listing `apps`.
+    pub fn for_author(author: &str, apps: &[App]) -> Self {
+        let path = format!("/author/{}", escape::url_component(author));
+        let title = format!("Apps by {author}");
+        let description = match apps.len() {
+            1 => format!("1 app submitted to the January Caffeine promptathon by {author}."),
+            n => format!("{n} apps submitted to the January Caffeine promptathon by {author}."),
+        };
+
+        PageMeta::new(&path, &title, &description)
+            .with_og_image(OgImage::card(&format!("{path}/og.png"), &title))
+            .with_json_ld(json_ld::collection_page(&title, &description, apps))
+    }
+
+    /// Use `og_image` instead of the site-wide card.
+    pub fn with_og_image(mut self, og_image: OgImage) -> Self {
+        self.og_image = og_image;
+        self
+    }
+
+    /// Attach schema.org structured data, rendered as JSON-LD.
+    pub fn with_json_ld(mut self, data: serde_json::Value) -> Self {
+        self.json_ld = Some(data);
+        self
+    }
+}
+
+/// Extract the author's `@handle` from an X/Twitter post URL such as
+/// `https://x.com/someone/status/123`. Returns `None` for other sites and for
+/// handle-less links like `https://x.com/i/status/123`.
+fn twitter_handle(post_url: &str) -> Option<String> {
+    let rest = post_url
+        .strip_prefix("https://")
+        .or_else(|| post_url.strip_prefix("http://"))?;
+    let (host, path) = rest.split_once('/')?;
+    let host = host
+        .strip_prefix("www.")
+        .or_else(|| host.strip_prefix("mobile."))
+        .unwrap_or(host);
+    if host != "x.com" && host != "twitter.com" {
+        return None;
+    }
+
+    let handle = path.split(['/', '?', '#']).next()?;
+    let is_valid = !handle.is_empty()
+        && handle.len() <= 15
+        && handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    // Reserved first path segments that are not user handles
+    let is_reserved = matches!(
+        handle.to_ascii_lowercase().as_str(),
+        "i" | "home" | "intent" | "share" | "search" | "hashtag" | "explore"
+    );
+
+    (is_valid && !is_reserved).then(|| format!("@{handle}"))
+}
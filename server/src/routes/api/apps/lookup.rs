@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+
+use crate::app::AppManager;
+use ic_asset_router::{route, HttpResponse, RouteContext, StatusCode};
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    #[serde(default)]
+    pub url: String,
+}
+
+/// The app submitted with `url`, compared in canonical form, so
+/// `http://Example.com/?utm_source=x` finds `https://example.com`.
+#[route(certification = "skip")]
+pub fn get(ctx: RouteContext<(), SearchParams>) -> HttpResponse<'static> {
+    let (status, body) = match AppManager::find_by_url(&ctx.search.url) {
+        Ok(Some(app)) => (
+            StatusCode::OK,
+            serde_json::to_vec(&app).unwrap_or_else(|_| b"{}".to_vec()),
+        ),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            br#"{"error":"App not found"}"#.to_vec(),
+        ),
+        Err(_) => (
+            StatusCode::BAD_REQUEST,
+            br#"{"error":"Invalid URL"}"#.to_vec(),
+        ),
+    };
+
+    HttpResponse::builder()
+        .with_status_code(status)
+        .with_headers(vec![(
+            "content-type".to_string(),
+            "application/json".to_string(),
+        )])
+        .with_body(Cow::Owned(body))
+        .build()
+}
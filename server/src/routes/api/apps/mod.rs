@@ -1,3 +1,4 @@
+pub mod index;
 #[allow(non_snake_case)]
 pub mod _id;
-pub mod index;
+pub mod lookup;
@@ -1,2 +1,3 @@
+pub mod stats;
 pub mod search;
 pub mod apps;
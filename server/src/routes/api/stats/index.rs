@@ -0,0 +1,25 @@
+use std::borrow::Cow;
+
+use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+
+/// App, author, domain and submission aggregates, see [`crate::stats::Stats`].
+/// Invalidated with the rest of the app data.
+pub fn get(_ctx: RouteContext<()>) -> HttpResponse<'static> {
+    match crate::stats::get()
+        .and_then(|stats| serde_json::to_vec(&*stats).map_err(|e| e.to_string()))
+    {
+        Ok(body) => HttpResponse::builder()
+            .with_status_code(StatusCode::OK)
+            .with_headers(vec![(
+                "content-type".to_string(),
+                "application/json".to_string(),
+            )])
+            .with_body(Cow::Owned(body))
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(format!("Stats failed: {e}").into_bytes()))
+            .build(),
+    }
+}
@@ -0,0 +1,42 @@
+use crate::app::AppManager;
+use crate::ogimage::{self, OgVariant};
+use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+
+use super::Params;
+
+/// OG images for an app, one path per variant and format: `og.png`,
+/// `og-square.jpg`, `og-thumb.webp`, … Each path is certified separately.
+pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
+    let Some((variant, format)) = OgVariant::from_file_name(&ctx.params.file) else {
+        return HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::NOT_FOUND)
+            .with_body(b"Not found".to_vec())
+            .build();
+    };
+
+    let id: i64 = match ctx.params.id.parse() {
+        Ok(id) => id,
+        Err(_) => {
+            return HttpResponse::builder()
+                .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+                .with_status_code(StatusCode::BAD_REQUEST)
+                .with_body(b"Invalid app ID".to_vec())
+                .build();
+        }
+    };
+
+    // Look up the app to get its name and image_id
+    let app = match AppManager::get_by_id(id) {
+        Ok(app) => app,
+        Err(_) => {
+            return HttpResponse::builder()
+                .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+                .with_status_code(StatusCode::NOT_FOUND)
+                .with_body(b"App not found".to_vec())
+                .build();
+        }
+    };
+
+    ogimage::image_response(ogimage::render_for_app(&app, variant, format), format)
+}
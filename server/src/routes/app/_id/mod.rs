@@ -6,6 +6,6 @@ pub struct Params {
     pub id: String,
 }
 
-#[path = "og.png.rs"]
-pub mod og_png;
 pub mod index;
+#[allow(non_snake_case)]
+pub mod _file;
@@ -0,0 +1,21 @@
+use crate::app::AppManager;
+use crate::page_meta::PageMeta;
+use crate::ssr;
+use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+
+use super::Params;
+
+pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
+    let author = ctx.params.name.as_str();
+    let apps = AppManager::list_by_author(author).unwrap_or_default();
+    let meta = if apps.is_empty() {
+        PageMeta::noindex("Author Not Found", "No apps by this author could be found")
+    } else {
+        PageMeta::for_author(author, &apps)
+    };
+
+    // Gallery markup for crawlers and no-JS clients; React hands the author
+    // over to the gallery search on load
+    let page = ssr::render_author(author, &apps).and_then(|body| ssr::render_page(&meta, &body));
+    ssr::html_response(page, StatusCode::OK)
+}
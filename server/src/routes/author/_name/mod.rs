@@ -0,0 +1,11 @@
+/// Typed route parameters for this route segment.
+///
+/// Auto-generated by the build script. Do not edit.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub name: String,
+}
+
+pub mod index;
+#[path = "og.png.rs"]
+pub mod og_png;
@@ -0,0 +1,19 @@
+use crate::app::AppManager;
+use crate::ogimage::{self, OgFormat};
+use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+
+use super::Params;
+
+/// The card for `/author/:name`, listing the author's app names.
+pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
+    let apps = AppManager::list_by_author(&ctx.params.name).unwrap_or_default();
+    if apps.is_empty() {
+        return HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::NOT_FOUND)
+            .with_body(b"Author not found".to_vec())
+            .build();
+    }
+
+    ogimage::image_response(ogimage::author_card(&ctx.params.name, &apps), OgFormat::Png)
+}
@@ -1,10 +1,15 @@
 use std::borrow::Cow;
 
 use crate::app::AppManager;
-use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+use ic_asset_router::{route, HttpResponse, RouteContext, StatusCode};
 
 use super::Params;
 
+// The asset router adds the global `X-Frame-Options: SAMEORIGIN` header to
+// every certified response, which would stop third-party sites from framing
+// the card. Skip-certified responses are served with the handler's own
+// headers instead.
+#[route(certification = "skip")]
 pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
     let id: i64 = match ctx.params.id.parse() {
         Ok(id) => id,
@@ -17,7 +22,6 @@ pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
         }
     };
 
-    // Look up the app to get its name and image_id
     let app = match AppManager::get_by_id(id) {
         Ok(app) => app,
         Err(_) => {
@@ -29,24 +33,21 @@ pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
         }
     };
 
-    let app_name = app.app_name.as_deref().unwrap_or(&app.title);
-    // Show the AI-generated title as subtitle, but only if it differs from app_name
-    let app_title = if app.title != app_name {
-        Some(app.title.as_str())
-    } else {
-        None
-    };
-    match crate::ogimage::render(app_name, app_title) {
-        Ok(png_bytes) => HttpResponse::builder()
-            .with_headers(vec![("Content-Type".into(), "image/png".into())])
+    match crate::oembed::render_card(&app) {
+        Ok(html) => HttpResponse::builder()
+            .with_headers(vec![
+                ("Content-Type".into(), "text/html".into()),
+                ("Cache-Control".into(), "public, max-age=86400".into()),
+                ("Content-Security-Policy".into(), "frame-ancestors *".into()),
+            ])
             .with_status_code(StatusCode::OK)
-            .with_body(Cow::Owned(png_bytes))
+            .with_body(Cow::Owned(html.into_bytes()))
             .build(),
         Err(e) => HttpResponse::builder()
             .with_headers(vec![("Content-Type".into(), "text/plain".into())])
             .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
             .with_body(Cow::Owned(
-                format!("OG image generation failed: {e}").into_bytes(),
+                format!("Embed card rendering failed: {e}").into_bytes(),
             ))
             .build(),
     }
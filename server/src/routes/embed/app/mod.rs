@@ -0,0 +1,2 @@
+#[allow(non_snake_case)]
+pub mod _id;
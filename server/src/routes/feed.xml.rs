@@ -0,0 +1,14 @@
+use crate::feed::{self, FeedFilter, FeedFormat};
+use ic_asset_router::{route, HttpResponse, RouteContext};
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    pub author: Option<String>,
+    pub q: Option<String>,
+}
+
+#[route(certification = custom(query_params = ["author", "q"]))]
+pub fn get(ctx: RouteContext<(), SearchParams>) -> HttpResponse<'static> {
+    let filter = FeedFilter::from_params(ctx.search.author.as_deref(), ctx.search.q.as_deref());
+    feed::response(FeedFormat::Rss, &filter)
+}
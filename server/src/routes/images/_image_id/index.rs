@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use crate::images::{self, ImageFormat};
+use ic_asset_router::{route, HttpResponse, RouteContext, StatusCode};
+
+use super::Params;
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    pub w: Option<u32>,
+    pub format: Option<String>,
+}
+
+/// A screenshot resized for `srcset`, e.g. `/images/:image_id?w=600&format=webp`.
+/// Defaults to the full width as JPEG.
+///
+/// Screenshots are also linked as `<image_id>_1500.jpg` and `_300.jpg`,
+/// which are served here too.
+#[route(certification = custom(query_params = ["w", "format"]))]
+pub fn get(ctx: RouteContext<Params, SearchParams>) -> HttpResponse<'static> {
+    let (image_id, width, format) = match legacy_file_name(&ctx.params.image_id) {
+        Some((image_id, width)) => (image_id, Some(width), Some("jpeg")),
+        None => (
+            ctx.params.image_id.as_str(),
+            ctx.search.w,
+            ctx.search.format.as_deref(),
+        ),
+    };
+
+    let width = width.unwrap_or(images::WIDTHS[images::WIDTHS.len() - 1]);
+    if !images::WIDTHS.contains(&width) {
+        return bad_request(format!(
+            "Unsupported width, use one of {:?}",
+            images::WIDTHS
+        ));
+    }
+    let format = match format {
+        None => ImageFormat::Jpeg,
+        Some(param) => match ImageFormat::from_param(param) {
+            Some(format) => format,
+            None => return bad_request("Unsupported format, use jpeg or webp".to_string()),
+        },
+    };
+
+    match images::resized(image_id, width, format) {
+        Ok(Some(image)) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), format.mime_type().into())])
+            .with_status_code(StatusCode::OK)
+            .with_body(Cow::Owned(image))
+            .build(),
+        Ok(None) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::NOT_FOUND)
+            .with_body(b"Image not found".to_vec())
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(format!("Image resize failed: {e}").into_bytes()))
+            .build(),
+    }
+}
+
+/// Split `<image_id>_<width>.jpg` into its parts.
+fn legacy_file_name(file_name: &str) -> Option<(&str, u32)> {
+    let (image_id, width) = file_name.strip_suffix(".jpg")?.rsplit_once('_')?;
+    Some((image_id, width.parse().ok()?))
+}
+
+fn bad_request(message: String) -> HttpResponse<'static> {
+    HttpResponse::builder()
+        .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+        .with_status_code(StatusCode::BAD_REQUEST)
+        .with_body(Cow::Owned(message.into_bytes()))
+        .build()
+}
@@ -1,25 +1,21 @@
-use std::borrow::Cow;
-use std::collections::HashMap;
-
+use crate::app::AppManager;
+use crate::json_ld;
+use crate::page_meta::PageMeta;
+use crate::ssr;
 use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
-use minijinja::Environment;
 
 pub fn get(_ctx: RouteContext<()>) -> HttpResponse<'static> {
-    let html = include_str!("../../../dist/index.html");
-    let env = Environment::new();
-    let template = env.template_from_str(html).unwrap();
-    let mut ctx = HashMap::new();
-    ctx.insert("title", "Caffeine January Promptathon Showcase".to_string());
-    ctx.insert(
-        "description",
-        "A gallery showcasing apps submitted to the January Caffeine promptathon.".to_string(),
-    );
-    ctx.insert("og_image", "/og-image.png".to_string());
-    let rendered = template.render(ctx).unwrap();
+    let title = "Caffeine January Promptathon Showcase";
+    let description = "A gallery showcasing apps submitted to the January Caffeine promptathon.";
+    let apps = AppManager::list().unwrap_or_default();
+    let meta = PageMeta::new("/", title, description).with_json_ld(json_ld::collection_page(
+        title,
+        description,
+        &apps,
+    ));
 
-    HttpResponse::builder()
-        .with_headers(vec![("Content-Type".into(), "text/html".into())])
-        .with_status_code(StatusCode::OK)
-        .with_body(Cow::Owned(rendered.into_bytes()))
-        .build()
+    // Gallery markup for crawlers and no-JS clients; React replaces it on load
+    let page =
+        ssr::render_home(title, description, &apps).and_then(|body| ssr::render_page(&meta, &body));
+    ssr::html_response(page, StatusCode::OK)
 }
@@ -1,4 +1,18 @@
-pub mod not_found;
-pub mod app;
+pub mod sitemap;
+#[path = "sitemap.xml.rs"]
+pub mod sitemap_xml;
+#[path = "atom.xml.rs"]
+pub mod atom_xml;
 pub mod index;
+pub mod stats;
+pub mod images;
+pub mod app;
+pub mod search;
+pub mod og;
 pub mod api;
+pub mod embed;
+#[path = "feed.xml.rs"]
+pub mod feed_xml;
+pub mod oembed;
+pub mod author;
+pub mod not_found;
@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use crate::app::AppManager;
+use ic_asset_router::{route, HttpResponse, RouteContext, StatusCode};
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    #[serde(default)]
+    pub url: String,
+    pub format: Option<String>,
+    pub maxwidth: Option<u32>,
+    pub maxheight: Option<u32>,
+}
+
+#[route(certification = custom(query_params = ["url", "format", "maxwidth", "maxheight"]))]
+pub fn get(ctx: RouteContext<(), SearchParams>) -> HttpResponse<'static> {
+    // Only JSON is supported; the oEmbed spec asks for 501 on other formats.
+    if ctx.search.format.as_deref().is_some_and(|f| f != "json") {
+        return HttpResponse::builder()
+            .with_status_code(StatusCode::NOT_IMPLEMENTED)
+            .with_headers(vec![(
+                "content-type".to_string(),
+                "application/json".to_string(),
+            )])
+            .with_body(Cow::Borrowed(br#"{"error":"Unsupported format"}"# as &[u8]))
+            .build();
+    }
+
+    let app = match crate::oembed::parse_app_url(&ctx.search.url)
+        .and_then(|id| AppManager::get_by_id(id).ok())
+    {
+        Some(app) => app,
+        None => {
+            return HttpResponse::builder()
+                .with_status_code(StatusCode::NOT_FOUND)
+                .with_headers(vec![(
+                    "content-type".to_string(),
+                    "application/json".to_string(),
+                )])
+                .with_body(Cow::Borrowed(br#"{"error":"App not found"}"# as &[u8]))
+                .build();
+        }
+    };
+
+    let oembed = crate::oembed::response(&app, ctx.search.maxwidth, ctx.search.maxheight);
+    let body = serde_json::to_vec(&oembed).unwrap_or_else(|_| b"{}".to_vec());
+
+    HttpResponse::builder()
+        .with_status_code(StatusCode::OK)
+        .with_headers(vec![(
+            "content-type".to_string(),
+            "application/json".to_string(),
+        )])
+        .with_body(Cow::Owned(body))
+        .build()
+}
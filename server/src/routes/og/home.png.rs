@@ -0,0 +1,12 @@
+use crate::app::AppManager;
+use crate::ogimage::{self, OgFormat};
+use crate::page_meta::SITE_NAME;
+use ic_asset_router::{HttpResponse, RouteContext};
+
+/// The home page card, also the fallback card for pages without their own.
+/// Invalidated with the rest of the app data, so the submission count and
+/// mosaic stay current.
+pub fn get(_ctx: RouteContext<()>) -> HttpResponse<'static> {
+    let apps = AppManager::list().unwrap_or_default();
+    ogimage::image_response(ogimage::home_card(SITE_NAME, &apps), OgFormat::Png)
+}
@@ -0,0 +1,5 @@
+#[path = "search.png.rs"]
+pub mod search_png;
+pub mod preview;
+#[path = "home.png.rs"]
+pub mod home_png;
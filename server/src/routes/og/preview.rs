@@ -0,0 +1,32 @@
+use crate::ogimage::{self, OgFormat};
+use ic_asset_router::{route, HttpResponse, RouteContext, StatusCode};
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    pub template: Option<String>,
+    pub name: Option<String>,
+    pub title: Option<String>,
+}
+
+/// A sample card drawn with an uploaded, not necessarily active, template.
+/// `template` is the preview key returned by `upload_og_template`.
+#[route(certification = custom(query_params = ["template", "name", "title"]))]
+pub fn get(ctx: RouteContext<(), SearchParams>) -> HttpResponse<'static> {
+    let key = ctx.search.template.as_deref().unwrap_or_default();
+    let name = ctx.search.name.as_deref().unwrap_or("Sample app");
+    let title = ctx
+        .search
+        .title
+        .as_deref()
+        .unwrap_or("A short description of what the app does");
+
+    match ogimage::preview(key, name, title) {
+        Ok(Some(png)) => ogimage::image_response(Ok(png), OgFormat::Png),
+        Ok(None) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::NOT_FOUND)
+            .with_body(b"Unknown preview key".to_vec())
+            .build(),
+        Err(e) => ogimage::image_response(Err(e), OgFormat::Png),
+    }
+}
@@ -0,0 +1,22 @@
+use crate::app::AppManager;
+use crate::ogimage::{self, OgFormat};
+use ic_asset_router::{route, HttpResponse, RouteContext};
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    pub q: Option<String>,
+}
+
+/// The card for `/search?q=`, certified per query like the page itself. The
+/// query is normalized first, so its variants share one persisted card.
+#[route(certification = custom(query_params = ["q"]))]
+pub fn get(ctx: RouteContext<(), SearchParams>) -> HttpResponse<'static> {
+    let query = ogimage::search_card_query(ctx.search.q.as_deref().unwrap_or_default());
+
+    let apps = if query.is_empty() {
+        vec![]
+    } else {
+        AppManager::search(&query).unwrap_or_default()
+    };
+    ogimage::image_response(ogimage::search_card(&query, &apps), OgFormat::Png)
+}
@@ -0,0 +1,46 @@
+use crate::app::app_types::App;
+use crate::app::AppManager;
+use crate::page_meta::{OgImage, PageMeta};
+use crate::{escape, ogimage, ssr};
+use ic_asset_router::{route, HttpResponse, RouteContext, StatusCode};
+
+#[derive(Default, serde::Deserialize)]
+pub struct SearchParams {
+    pub q: Option<String>,
+}
+
+#[route(certification = custom(query_params = ["q"]))]
+pub fn get(ctx: RouteContext<(), SearchParams>) -> HttpResponse<'static> {
+    let query = ctx.search.q.as_deref().map(str::trim).unwrap_or_default();
+
+    let apps = if query.is_empty() {
+        vec![]
+    } else {
+        AppManager::search(query).unwrap_or_default()
+    };
+    let title = if query.is_empty() {
+        "Search".to_string()
+    } else {
+        format!("Search results for \u{201c}{query}\u{201d}")
+    };
+    // Result pages are thin, query-dependent content, so keep them out of
+    // search engine indexes
+    let meta = PageMeta::noindex(
+        &title,
+        "Search the apps submitted to the January Caffeine promptathon.",
+    )
+    .with_og_image(OgImage::card(&card_url(query, &apps), &title));
+    let page = ssr::render_search(query, &apps).and_then(|body| ssr::render_page(&meta, &body));
+    ssr::html_response(page, StatusCode::OK)
+}
+
+/// The search card for `query`. Queries without results share the generic
+/// card, so only queries that find something are rendered on their own.
+fn card_url(query: &str, apps: &[App]) -> String {
+    let query = ogimage::search_card_query(query);
+    if query.is_empty() || apps.is_empty() {
+        "/og/search.png".to_string()
+    } else {
+        format!("/og/search.png?q={}", escape::url_component(&query))
+    }
+}
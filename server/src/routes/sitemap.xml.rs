@@ -0,0 +1,23 @@
+use std::borrow::Cow;
+
+use crate::app::AppManager;
+use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+
+pub fn get(_ctx: RouteContext<()>) -> HttpResponse<'static> {
+    let apps = AppManager::list().unwrap_or_default();
+
+    match crate::sitemap::render(&apps) {
+        Ok(xml) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "application/xml".into())])
+            .with_status_code(StatusCode::OK)
+            .with_body(Cow::Owned(xml.into_bytes()))
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(
+                format!("Sitemap generation failed: {e}").into_bytes(),
+            ))
+            .build(),
+    }
+}
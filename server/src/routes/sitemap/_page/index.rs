@@ -0,0 +1,47 @@
+use std::borrow::Cow;
+
+use crate::app::AppManager;
+use ic_asset_router::{HttpResponse, RouteContext, StatusCode};
+
+use super::Params;
+
+pub fn get(ctx: RouteContext<Params>) -> HttpResponse<'static> {
+    // Pages are addressed as /sitemap/<n>.xml
+    let page: usize = match ctx
+        .params
+        .page
+        .strip_suffix(".xml")
+        .and_then(|n| n.parse().ok())
+    {
+        Some(page) => page,
+        None => {
+            return HttpResponse::builder()
+                .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+                .with_status_code(StatusCode::BAD_REQUEST)
+                .with_body(b"Invalid sitemap page".to_vec())
+                .build();
+        }
+    };
+
+    let apps = AppManager::list().unwrap_or_default();
+
+    match crate::sitemap::render_page(&apps, page) {
+        Ok(Some(xml)) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "application/xml".into())])
+            .with_status_code(StatusCode::OK)
+            .with_body(Cow::Owned(xml.into_bytes()))
+            .build(),
+        Ok(None) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::NOT_FOUND)
+            .with_body(b"Sitemap page not found".to_vec())
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(
+                format!("Sitemap generation failed: {e}").into_bytes(),
+            ))
+            .build(),
+    }
+}
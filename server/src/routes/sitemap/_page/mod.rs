@@ -0,0 +1,9 @@
+/// Typed route parameters for this route segment.
+///
+/// Auto-generated by the build script. Do not edit.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub page: String,
+}
+
+pub mod index;
@@ -0,0 +1,7 @@
+use crate::stats::{self, Chart};
+use ic_asset_router::{HttpResponse, RouteContext};
+
+/// Apps per author, the most prolific first.
+pub fn get(_ctx: RouteContext<()>) -> HttpResponse<'static> {
+    stats::chart_response(Chart::Authors)
+}
@@ -0,0 +1,7 @@
+use crate::stats::{self, Chart};
+use ic_asset_router::{HttpResponse, RouteContext};
+
+/// Apps with and without a screenshot and a social post.
+pub fn get(_ctx: RouteContext<()>) -> HttpResponse<'static> {
+    stats::chart_response(Chart::Coverage)
+}
@@ -0,0 +1,8 @@
+#[path = "authors.svg.rs"]
+pub mod authors_svg;
+#[path = "submissions.svg.rs"]
+pub mod submissions_svg;
+#[path = "coverage.svg.rs"]
+pub mod coverage_svg;
+#[path = "domains.svg.rs"]
+pub mod domains_svg;
@@ -2,186 +2,186 @@
 -- Generated at: 2026-02-13T13:28:46.529Z
 -- Submissions processed: 183
 
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://myl-f15.caffeine.xyz/', NULL, 'MYL: Transform Fleeting Ideas into Actionable Missions', 'MYL is an innovative app designed to capture and develop your spontaneous ideas from various sources. Save inspiring moments, organize thoughts, and systematically turn random sparks of creativity into clear, achievable missions with structured planning and tracking.', '0f7157e361177b84e7c87da7edaec5e8', 'Thanos Aravanis', 'MyGallery', 'https://x.com/i/status/2018307457567662444');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://how-lh2.caffeine.xyz', NULL, 'Caffeine Draft App Management Page', 'A webpage for managing draft apps on the Caffeine platform, allowing users to publish, restore, or modify their app drafts with features like live publishing, monetization, and custom domain options.', 'fedc8a4751eb1a1cb33255b2537ebec9', 'Pratik', 'How', 'https://x.com/gypsyhim/status/2018178548583051336?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lifeflow-qam.caffeine.xyz/', NULL, 'LifeFlow OS: AI-Powered Holistic Life Management', 'A comprehensive personal management platform that leverages AI to track mental and physical health, enhance productivity, organize daily routines, and provide digital security—all while prioritizing user privacy and autonomy.', 'e624df605a4469556b3eb814c26d2465', 'Danz asydq', 'LifeFlow OS', 'https://x.com/i/status/2018048844416839773');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://veggiemonster-1pc.caffeine.xyz/', NULL, 'VeggieMonster: Gamify Healthy Eating for Kids', 'A fun, interactive app that encourages children to eat fruits and vegetables by feeding a cute monster. Track progress, explore a variety of healthy foods, and make nutrition engaging through playful gameplay.', 'c9d1faf60bc527077b3ad2aea0a96257', 'Glitch', 'VeggieMonster', 'https://x.com/TheGlitchCrypto/status/2018278169724232081');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://savings-plan-b8g.caffeine.xyz/', NULL, 'Savings Plan: Blockchain-Powered Personal Finance Tracking', 'A decentralized savings tracker that helps you create, monitor, and achieve financial goals with secure blockchain technology. Set targets, visualize progress, and stay motivated on your path to financial success.', '359e667250e57268d6b7c0ce6b23bb27', 'Suportus', 'Savings Plan', 'https://x.com/suportus/status/2018347616078070094');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://refuelos---wellness-companion-for-remote-workers-n9e.caffeine.xyz/', NULL, 'RefuelOS: Wellness App for Remote Workers'' Mental Health', 'A digital wellness companion designed to support remote workers'' mental health and well-being. Offers calming tools, secure authentication, and personalized support to help professionals maintain balance and reduce stress in their work-from-home environment.', '568f921d4669c8acb4e9948b3fdfe78d', 'Gboyega_Designs', 'RefuelOS', 'https://www.linkedin.com/posts/adedokun-gboyega_graphicdesign-uxdesign-wellnessdesign-activity-7424124366193364992-MBnl');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://backshift-ueq.caffeine.xyz', NULL, 'Backshift: Resilience and Personal Growth Platform', 'Backshift is a motivational platform encouraging personal resilience and transformation. With a minimalist approach, it inspires individuals to persevere, adapt, and find new paths forward when facing challenges, embodying the philosophy of ''Don''t quit. Just shift.''', 'e2143bfe39200d8918e06dab1ab291ee', 'Forida', 'Backshift', 'https://x.com/i/status/2018360994377142560');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://today-63t.caffeine.xyz/', NULL, 'Today: Personal Daily Intention & Reflection App', 'A private, secure digital space designed to help you set daily intentions, track personal growth, and engage in meaningful self-reflection. Powered by Internet Identity for a confidential and intuitive journaling experience.', '631a051d9bd28385bcbf0a0e32eccfa1', 'Kay', 'Today', 'https://x.com/kayicp/status/2018358085967126542');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://regret-minimizer-fcx.caffeine.xyz/', NULL, 'Regret Minimizer: AI-Powered Future Simulation Platform', 'Revolutionary self-improvement intelligence platform using advanced neural forecasting, 3D life simulations, and AI-driven insights to help users minimize regrets, maximize potential, and make more informed life decisions through interactive technology.', '1aae573bf3df29bacfc3397a6e2ab956', 'Joseph Israel', 'Regret Minimizer', 'https://x.com/Israel_Icp/status/2018369831418863689?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://nofapnocig-bau.caffeine.xyz/', NULL, 'NoFapNoCigs: Dual Habit Quitting Support Platform', 'A comprehensive support system for individuals seeking to overcome simultaneous addictions to smoking and compulsive behaviors. Empowering users to break free from destructive habits, reclaim personal control, and transform their life through strategic, holistic recovery approaches.', '687d6a6f61850464e88e31b71c14ebbb', 'Dipo', 'NoFapNoCig', 'https://x.com/dipoxyz_/status/2018373749406351438?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://danbasketball-team-management-and-scoring-app-htr.caffeine.xyz/', NULL, 'Dan Basketball: Team Management & Game Scoring App', 'A comprehensive basketball team management platform that allows easy player registration, team formation, and game tracking. Supports team slot assignments, waiting list management, and seamless game preparation for coaches and team organizers.', 'ad1620a44214eedf2b24bb33dd2a7f11', 'Danilo R. Cortaga', 'Dan Basketball team management', 'https://x.com/i/status/2018378949542515152');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://familia-huq.caffeine.xyz/', NULL, 'Familia HUQ: AI-Powered Family Organization & Emotional Intelligence Platform', 'An innovative family app that combines AI, AR, and emotional intelligence to simplify family life. Features include smart scheduling, immersive storytelling, task management, and wellness tracking, all designed to bring families closer together through cutting-edge technology.', 'adfe416ac3355a1df246f8d972ae1880', 'Hannah', 'Familia', 'https://x.com/DomClipX/status/2018377599374729258?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://handnwellness.org', NULL, 'Here & Now: Mental Wellness Companion App for Emotional Support', 'A compassionate mobile app designed to help users navigate their emotional landscape through guided routines, fear management techniques, mood tracking, and personalized mental health support. Offers quick anchoring, reset strategies, and weekly insights to promote emotional resilience.', '9d50e85ee22e5e583bd5e856261cb761', 'Tyler Pernitsch', 'Here & Now', 'https://x.com/tyicp/status/2018380127550218248?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://momentum-8ap.caffeine.xyz/', NULL, 'Momentum: Build Lasting Habits Through Resilience', 'A habit-tracking app that focuses on building momentum instead of punishing streaks. Celebrate progress, track consistency, and support your personal growth with recovery tasks and a unique Momentum Score that helps you stay motivated.', '71ee0ac7d7f0a0365e816a59704e5bb3', 'Maki', 'Streaks Are Fragile Momentum Isn''t', 'https://x.com/hellhou72100196/status/2018380887709089859?s=46&t=2Z81Z_7jbLLl9s2Okq3IAg');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://atomic-stacks-g08.caffeine.xyz', NULL, 'Atomic: AI-Powered Habit Stacking for Sustainable Personal Growth', 'Atomic is an innovative habit-building app that helps you design sustainable routines by stacking small habits onto existing behaviors. Powered by AI coaching, it provides personalized insights and visualizes your progress to transform willpower into consistent, meaningful change.', 'dcbeded66a020b9c147b6ba1c0e749aa', 'Mohamed Salem', 'Atomic: The Habit Stacking Application', 'https://www.linkedin.com/posts/cs-with-mohamed-salem_productivity-mindset-atomichabits-share-7424150168721141761-BMqP?utm_source=share&utm_medium=member_desktop&rcm=ACoAAD7SkX8BBabPAAA8V-CCfn4HEa-12SEE9iI');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://capability-debt-tracker-p4g.caffeine.xyz/', NULL, 'Capability Debt Tracker: Quantify Professional Development Costs', 'A tool that helps professionals calculate the long-term financial and career impact of delaying skill development, certifications, and professional growth. Assess the hidden costs of postponed learning and career advancement across personal and professional domains.', '59f87d426f98c359dfba7799c0ac902a', 'Nairavine', 'Capability debt tracker', 'https://x.com/Nairavine/status/2018384417169813717?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://reconnect-0t2.caffeine.xyz/', NULL, 'ReConnect: Couples Wellness App for Deeper Relationship Connection', 'A digital platform designed to help couples strengthen their bond through private reflections, shared insights, and cooperative moments. Enhance communication, understanding, and intimacy with intuitive wellness tools that support relationship growth.', '4eb3c893fba2b549767b619bceb30c31', 'Fahim  Haque', 'ReConnect', 'https://x.com/fahimhaque77/status/2018384949569548412?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ashane---personal-life-transformation-app-72n.caffeine.xyz/', NULL, 'Ashane: AI Life Coach for Personal Transformation', 'An innovative AI-powered personal coaching app that helps you transform your life by tracking finances, health, mindset, and personal growth. Get personalized guidance from your Future Self to overcome challenges and achieve your dreams.', '7fc93d1bc204727e7d6427dfd8d04d23', 'Tinomutenda Praymore Nkomo', 'Ashane', 'https://vt.tiktok.com/ZSapHevGB/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://readvo-pf0.caffeine.xyz/', NULL, 'ReadVo: Speed Reading Platform for Enhanced Learning & Comprehension', 'A comprehensive training platform that helps users improve reading speed and comprehension through personalized exercises, baseline assessments, progress tracking, and interactive learning tools. Master faster reading with guided techniques and motivational achievements.', '557d6aede4ef16d23f04914ffbf6cf57', 'Nolan Plummer', 'ReadVo', 'https://x.com/DubmansOfficial/status/2018391164215505357');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://pitm.fintiqi.com', NULL, 'TaxManager NG: Simplify Nigerian Personal Income Tax Management', 'Effortlessly manage your Nigerian personal income tax with TaxManager NG. Track income, optimize exemptions, get deadline reminders, and calculate taxes accurately. Secure, user-friendly platform for stress-free tax compliance.', '828132388e9aa1be85bf5e90246476ba', 'Hope Sammy', 'Personal Income Tax Manager', 'https://www.instagram.com/reel/DTw2_uhCGrG/?igsh=MTgwaXR1OXQ0N2E2Nw==');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://better-leader-hiu.caffeine.xyz/', NULL, 'Better Leader: Leadership Skills Development Platform', 'A comprehensive online platform designed to help professionals transform their leadership abilities through personalized assessments, tailored development plans, progress tracking, and expert resources. Enhance your leadership skills with structured, data-driven guidance.', 'af2aedd86f3386c3eb8a41396a749abf', 'Martin Plsek', 'Better Leader', 'https://x.com/plsak/status/2018427328456544490?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://one-step-app-54l.caffeine.xyz/', NULL, 'One Step: Minimal Goal Progression Through Tiny, Achievable Actions', 'A goal-setting app that breaks down personal development into the smallest possible actions, helping users overcome resistance by making progress feel effortless. Inspired by Kaizen and Atomic Habits, One Step focuses on consistent, low-pressure growth across fitness, learning, mindfulness, and personal goals.', 'e78662b6f5a65fe151c8d7c32d2ec14e', 'Valkyrill', 'One Step', 'https://x.com/i/status/2017748323868799452');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://fritso-55m.caffeine.xyz/', NULL, 'Fritso: Calculate Work Hours Needed for Your Purchases', 'A simple tool that helps you understand the real value of your purchases by translating item prices into the number of work hours required to afford them. Gain insights into your spending by measuring the true cost of your desires.', '2d56e7b311b9ca60ea5f6731bdf01e50', 'Adam Snetiker', 'Fritso', 'https://www.instagram.com/p/DURf3NHkl6hkejk6op0zXDx7_Epjn1MPyeD3kg0/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://nr-ayr.caffeine.xyz/', NULL, 'Pythagorean Numerology Insights: Personal Name & Birth Date Analysis', 'Discover the hidden meanings behind your name and birth date using David Phillips'' numerology system. Generate a comprehensive personal numerology report that reveals insights into your life path, personality, and potential compatibility.', '57eee24cd7b295d50201a213830ec94a', 'Greg', 'Pythagorean Numerology', 'https://x.com/psychobiosocial/status/2018473848455450702?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://aimeal-lj1.caffeine.xyz/', NULL, 'Caffeine Draft App: Create, Publish, and Monetize Content', 'A platform for content creators to develop, publish, and monetize digital apps with features like live chat, custom domains, and app analytics. Easily manage and share your creative projects through Caffeine''s streamlined publishing tools.', '2bceecd949c03794b2710dd59a70fa11', 'Incognitov1_', 'MEALai', 'https://www.x.com/incognitov1_');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://icpmemeworld-65u.caffeine.xyz', NULL, 'Icpworldmemepad: Revolutionary ICP Memecoin Platform', 'Create and trade memecoins on the Internet Computer Protocol with lightning-fast trading, advanced analytics, and unique Fair Mode protection. Launch your token in minutes, connect your Icpswap wallet, and join the next viral crypto sensation.', '564c3ae6714f189946d942f9a55fe909', 'Akmal Bhutta', 'Icpworldmemepad', 'https://x.com/icpmemeworld?t=3Bbkhkdt1K5GEbF9hkIYKw&s=08');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://task-management-system-ubp.caffeine.xyz/', NULL, 'Task Management System: Organize, Prioritize, and Optimize Workflow', 'A comprehensive task management platform that enables users to create custom task fields, track task urgency and value, set dependencies, and leverage automatic metrics calculation. Designed to streamline work organization and enhance productivity with intuitive sorting and filtering capabilities.', 'e97082dea91ecc1aac59c4f41053128d', 'Ioannis Giftakis', 'Task Management System', 'https://www.facebook.com/groups/teachersgr/posts/25889959310620394/?__cft__[0]=AZY4nULzeB-QF4As6tBeksOndfw9i5dzxvz1nhqzkdWzjefCwpBtfGhU3fQ458S9S2H7eos6fz_gHcwhLJ1aWt_uBiOwXoEow38fp8F840HgmbwJfocSH689jYVf4vEmmJWTNXHufb-whKkmT5FYa7nW&__tn__=%2CO%2CP-R');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://pelatihanz7-6fw.caffeine.xyz/', NULL, 'Training Management System: Streamline Employee Learning & Development', 'A comprehensive web application for efficient training planning, tracking, and management. Features interactive dashboards, automated certificate tracking, secure authentication, and easy data management for organizations to optimize employee skill development.', '33db02aef1d491add8b4806d8fe4b4b7', 'Fariz Arizal', 'Worker Training Planning and Realization Application', 'https://pelatihanz7-6fw.caffeine.xyz/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://questcrush-ai-7zo.caffeine.xyz/', NULL, 'QuestCrush AI: Gamified Personal Development with AI Coaching', 'Transform personal growth into an engaging adventure with AI-powered quests. Receive smart, personalized challenges, earn XP, track progress, and get motivational support from an intelligent AI coach to level up your life and achieve your goals.', 'a996e9dd38eed6d1734fabac50c59c84', 'Samuel Mungai Owino', 'QuestCrush AI', 'https://x.com/Sam_mungai_/status/2018565489493221528?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://didntquitselfimprovementapp-7gm.caffeine.xyz', NULL, 'Caffeine App Development Platform', 'A web platform for creating, publishing, and managing apps with features like live publishing, monetization options, custom domains, and integrated analytics for developers and creators.', '69ecd02ad0948adffc83b2c367b8afbe', 'John', 'IDidntQuitSelfImprovementApp', 'https://x.com/i/status/2018573622735200744');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://elevatedaily-f64.caffeine.xyz/', NULL, 'Elevate Daily: Personal Growth & Habit Tracking App', 'A comprehensive self-development web application designed to help users build positive habits, track personal goals, and enhance overall wellness through an intuitive digital platform. Empower your personal growth journey with personalized tracking and motivational tools.', 'e43318753f2ceedc020b655ce0951663', 'Baron', 'Elevate Daily', 'https://x.com/RCanaa/status/2018582268441465260?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://quit-valhalla-334.caffeine.xyz/', NULL, 'Quit Valhalla: Habit Tracking for Personal Transformation', 'A motivational app designed to help users track and overcome challenging habits, celebrating personal growth through sobriety tracking, achievements, and inspirational support. Empower your journey of self-improvement with goal-setting and progress monitoring.', '680da88923f4e6b0c16d382be8204b5d', 'Kenny Blaq', 'Quit Vahalla', 'https://x.com/mint_radarxyz/status/2018595582517129224?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://the-key-sz1.caffeine.xyz/', NULL, 'THE KEY: Daily Goal Tracking & Personal Progress App', 'A powerful personal development tool designed to help you systematically track daily actions, build momentum, and progressively move closer to your life goals through consistent, intentional progress tracking.', '05d529d400ec17a43acfe4fd7362ced7', 'Jan Hruška', 'THE KEY', 'https://x.com/AurroraGenArt/status/2018598105168457806');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ecoverse-idw.caffeine.xyz/', NULL, 'KindCart: AI-Powered Sustainability Shopping Assistant', 'KindCart helps shoppers make eco-friendly choices by analyzing receipts, providing instant sustainable product alternatives, and tracking personal carbon impact. Empower your shopping with AI-driven green insights and reduce your environmental footprint.', 'e04441d10089a6a3b630276d4f3af94f', 'Koushik Ghosh', 'KindCart', 'https://x.com/koushik_AI/status/2018599523812253865?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://soulsync-2sm.caffeine.xyz/', NULL, 'SoulSync: AI-Powered Personal Growth Platform', 'An innovative digital platform that combines knowledge management and emotional journaling, enabling personal development through secure, decentralized authentication. Designed to track intellectual and emotional growth with AI-assisted insights.', 'b87a60e8324a66a126bda2d7c8f99435', 'Konson', 'SOULSYNC', 'https://x.com/konson1009/status/2018616102822289867?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://typefaster---typing-challenge-app-mg6.caffeine.xyz', NULL, 'TypeFaster: Typing Challenge Game with Crypto Rewards', 'Improve your typing skills through 5 progressive levels, earn XP, and compete daily for ICP cryptocurrency prizes. Challenge yourself, track your progress, and climb the leaderboard in this engaging typing performance app.', '87c80abc8d852dbcceb302761e103711', 'Vlad Smen', 'TypeFaster', 'https://x.com/tuvav243788/status/2018618188049649668');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://food-recipes-book-application-22y.caffeine.xyz/', NULL, 'Food Recipes Book: Personal Healthy Cooking Companion', 'A digital recipe management platform that lets you create, organize, and share your personal collection of healthy recipes. Securely authenticate and access your culinary library with ease, powered by Internet Computer technology.', 'f2299af00e253d8e35ba5db32b92d26a', 'Artūras', 'Healthy Food Recipes Book', 'https://x.com/i/status/2018620212548116709');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://groundflow---wellness-app-0vp.caffeine.xyz/', NULL, 'GroundFlow: Holistic Wellness App for Mood Tracking & Self-Care', 'A comprehensive wellness app that helps you track mood, manage stress, and improve mental health through daily check-ins, quick practices, progress monitoring, and community support. Designed to empower personal well-being with intuitive, supportive tools.', 'f8eb46c292c7e69830401d1c3d1c9b3c', 'Jephtah Ogan', 'GroundFlow', 'https://x.com/i/status/2018618337723662380');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://better-me-8j4.caffeine.xyz/', NULL, 'Better Me: Personal Growth and Secure Authentication Platform', 'A transformative web application designed to support your personal development journey, offering secure login through Internet Identity and providing tools to help you track, improve, and evolve your best self.', '85b821ce5be0cf350d6486a1b7ee0f79', 'birjahang kerung', '''''Better Me'''' Self Improvement App', 'https://x.com/i/status/2018624811648610447');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lifereset-jol.caffeine.xyz', NULL, 'LifeReset: Mindful Self-Improvement & Personal Growth Platform', 'A transformative digital tool designed to help users build self-awareness through structured reflection, evidence-based progress tracking, and AI-powered insights. Enhance personal growth with daily practice and intelligent pattern recognition.', '4eab95c6a212de66714780d9eb7dc732', 'Judex', 'LifeReset', 'https://x.com/i/status/2018583191792349414');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://iamfocused.app', NULL, 'FOCUS.: Motion-Driven Productivity App to Reclaim Your Attention', 'A revolutionary app that uses motion detection to help you break digital distractions. By physically placing your phone down, you create a tangible commitment to focus, leveraging behavioral psychology to train your brain, boost productivity, and reconnect with deep work.', '7c9c19a0435e7a7cb682edf74f23585a', 'Markus Brunner', 'FOCUS.', 'https://x.com/markus_ai_/status/2018624902186848336?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-you-simulator-k28.caffeine.xyz/', NULL, 'Future You Simulator: Visualize Your Path by 2026', 'An interactive tool that reveals two potential futures based on your current habits—showing your default trajectory and an optimized path. In just 5 minutes and 10 steps, gain clarity on personal growth and actionable insights without judgment.', '1dad6bad09e7ea5a20fb6631c55aab0b', 'sunil limbu', 'Future You Simulator 2026 Edition', 'https://x.com/i/status/2018647285945733370');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://kosha-compass-i1x.caffeine.xyz/', NULL, 'Kosha Compass: Holistic Personal Growth and Self-Discovery Platform', 'A transformative digital tool designed to help individuals track and understand their five layers of being (koshas), supporting personal development, self-awareness, and spiritual growth through a personalized wellness journey.', '02a2713cf8b28cc72b58ae8959d1e75e', 'Vandana', 'Kosha Compass', 'https://www.linkedin.com/posts/vandana-jariwala-459159386_caffeineai-ai-build-activity-7424429074363637760-drEf?utm_source=share&utm_medium=member_desktop&rcm=ACoAAF8QAEUBm2sBPG4fz_DWg2lipb-9E9S0xgM');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://weekly-reflection-gsy.caffeine.xyz/', NULL, 'Weekly Reflection: Personal Journey Tracking & Summarization', 'A secure, private platform for documenting and reflecting on your weekly experiences. Personalize your journey, pause and observe your progress, and securely store insights using Internet Identity authentication.', 'de56b5969e4b1ba152151d13e6a90d35', 'Gunawan Yudho Satrio', 'kenzonutss', 'https://x.com/yudho_oo/status/2018666020488266174?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://my-gratitude-and-positivity-promptathon-v2-aps.caffeine.xyz/', NULL, 'Gratitude & Positivity Promptathon: Daily Reflection & Mindfulness', 'An interactive digital platform designed to help users cultivate daily gratitude and positive thinking through secure, private journaling. Encourage personal growth, mindfulness, and emotional well-being with guided prompts and a supportive online experience.', '495011912e09903fda9f1e70e56620d1', 'Ben Slater', 'My Gratitude & Positivity Promptathon V2', 'https://x.com/monkey898898/status/2018676462795084221?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://deskrhythm-1ua.caffeine.xyz/', NULL, 'DeskRhythm: AI-Powered Wellness App for Healthier Work Habits', 'An intelligent application that uses ambient technology to guide users toward better workplace wellness. Track posture, manage breaks, monitor digital habits, and receive personalized insights to improve daily productivity and overall wellbeing.', 'ed44d85f476671138efc4d4300cfda35', 'Rasheed Mudasiru', 'DeskRhythm', 'https://vt.tiktok.com/ZSaGPsnCf/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://gamified-health-and-wellness-tracker-j9t.caffeine.xyz/', NULL, 'Routinely: Gamified Wellness Tracker for Personal Growth', 'A revolutionary app that transforms self-improvement into an engaging game. Complete daily wellness quests, earn XP, compete on global leaderboards, and level up through cognitive challenges and habit-building activities across mindfulness, fitness, and personal development.', '51554673a376a06385b4e85bf543aac4', 'Syv', 'Routinely', 'https://x.com/0xSyv/status/2018682154494926999');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://daily-icp-mental-health-check-ue7.caffeine.xyz/', NULL, 'ICP Daily Health Check: Economic Simulation & Price Calculation Tool', 'A web application for analyzing and estimating ICP token prices through dynamic economic simulation. Configure cycle demand parameters, explore market variables, and calculate potential price scenarios with interactive dashboard and economic modeling features.', '26de4b567f7911c25af8a9fe1a954373', 'Juice64', 'Make My Day Inputs', 'https://x.com/Juice64icp/status/2017402441042694606?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-you79-25a.caffeine.xyz/', NULL, 'Future You, Interrupted: Goal Tracking & Time-Shifted Messaging', 'A unique platform that allows users to create personal goals, write messages to their future selves, and track progress through an innovative dashboard. Sign in or try demo mode to explore self-reflection and future planning.', '3335caffeb6a4e9c953df31560ac98ea', 'B M Kiran', 'Future You, Interrupted', 'https://x.com/Manojkiran10063/status/2018709212142915638?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://adaptive-habit-coach-aln.caffeine.xyz/', NULL, 'Adaptive Habit Coach: Personalized Habit Building & Progress Tracking', 'A smart, supportive app that helps you build better habits step by step. Track your progress, stay motivated, and create lasting change with personalized insights and encouragement. Secure, private, and designed to help you succeed.', '91258f84fe21951f32e73cc72d959e54', 'Joel', 'Adaptive Habit Coach', 'https://x.com/icpholic/status/2018713286607253575');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://helios-app-6rl.caffeine.xyz/', NULL, 'HELIOS: Personalized Productivity App with Internet Identity Authentication', 'HELIOS is an innovative app designed to enhance your daily rhythm and productivity. Featuring secure Internet Identity login, the platform offers a streamlined approach to managing tasks and personal workflows with cutting-edge blockchain authentication technology.', '8f68decfcfefdf11f4f40addc75e0fca', 'Aron', 'Helios app', 'https://x.com/Meta_Aron/status/2018720423161049201?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://my-info-vault-3ea.caffeine.xyz/', NULL, 'Info Vault: Secure Personal Data Management on the Blockchain', 'A privacy-focused platform for managing personal information like tasks, bills, and notes using blockchain technology. Leverages Internet Identity for secure authentication and ensures data encryption and protection on the Internet Computer.', 'c3582b197c0ca0619131a42741412c47', 'Edi', 'Info Vault', 'https://x.com/EDI_ICP/status/2018724085891576219?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://theinnerop-o2a.caffeine.xyz/', NULL, 'The Inner Operator: Minimalist Objective Tracking for Focused Execution', 'A streamlined productivity tool that operates silently in the background, tracking objectives without distractions. Provides clear orders, honest feedback, and pace tracking to help you execute what matters most, with privacy-first, mobile-friendly design.', 'b7ea670fd9dc1e30d4b998a75176154d', 'Mark Pernitsch', 'The Inner Operator', 'https://x.com/mindr3adr/status/2018744209054793941?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://public-domain-music-radio-l9g.caffeine.xyz/', NULL, 'Public Domain Music Radio: Vintage Streaming for Businesses', 'Stream 100% legal vintage music for restaurants, coffee shops, stores, bars, hotels, and salons. No login required—enjoy curated public domain tracks that create a nostalgic ambiance for your business, completely free forever.', '559fe3ed2d59855e6518787c6be5fa25', 'Romulus', 'Vintage Radio - Public Domain Music for Business', 'https://x.com/Romulus96711505/status/2018747958641020931');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://life-ai-db4.caffeine.xyz/', NULL, 'Life AI: Comprehensive Personal Dashboard for Life Management', 'An innovative digital platform that helps users comprehensively manage and monitor various life aspects through a secure, blockchain-powered dashboard. Track personal progress, maintain privacy, and gain holistic insights into your life''s development.', 'a55770a7dfb65ace24f6236a231ab1c6', 'Aril babunuddin', 'Life AI', 'https://x.com/i/status/2018755320311693655');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-self-notes-6so.caffeine.xyz/', NULL, 'Future Self Notes: Reflective Journaling Across Time', 'A unique journaling app that lets you write messages to your future self with time-delayed delivery. Capture insights, track moods, and receive personalized prompts while keeping your reflections private and secure on blockchain technology.', '3bb10ae54c4477b03305604c8fe00480', 'Shane Bloomfield', 'Future Self Notes', 'https://x.com/i/status/2018628333756629001');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://excal-vc5.caffeine.xyz/', NULL, 'ExCal: Smart Expense Tracking & Budget Management App', 'ExCal helps you take control of your finances through easy expense recording, budget setting, and insightful spending analytics. Securely track, categorize, and visualize your financial habits with an intuitive, privacy-focused application.', '9dbfec0ec329128893949f0a21a6cf28', 'Ketan Chandekar', 'ExCal Expense Calculator', 'https://x.com/ketan_chandekar/status/2018762789482119472?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://anchor-5zs.caffeine.xyz/', NULL, 'Anchor: Holistic Self-Development App for Women', 'A transformative digital platform designed to support women''s personal growth through intentional, energy-aligned practices. Focuses on emotional well-being, setting meaningful intentions, and nurturing self-awareness beyond traditional productivity metrics.', 'e137f2f89baa5986b2e57459ba403cde', 'Michelle', 'Anchor', 'https://x.com/ben_collinzz/status/2018766979272695927?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lockedin-hfg.caffeine.xyz/', NULL, 'LockedIn: Strategic Goal Planning & Accountability App', 'A powerful self-development tool that helps entrepreneurs and professionals transform goals into actionable plans. Break down objectives, track daily progress, conduct weekly reviews, and stay consistently aligned with your vision.', 'a308b4b27a736b189dbc413b990abeb7', 'Rissrozy C. Obiefuna', 'LockedIn', 'https://x.com/i/status/2018763743920783378');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://soma-6he.caffeine.xyz/', NULL, 'Soma: Intuitive Somatic Sensation Tracking App', 'A mobile web application for mindful body awareness, allowing users to log and track bodily sensations through an interactive, dot-based interface. Offers guest mode for quick, local data tracking without account creation.', '472ee1f525913a9346cc73360c4a352c', 'Andreea Coman Oprin', 'Soma', 'https://www.linkedin.com/posts/andreeaoprin_soma-somatic-tracking-mobile-web-application-share-7424546692437417984-n-cy?utm_source=share&utm_medium=member_desktop&rcm=ACoAABaKtEEBqeel5GKa_Vpc3Gl3cw5NKoDdb6U');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://puffinwellness-mu3.caffeine.xyz/', NULL, 'Puffin Wellness: Your Mindful Self-Improvement Companion App', 'A holistic wellness app featuring AI-powered chat, guided breathing, goal tracking, journaling, and interactive tools designed to support emotional well-being and personal growth through engaging, supportive features.', 'bb226b130f1e44232297b161f4c5800b', 'Ben', 'Puffin Wellness', 'https://x.com/PuffinHubx/status/2018781997523706287');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://kindness-garden-egd.caffeine.xyz/', NULL, 'Kindness Garden: Character Growth Through Interactive Quests', 'An innovative platform that helps users develop personal character and kindness through engaging, gamified quests. Securely track your progress using Internet Identity while embarking on a meaningful journey of self-improvement and compassionate growth.', 'f18d65568ecebcadb57849e7940b4770', 'Usertest', 'Kindness Garden', 'https://x.com/lukitagamer6/status/2018793127130403156');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://attn-nmp.caffeine.xyz/', NULL, 'ATTN: Mindful Attention Tracking Without Distractions', 'A minimalist digital tool designed to help you quietly reflect on and understand your daily attention patterns. Simple, streak-free, and notification-free, ATTN offers a gentle approach to self-awareness and personal insight.', 'b704d16f662265a2106111113b294e67', 'Carl Fitzpatrick', 'ATTN', 'https://x.com/i/status/2018793149116899799');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://daily-diary-ytz.caffeine.xyz/', NULL, 'Daily Diary: Private Blockchain-Powered Personal Journal App', 'A secure, modern journal application that leverages blockchain technology to protect your personal memories. Login with Internet Identity for a private, encrypted digital diary experience where your data remains completely yours.', '12b9f2e0fdf6c156408c2e9f6672574a', 'shogaku', 'Yasu', 'https://x.com/i/status/2018805371671085310');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://vision-k0a.caffeine.xyz/', NULL, 'Vision: Transform Dreams into Actionable Visual Goals', 'A powerful goal-setting platform that helps you create inspiring vision boards, break down aspirations into microgoals, and track progress across multiple life areas. Visualize your dreams with personalized images and secure authentication.', 'e284c5e9b305222f7fa78c0b0e1f9041', 'SJ', 'Vision', 'https://www.instagram.com/p/DUT9rFQjCqK/?utm_source=ig_web_copy_link&igsh=MzRlODBiNWFlZA==');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://yip-pjz.caffeine.xyz/', NULL, 'YIP: Personal Goal Setting & Transformation App', 'A powerful personal development platform that helps you design your future through intentional goal-setting, self-reflection, and creative visualization. Transform your year with purpose, create affirmations, build moodboards, and define your ideal self.', '8c8ac84d143cdd8937b45f74cf16d09f', 'ICPVibeCoder', 'YIP app - "Year in Preview"', 'https://x.com/i/status/2018820750719996013');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://wellness-bell-3kp.caffeine.xyz', NULL, 'Wellness Bell: Mindfulness Timer & Meditation Audio Companion', 'A simple web application designed to support meditation and mindfulness practices. Set custom timer durations, configure bell sounds, and create a peaceful audio-guided experience to help you stay focused and centered during your wellness sessions.', '77ccca1601b6cac43f48faafbd05bfd7', 'Jason Khoh', 'Wellness Bell', 'https://x.com/ICP_Insights/status/2018651384858759302?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ironsharpensiron-nrx.caffeine.xyz/', NULL, 'Iron Sharpens Iron: Community-Driven Personal Growth Platform', 'A purpose-driven social network designed to unite individuals committed to self-improvement. Connect with like-minded people, set and track personal goals, share experiences, and grow together through meaningful conversations and mutual accountability.', 'a033a1a649b14cd21a1b0c1351c2ac08', 'Joshua Coston', 'Iron Sharpens Iron', 'https://x.com/Mr_Coston/status/2018852279672320208');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://exercise-and-weight-loss-tracking-app-oz0.caffeine.xyz/', NULL, 'GoalQuest: Fitness Tracking App for Weight Loss & Health Goals', 'Transform your body and life with GoalQuest, a comprehensive fitness platform that helps you create SMART goals, track workouts, visualize progress, and connect with a supportive fitness community. Earn rewards and stay motivated on your health journey.', '9972aa390e9eff50eacb6e2587e88176', 'Joe Walsh', 'Self Improvement goal tracking app', 'https://x.com/JAW66Coin/status/2018858993536696746?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://friction-v70.caffeine.xyz', NULL, 'Friction: Personal Growth App for Intentional Self-Improvement', 'A transformative self-improvement platform that helps users create meaningful personal change through structured guidance, private journaling, goal tracking, and random challenges designed to push individuals beyond their comfort zones and foster continuous personal development.', '206b131b2ad656d64b3ec52a3280419f', 'Juan Fonseca', 'Friction', 'https://x.com/Womplet/status/2018892965469397089?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://the-virtue-nator-rp3.caffeine.xyz/', NULL, 'Virtue-Nator: Cultivate Excellence Through Habitual Virtues', 'An interactive web app exploring philosophical wisdom about virtue, helping users understand and practice key ethical principles. Learn how repeated good choices shape character, with practical guidance on developing virtues like justice, wisdom, and self-improvement.', '5977cd9416e8a822d4616b84d4f4117d', 'Joe', 'THE VIRTUE-NATOR', 'https://x.com/JoeWils41632240/status/2018906318539829367');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://colortexteditor-s0u.caffeine.xyz', NULL, 'ColorTextEditor: Advanced Rich Text Editing with Color Tools', 'A professional React-based text editor offering comprehensive formatting options, advanced color manipulation, and versatile file management. Features include theme toggling, headers, lists, code blocks, and easy data backup and export capabilities.', 'c4fd879e294095f978f55080d54404b7', 'Monte Self', 'ColorTextEditor', 'https://x.com/MonteSelf61');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://capitalis-s01.caffeine.xyz', NULL, 'Capitalis: Personal Capital Allocation & Investor Mindset Platform', 'A calm, systematic approach to understanding personal finance and investing. Track time, money, and effort weekly to develop long-term financial clarity and confidence without pressure or noise. Designed to help you gradually build an intentional investment mindset.', '715dab71fd816a0b6a157a2314ffe42b', 'Sanketh Elalli', 'Capitalis', 'https://www.linkedin.com/posts/snketh_n8n-caffeineai-promptengineering-activity-7424707640880021504-J7iq?utm_source=social_share_send&utm_medium=member_desktop_web&rcm=ACoAADpZWXwBw13xmh6mduaQB_5MfS1FWQeCRq0');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://echomoods-n6b.caffeine.xyz', NULL, 'EchoMoods: Anonymous Global Mood Sharing Platform', 'An innovative app that allows users to anonymously share and explore emotions worldwide. Connect with others through a secure, identity-protected platform that reveals the collective emotional landscape while maintaining personal privacy.', '1f44178b197e8913b33be2d941a7594f', 'Tukalu', 'Echomoods', 'https://x.com/Acut40212407/status/2018968858585923786');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ethosarc-l3q.caffeine.xyz/', NULL, 'Ethos: Personal Growth Companion for Intentional Development', 'A digital platform designed to help users track and enhance personal growth through structured insights, progress monitoring, and a supportive dashboard. Empowers individuals to cultivate intentional self-improvement with secure, user-friendly tracking tools.', '829e764d23f0b800181c69098536452d', 'Müslüm', 'Ethos', 'https://x.com/thec4ke/status/2018977937324908577?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://friction-vault-contracts-lbb.caffeine.xyz/', NULL, 'Friction Vault: Personal Goal Achievement with Financial Accountability', 'A blockchain-powered platform that helps users set and track personal goals through financial stakes. Create self-contracts with dual vault systems, daily check-ins, and smart analytics to overcome obstacles and incentivize success.', '8d61c6625e0f262547bfcce5f0d84639', 'Rajat Kumar', 'Friction Vault Contracts', 'https://x.com/rajatwasan2/status/2018599123474387108?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://smart-goals-v9h.caffeine.xyz/', NULL, 'SMART GOALS: Personal Goal Planning & Achievement Platform', 'A comprehensive goal tracking tool designed to help you set clear objectives, monitor progress, and systematically work towards personal and professional success. Empower yourself with strategic planning and actionable insights.', 'c6b9c783c18583c0637210e8d85fe38e', 'LUKITO NUGROHO', 'SMART GOALS', 'https://x.com/KiiBit/status/2018988116112203938?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://nora-e7n.caffeine.xyz', NULL, 'NORA: Your Mindful Companion for Intentional Living', 'A personal reminder assistant that helps you stay connected to your deeper values and goals through gentle, personalized prompts. NORA supports intentional living by providing meaningful nudges that inspire reflection and alignment with your true motivations.', '5f70f0c3b32ffe3ceec4b7ef50ffd26a', 'Benjamin John', 'N.O.R.A - North-Oriented Reminder Assistant', 'https://x.com/i/status/2018995335578997022');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://one-promise-p83.caffeine.xyz/', NULL, 'One Promise: Daily Self-Trust and Personal Growth', 'A mindful approach to personal development focused on making and keeping one small, meaningful promise to yourself each day. Cultivate self-trust, reflection, and intentional progress through simple, achievable commitments.', 'ff64813ca6aa919acfe762426160aa6a', 'Jacob Kemsley', 'One Promise', 'https://www.linkedin.com/posts/jkemsley_one-promise-share-7424761301492494336-b_hd/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mithaq-l42.caffeine.xyz/', NULL, 'MITHAQ: Personal Accountability Platform for Growth & Goals', 'A private digital vault for personal development, goal tracking, and self-accountability. Set SMART goals, perform daily check-ins, earn progress badges, and maintain consistency in a secure, encrypted personal space.', '949134286676e0fe0d31388e51a4d9d6', 'Alsharif Suhaim', 'MITHAQ', 'https://x.com/i/status/2019000306936274994');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://shelter-connect-0kx.caffeine.xyz/', NULL, 'Shelter Connect: Emergency Storm Shelter Directory for Portugal', 'A community-driven platform helping people locate and share safe storm shelters across Portugal. Add shelter locations, request help during emergencies, and contribute to a collaborative safety network.', '27cf937fda13be93d168f0db94a958e0', 'Sukant Ghosh', 'Shelter Connect', 'https://x.com/zuki_sf/status/2019012863407661081');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mental-health-clinic-kqf.caffeine.xyz/', NULL, 'Mental Health Clinic Denpasar: Professional Psychiatric Care in Bali', 'A premium psychiatric clinic offering comprehensive mental health services in Denpasar, Bali. Providing expert evaluation, diagnosis, treatment, and ongoing support for various mental health conditions with a personalized, confidential, and ethical approach.', 'f4db725a4e47526fe27a2292ade5407a', 'Pratiwi', 'Tiwi', 'https://x.com/ceritaseleb62/status/2019018218585890999');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://continuum-10o.caffeine.xyz', NULL, 'Continuum: AI-Powered Personal Growth & Habit Tracking', 'An innovative personal development platform that uses AI to help you build clarity and consistency. Track patterns, understand habits, and make meaningful progress through gentle, guided daily growth strategies.', '440a08280ae7e9e5dabef4e57c48ba9f', 'Icpspace', 'Continuum', 'https://x.com/Icpspace/status/2019016711425438192?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://www.prabhatchhirolya.com', NULL, 'Prabhat Chhirolya: XR Design & Immersive Visualization Portfolio', 'Professional portfolio of Prabhat Chhirolya, an innovative XR designer and immersive visualization expert specializing in BIM/VDC architecture and AI-driven creative solutions. Based in Mumbai, India, showcasing cutting-edge design and technological expertise.', '789e359196089435e8d5cd0db85673ac', 'Prabhat Chhirolya', 'Prabhat Chhirolya Creative OS', 'https://www.linkedin.com/posts/prabhatchhirolya_%F0%9D%97%A7%F0%9D%97%B5%F0%9D%97%B6%F0%9D%98%80-%F0%9D%97%BD%F0%9D%97%BC%F0%9D%97%BF%F0%9D%98%81%F0%9D%97%B3%F0%9D%97%BC%F0%9D%97%B9%F0%9D%97%B6%F0%9D%97%BC-%F0%9D%97%96%F0%9D%97%A9-%F0%9D%98%84%F0%9D%97%AE%F0%9D%98%80-activity-7424697985017745408-G4Wm?utm_source=social_share_send&utm_medium=android_app&rcm=ACoAAB6m5MAB9ef0aEv1PqqmsrwbWXLk35pp3UI&utm_campaign=share_via');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mobile-wellness-companion-d7g.caffeine.xyz/', NULL, 'AI Wellness Coach: Personalized Health & Balance Companion', 'An innovative AI-powered wellness platform that provides tailored personal health programs, progress tracking, and motivational guidance to help you achieve optimal mental and physical well-being through secure, decentralized technology.', '78d01f8ee491801502b84f4fd32f53a6', 'Maira', 'pratiwi-113', 'https://x.com/yunzks/status/2019034128424079497');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://balimedic-ffj.caffeine.xyz/', NULL, 'BaliMedicCare: Personalized Wellness & Preventive Care in Bali', 'Premium wellness center offering comprehensive health solutions in Bali. Tailored wellness programs, IV therapies, diagnostics, and personalized care for families and individuals seeking holistic health and longevity.', 'cee6d45ef77177bae593465957c053d9', 'dani lingga nugraha', 'wow', 'https://x.com/dalinggra/status/2019035691427205176');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://myrepublic-tuk.caffeine.xyz/', NULL, 'MyRepublic Network Hub: Partner Platform for Internet Services', 'Professional network marketing platform for MyRepublic internet services. Manage networks, track performance, and earn attractive commissions by promoting high-speed internet packages ranging from 100 Mbps to 1 Gbps.', '42a3287294f7294703208cf89e6ec628', 'joni', 'joni', 'https://x.com/boster070/status/2019037236302590274');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://wellness-program-app-lwo.caffeine.xyz/', NULL, 'Wellness Journey: Personalized Health & Mindfulness App', 'A comprehensive wellness app that offers personalized health programs, progress tracking, daily reminders, and secure authentication. Designed to support your journey towards improved well-being and mindfulness with intuitive features and goal-oriented support.', '7f85c9bfdd3e37affd151f8691df6c13', 'ratih', 'tesnet', 'https://x.com/MalakaYatre/status/2019039174154915914');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://caesarmediccare-v7l.caffeine.xyz/', NULL, 'Caesar Homecare: Professional Home Care Services in Jakarta', 'Trusted home healthcare provider offering compassionate nursing care for seniors, post-surgery patients, and newborns. 24/7 certified nurses, personalized services, and affordable packages in Jakarta.', '603c946d5c5839f71f44126c324cce9f', 'caesar', 'off', 'https://x.com/Bilang2024/status/2019040103499473064');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://quitter-r8y.caffeine.xyz', NULL, 'QUITTER: Addiction Recovery App for Personal Empowerment', 'A mobile web app designed to support individuals overcoming addiction. Track progress, resist cravings, and connect anonymously with a supportive community. Offers local data privacy and optional encrypted storage, helping users take control of their recovery journey.', '0a959c5e615fb76c5825b4d74d21f0d5', 'NEEL BHANUSHALI', 'QUITTER', 'https://x.com/myartbar/status/2019045192863781095');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://physiq-zpa.caffeine.xyz/', NULL, 'PhysiQ: Fitness Progress Tracking & Personal Analytics App', 'PhysiQ helps you document and visualize your fitness journey through daily progress photos, workout logging, and interactive analytics. Securely track your transformation with a private, user-friendly platform that turns your fitness goals into measurable progress.', 'e4c06001dc67bbf3036601ba7815cfa6', 'Zander', 'Physiq', 'https://x.com/i/status/2019041512752046309');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://champain-qqk.caffeine.xyz/', NULL, 'Champain: Balanced Personal Growth & Habit Tracking', 'A holistic habit-building app that helps you develop sustainable progress across life areas. Track personal growth, build resilience, and maintain balance between health, happiness, and essential life goals without burnout.', '9b6116b1f72cfb4649760bcc95388f99', 'Yuvaraja', 'Champain', 'https://x.com/youwetweet/status/2019049905776669003');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://evolve-os---personal-growth-jxa.caffeine.xyz/', NULL, 'EVOLVE OS: Personal Growth Platform for Self-Discovery & Development', 'A digital ecosystem designed to support personal transformation, offering a joyful and secure space for self-reflection, growth tracking, and intentional development. Empowers users to cultivate meaningful progress through intuitive tools and mindful practices.', 'd8cec26a954921610e5d5120aeff9916', 'Shailesh Sethi', 'Evolve OS', 'https://www.instagram.com/reel/DUVrmnLEqCU/?igsh=MWthM29qbHRvNnc2dw==');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://finplangoalinvest-8u1.caffeine.xyz', NULL, 'FinancePro: Smart Goal Planning & Investment Tracking App', 'A comprehensive financial management platform that helps you set, track, and visualize your financial goals across short, mid, and long-term horizons. Monitor investment portfolios, gain powerful insights, and take control of your financial future with intuitive analytics and reporting tools.', '44f5138e7046a214f10cc5b0723cf709', 'Ashish Kumar Gupta', 'FinancePro', 'https://x.com/i/status/2019051903502766477');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://bondly-lpl.caffeine.xyz/', NULL, 'Bondly: AI Relationship Coaching for Modern Connections', 'Bondly is an innovative AI-powered relationship coach that provides witty, supportive guidance to help users improve their interpersonal skills and connections. Designed to offer personalized insights and strategies for building stronger, more meaningful relationships.', 'bd14b02cc2619643f4426680739d5288', 'Jahanvi Jariwala', 'Bondly', 'https://www.linkedin.com/posts/jahanvi-jariwala-b226ab3aa_buildinpublic-caffeineai-relationshipgoals-activity-7424439736066494464-iJua?utm_source=share&utm_medium=member_desktop&rcm=ACoAAGQgYooBvcu0Hx1yW5pp9UxeGcvTUGh3phw');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://habit-chain-icp-295.caffeine.xyz/', NULL, 'HabitChain: Collaborative Habit Building Platform', 'HabitChain helps you develop positive habits through community support and accountability. Connect with others, track progress, and transform your lifestyle by building habits together in a motivating, interactive environment.', 'd6d1864667a6cab82a2973fa10208534', 'Tukalu', 'Habit Chain', 'https://x.com/Acut40212407/status/2019058497485164809');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mirrorme-weekly-summary-feature-with-interactive-w-uvo.caffeine.xyz/', NULL, 'MirrorMe: Daily Wellness & Self-Reflection App', 'A mindful digital companion designed to support personal growth through daily inspirations, affirmations, wellness tips, and guided self-reflection. Nurture your mental health, embrace continuous learning, and discover inner wisdom in just a few minutes each day.', 'ae2d76e1e87622bfb0be4df2f51d63c7', 'Sunit solanki', 'MirrorMe', 'https://x.com/i/status/2019048287576449478,https://www.facebook.com/100001291175592/posts/pfbid02MWnnxMW2bSzWaViPqoTXCSYxBmzZNV2zbcq7Kbb7rrsVytsBNDpH3vSNG6NiXFPql/,https://www.linkedin.com/posts/sunit-solanki_promptathon-wellnesstech-mindfulliving-activity-7424804968295133184-fIDY?utm_source=share&utm_medium=member_android&rcm=ACoAAAktyFsBICLrVOdGpmy2ubJkkO7pv03nXZA,https://www.instagram.com/p/DUVpoihDKG1/?igsh=MXJqbTcxY3QxcHh5dA==');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://brutal.services', NULL, 'BRUTAL: No-Nonsense Sobriety Tracking App', 'A raw, honest mobile app for tracking alcohol reduction and sobriety. Designed for individuals committed to real personal progress, BRUTAL offers daily check-ins, direct accountability, and a no-excuses approach to changing drinking habits.', 'b40c661b4f4b65ff18a81075448156f4', 'Ptica', 'BRUTAL', 'https://x.com/pticaptica2/status/2019172209558974518');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://forge-ai-3ha.caffeine.xyz/', NULL, 'Forge AI: Autonomous Agent Marketplace for Outcome-Driven Execution', 'Forge AI is a revolutionary platform where AI agents autonomously orchestrate complex workflows, discover specialized capabilities, and deliver outcomes. Agents coordinate, execute tasks, and settle payments trustlessly, transforming AI from a tool into an intelligent infrastructure that works without constant human intervention.', '6c40e92a93df2c5ceed3e75c158bfd87', 'MonkeyCruise', 'Forge AI', 'https://x.com/forgeai35916/status/2019070270183481545?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://stimuli-vm1.caffeine.xyz/', NULL, 'Stimuli: Brain Training App for Cognitive Skills Development', 'A cognitive training app featuring daily mental exercises focused on improving focus, memory, and reasoning skills. Offers time visualizer, number pairs, and reverse order challenges at varying difficulty levels to help users enhance their mental performance.', '26dea3e16411ae815fc957e6f03a046b', 'Disha', 'Stimuli', 'https://x.com/tnt_exploder/status/2019060499741102589?s=46&t=ds1GdwB0ntvk4RHWVdEaJw');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://joyrader.xyz', NULL, 'Joy Rader: Personal Emotional Tracking & Privacy-First Mood Garden', 'A private digital sanctuary for tracking and understanding your emotions. Designed with complete privacy, Joy Rader helps you cultivate self-awareness and notice moments of joy through a unique, personal emotional tracking experience.', 'e411688fdaea8057096e1b165b8ac949', 'Moicp', 'Joy rader', 'https://x.com/novahodler/status/2018970220560572903?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ictandem-s4t.caffeine.xyz/', NULL, 'icTandem: Social Accountability App for Personal Growth', 'A collaborative app that supports personal development through shared motivation. Helps users improve habits, nutrition, and exercise by connecting individuals who support each other''s journey, like riders on a tandem bike working together towards their goals.', 'd094b5fe16f3e70c9b20f4eb267b8f73', 'Francisco Hefner', 'icTandem', 'https://x.com/FranHefner/status/2019082422625661216');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://profitly-jck.caffeine.xyz/projects/1/dashboard', NULL, 'Profitly: Financial Tracking & Profit Management Dashboard', 'A comprehensive financial management platform for tracking income, expenses, and team profits. Add transactions, invite team members, and gain real-time insights into your project''s financial performance.', '953e930fb089382055eb8b79634c5d21', 'Salsa Dwi Nur Hidayah', 'Profitly', 'https://x.com/slzyoung/status/2019082349007298824?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://cryptoswish---cryptocurrency-wallet-and-contact-ma-645.caffeine.xyz/', NULL, 'CryptoSwish: Secure Crypto Wallet and Contact Management Platform', 'A comprehensive cryptocurrency management solution offering secure wallet tracking and contact management. Designed for crypto enthusiasts to streamline their digital asset interactions with robust authentication and user-friendly interface.', 'af63c224bd82bec268fb07745683b665', 'Gunnar', 'CryptoSwish', 'https://x.com/i/status/2018655676697129030');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://milano-cortina-2026-retro-games-hub-wg3.caffeine.xyz', NULL, 'Milano Cortina 2026 Retro Olympic Arcade Experience', 'A nostalgic digital arcade celebrating the Milano Cortina 2026 Winter Olympics through classic retro gaming. Sign in with Internet Identity to explore vintage Olympic-themed arcade games and interactive experiences.', 'e80bf4588886f2a7acd95afa70887660', 'Carlo', 'Olympic Arcare Games - Milano Cortina 2k26', 'https://x.com/denardincarlo?s=11&t=7wGxqO7tiOxTLjIKz82K4g');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('http://beforeyousleep-hbz.caffeine.xyz', NULL, 'Before You Sleep: Daily Reflection & Mindfulness App', 'A peaceful nightly ritual that helps you pause and appreciate life''s small, meaningful moments. Write down 3 good things from your day, build a mindful habit, and learn to enjoy the present instead of always waiting for something extraordinary.', '59451d3ad5a3306d9779ec7ae15aade3', 'Haruto', 'Before You Sleep', 'https://x.com/HarutoWang');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://wa-53i.caffeine.xyz', NULL, 'WA Sender Pro: Bulk WhatsApp Messaging Made Simple', 'Effortlessly send mass WhatsApp messages with advanced contact management, personalized templates, and real-time delivery tracking. Streamline your communication with easy contact input and dynamic message personalization.', '2419ca23dd3ba9b63a812d1898885f1f', 'bos', 'bos', 'https://x.com/academy_hooked/status/2019091587842851285');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lingoflow-4rj.caffeine.xyz/', NULL, 'LingoFlow: Smart Language Learning with Pomodoro & Active Recall', 'An innovative language learning platform that combines focused Pomodoro study sessions, active recall techniques, and secure progress tracking. Sync your vocabulary and stats across devices using blockchain technology for an intelligent, personalized learning experience.', 'a446311efc34f9af4f6146686fbbbc24', 'Maria', 'LingoFlow', 'https://x.com/KanaNarai/status/2019093139445735649?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://numinamath-xzz.caffeine.xyz/', NULL, 'Numina: Personalized Math Learning Platform for Skills Mastery', 'Numina is an interactive math practice dashboard that helps learners track progress, follow personalized learning paths, and systematically master mathematical skills through targeted exercises and adaptive learning.', '58477842949e735e6ddb9d771a620a68', 'Michael Ansell', 'Numina', 'https://x.com/EdUniteDappsICP');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://soph-hbf.caffeine.xyz', NULL, 'Three Little Pigs: Interactive Educational Mini Game', 'An engaging digital learning experience that teaches children problem-solving and storytelling through the classic Three Little Pigs tale. Explore interactive gameplay that makes learning fun and memorable.', '3f4b89dcd7547de6a2bc269c4a0c07fd', 'Paulo Moreno', 'Soph', 'https://x.com/psmoreno/status/2019101867440238722?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://sweetsteps-hah.caffeine.xyz/', NULL, 'SweetSteps: Gentle Goal Tracking & Motivation Platform', 'A supportive goal-tracking app that helps you make consistent progress without overwhelming pressure. Climb your goals one sweet step at a time, using a compassionate approach to personal development and motivation.', 'd859b24561d4e49d2940ce8fa1226831', 'Shreya V', 'SweetSteps', 'https://x.com/MMeow28001/status/2019107235650252926?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://streaks-without-shame-12k.caffeine.xyz/', NULL, 'Streaks Without Shame: Compassionate Personal Growth Companion', 'A supportive app that helps you track personal goals without judgment. Focus on gentle progress, compassionate reflection, and honoring your journey. Designed to make self-improvement feel nurturing and kind.', '721ceda3b800bbbbb7a35af7ff693f0f', 'Roberto pagan', 'Rpjr08', 'https://x.com/robertopjr1?s=21');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://duat-e9v.caffeine.xyz/', NULL, 'DUAT: AI Privacy Guidance for Digital Identity Challenges', 'An AI assistant designed to help users understand digital identity issues by providing clear, transient explanations about online incidents without storing data or directly intervening in accounts. Privacy-focused guidance to increase user understanding.', '6acd4f3a55428ef1d2064d1d0234ea52', 'zurab', 'DUAT', 'https://x.com/igalixai/status/2019110046056214786?s=46&t=pkBtk-yExZqO_2MsAcDaIQ');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://zyptext-n69.caffeine.xyz/', NULL, 'ZypText: Speed Reading App for Global Scripts', 'A web-based speed reading application that helps users improve reading speed and comprehension. Supports multiple global scripts including Chinese, Arabic, and Cyrillic, with customizable settings for font, speed, and focus guides.', '308e6dcfdf91cae2da2110e4671f746e', 'Michael Guta', 'ZypText', 'https://x.com/mmguta/status/2019117022626418773');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://bottlepost.blog/', NULL, 'Bottle Post: Creative Writing and Digital Storytelling Blog', 'A unique blog exploring creative writing, storytelling, and digital narratives through the metaphorical lens of messages in bottles. Discover innovative writing perspectives and personal reflections on communication and creativity.', 'aaeb044d11da90344f67f2e2a09338f4', 'Selman Kelmendi', 'Bottle Post', 'https://x.com/future_rain_/status/2019117781552361820?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://17-minutes-6a5.caffeine.xyz/', NULL, '17 Minutes: Mindfulness for Busy Parents', 'A mobile app designed specifically for parents, offering quick and accessible mindfulness practices that fit into those precious few spare minutes of the day. Tailored to help parents find calm and mental clarity amidst their hectic schedules.', '7f007c30d174aad93585768be4889218', 'Hafsa Usmani', '17 Minutes', 'https://x.com/HafsaUsmani2/status/2018910240604090443');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://archetype-edition-fr3.caffeine.xyz/', NULL, 'Studio Chladni: Interactive MIDI Generator & Sound Visualization', 'Advanced musical tool featuring real-time Chladni visualization, probabilistic MIDI generation, and synesthetic color-tone mapping. Explore interactive sound harmonics, wave forms, and experimental audio synthesis with intuitive controls.', '71d50c821d7fdadcfa93060936c996a8', 'teixeira', 'Studio Chladni – Archétype Cristal Polishead Edition V80 FINAL', 'https://x.com/gualterianoo/status/2019120432209883400?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://x39-dark---noticias-de-la-dark-web-y-crypto-k35.caffeine.xyz/', NULL, 'X39 Matrix: Decentralized Web Protocol on Internet Computer', 'A cutting-edge blockchain protocol that seamlessly connects tokens, data, and communities through a decentralized framework. Built on Internet Computer, X39 Matrix eliminates intermediaries and provides scalable, mathematically-verified interactions with enhanced trust and transparency.', '3d11592a48f16c4198ec794281b2f712', 'x39', 'X39 Matrix', 'https://www.instagram/Olivares_sfc.com');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://why-again-reflect-iiq.caffeine.xyz/', NULL, 'Why, Again? - Reflective Pattern Analysis Tool', 'A thoughtful web application designed to help users pause, critically examine personal patterns and habits, and make intentional decisions. Encourages self-reflection through guided questioning and mindful analysis of behavioral routines.', '76a24908bc4795f38309282dd54d6c9d', 'Ahmad Ekhlas', 'Why, Again?', 'https://www.linkedin.com/feed/update/urn:li:activity:7423535412201308160/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://managingfriends-l6r.caffeine.xyz/', NULL, 'Friends: Easy Event Planning & Group Decision Making', 'A collaborative platform for organizing events, tournaments, and activities with friends. Create polls, vote democratically on locations, manage a shared calendar, and make group decisions simple and transparent.', 'e75c6a0a740e20bda16e2a8f6b3b8c31', 'Alessandro', 'ManagingFriends', 'https://x.com/AleCastaz/status/2019129652128997733?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://innerbloom-6ty.caffeine.xyz/', NULL, 'Inner Bloom: Personalized Meditation App for Mindfulness & Calm', 'A holistic meditation app that offers tailored meditation sessions, peaceful sounds, and progress tracking. Designed to help users build a gentle mindfulness habit, reduce stress, and find inner clarity through personalized, intuitive experiences.', 'ef18df6de4bb635e43c594d3353e1ed3', 'Dovydas Šalkevičius', 'Inner Bloom', 'https://x.com/daveDash2000/status/2019130849707385225');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://growthtree-z2y.caffeine.xyz/', NULL, 'GrowthTree: Personal Development Tracker & Habit Management App', 'A comprehensive personal growth platform that helps users track progress across life areas like health, mind, career, and creativity. Visualize skill development, log habits, set challenges, and build consistent routines through an engaging, gamified interface.', 'd31a2407c8a613150254ebb6c8cd42bf', 'Sidharth Sethi', 'GrowthTree', 'https://x.com/TechSpiritSS/status/2019133048319779222?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://inky-frame-emulator-nt0.caffeine.xyz/', NULL, 'Inky Frame Emulator: Browser-Based E-Ink Display Testing Tool', 'A web-based emulator for Pimoroni Inky Frame that provides accurate 800×480 e-ink display simulation, interactive code sandbox, virtual SD card, and time control features for developers to test and visualize e-ink display projects without physical hardware.', 'af5143e86049d7f1b350b39f740f9b62', 'Goth2Boss', 'Inky frame emulator', 'https://x.com/_Goth2Boss/status/2019080859228233913?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://the-pivot-rif.caffeine.xyz/', NULL, 'The Pivot: Adaptive Self-Improvement Without Judgment', 'A compassionate personal growth app that dynamically adjusts goals to your daily energy levels. Focuses on sustainable progress through intelligent difficulty scaling, friction mapping, and identity-driven insights—helping you grow at your own pace without guilt or rigid streaks.', '9705d13bd554b163600042a20dec881d', 'John Oloruntobi', 'The Pivot', 'https://vt.tiktok.com/ZSaWS2RxY/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://myappstudysearch-cgq.caffeine.xyz', NULL, 'AI Study Search: Smart Learning Resource Discovery Platform', 'An intelligent study resource search application that helps students find high-quality learning materials across various subjects. Powered by AI, users can search, upload, and collaborate on study resources while earning points and accessing premium content.', 'e6d123f67db9a7d3cd83345e961050d1', 'Miles Morales', 'StudySearch', 'https://x.com/i/status/1972777263906324943');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://inventory-saas-application-9xy.caffeine.xyz/', NULL, 'Inventory Management SaaS - Multi-Location Stock Tracking', 'A comprehensive SaaS solution for seamless inventory management across multiple locations. Track stock levels, enable team collaboration, maintain detailed audit trails, and leverage secure Internet Identity authentication for streamlined business operations.', 'b94dd755465f51fdb56fd020f3484e05', 'Kunal Prashant', 'Inventory SaaS', 'https://x.com/kunalprashant2/status/2019144078991192503?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://rephrase-o7t.caffeine.xyz/', NULL, 'RePhrase: Transform Complaints into Positive Affirmations', 'A mindfulness app that helps users convert negative thoughts into empowering statements. Track personal growth, receive weekly motivation, and develop a more positive mental approach through guided reframing techniques.', 'b5375e33259de7d9739c614af1827667', 'Paul Lamping', 'RePhrase', 'https://x.com/PabloLim7/status/2019147509655048585?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://superpod.app/', NULL, 'Superpod: AI Relationship Intelligence App for Deeper Connections', 'Capture and remember important moments with people who matter. Superpod uses AI to help you organize relationships, get personalized insights, track important dates, and build meaningful connections—all while keeping your data completely private and secure.', 'e80b4f901b9d71b3d431827f9587a9d9', 'Marco Näf', 'Superpod', 'https://x.com/naefdigital/status/2019150113420243144?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://prep-v3j.caffeine.xyz/', NULL, 'PREP: AI-Powered Smart Study Assistant for Personalized Quizzes', 'Transform your study materials into effective practice quizzes with PREP. Upload lecture notes, PDFs, and documents to generate personalized quizzes, get instant feedback, and track your exam readiness in just three simple steps.', 'e36b9bdd28455606011159571f1705e9', 'Deborah Emily', 'PREP', 'https://vt.tiktok.com/ZSaWyLQnP/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://moments-fyn.caffeine.xyz/', NULL, 'Moments: Capture and Share Life''s Meaningful Snapshots', 'A digital platform designed to help users create and preserve genuine memories, moving beyond mindless social media scrolling. Moments encourages intentional, meaningful photo sharing and connection.', '7edbf181ccbdb9770f3e4c00e8d1c84f', 'Janice Gathoga', 'MOMENTS', 'https://x.com/JaniceGathoga/status/2019161283875205182?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://carfix-huj.caffeine.xyz/', NULL, 'AutoMaintain: DIY Vehicle Maintenance Made Easy', 'A comprehensive app that helps car owners perform their own maintenance by providing vehicle-specific parts information and detailed step-by-step repair guides. Simply enter your car''s details to access personalized maintenance instructions and compatible part recommendations.', 'f1bf0786abaef50193619cffd1d524b9', 'Sean johnson', 'Car fix', 'https://x.com/SquiddVicious/status/2019168695885656164?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://aura-llu.caffeine.xyz', NULL, 'Aura: Personal Knowledge Curation Platform', 'A secure web application that serves as a personal gateway for discovering, organizing, and accessing curated knowledge and daily insights. Powered by Internet Identity for seamless and secure authentication on the Internet Computer.', '38b731a5462fac3db9241054b91c348f', 'Peter Kazadi', 'aura', 'https://https:www.facebook.com/reel/3167146663458162/?app=fbl');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://thrive-pp7.caffeine.xyz', NULL, 'Thrive: Personal Growth & Habit Tracking Platform', 'A secure, private platform designed to support personal development through daily habit tracking, mindful check-ins, and guided progress sessions. Empower your growth journey with personalized tools that help you build lasting positive change.', '60077defa926353533e031f9c1bd7d03', 'Subin S K', 'Thrive', 'https://x.com/subinspacesk/status/2019172108191035470?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://thrive-ai---your-personal-growth-companion-9dh.caffeine.xyz/', NULL, 'Thrive AI: Your Personal Growth and Development Companion', 'Thrive AI is an innovative personal growth platform that offers secure, personalized development tools powered by Internet Computer authentication. Designed to help users enhance their potential through intelligent, adaptive guidance and support.', '98f28aa08effcce90ab45b4bbeec1943', 'Speed', 'Thrive AI', 'https://x.com/Big_Timmm/status/2019173204976152697?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://peepal-1ot.caffeine.xyz/', NULL, 'PeePal: Your Personal Hydration Tracking Companion', 'PeePal is a quick and easy hydration tracking app that helps you understand your body''s hydration needs. Simply check your urine color and get personalized water intake recommendations in under a minute, with optional history tracking.', '9e9bdff6bcf07cc99a48ba492ef8d6e7', 'Bartolomej Vesely', 'PeePal', 'https://x.com/bartholomewve/status/2019177159865111020?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://zeal-4s6.caffeine.xyz/', NULL, 'Zeal: Energy Balance and Burnout Prevention App', 'Zeal helps you track and manage your personal energy levels by identifying life''s energy drainers and chargers. Monitor your emotional and mental well-being to prevent burnout and find a sustainable balance in work and life.', '57f72b6ab432bb93afbe4712eb998c32', 'KevLoui', 'Zeal', 'https://x.com/majesticloui/status/2019178069286932622?s=46&t=1vKY6R0FYADl7T6o9UFBEQ');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://brave-echo-qog.caffeine.xyz/', NULL, 'Brave Echo: Personal Growth & Daily Challenge Tracking App', 'Brave Echo helps you build courage and personal resilience through daily challenges. Track progress, connect with friends, and customize tasks to progressively develop strength and confidence in small, meaningful steps.', 'd305d4e4b608c8a9736c87fea768cd2e', 'Anna', 'Brave Echo', 'https://x.com/patrikvonsponge/status/2019180488284324172?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://painto-41c.caffeine.xyz', NULL, 'Painto: Community-Driven Digital Art Platform for Mobile & Tablet', 'A creative drawing app that enables users to create, share, and explore digital artwork. Sign in to access a collaborative canvas, showcase your paintings, and engage with a vibrant artistic community through ratings and shared inspiration.', '6d5a856d8c44e6611fcf0d13f84593fe', 'Ra.icp', 'Painto', 'https://x.com/i/status/2019181891484541330');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://brainsnacks-9nf.caffeine.xyz/', NULL, 'BrainSnacks: 5-Minute Micro-Learning for Busy Minds', 'Transform your daily routine with bite-sized learning sessions. Master skills in psychology, productivity, and leadership in just 5 minutes a day. Earn XP, track progress, and compete globally while building knowledge effortlessly.', '990e9463e4df3cc49e3518820472876c', 'Cryptomax', 'BrainSnacks', 'https://x.com/crypt0max_/status/2019182451713560907?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://closecircle-9fc.caffeine.xyz/', NULL, 'CloseCircle: Private Emotional Sharing for Your Closest Connections', 'An invite-only social app that lets you share personal emotional statuses exclusively with your most trusted circle. Control what you share, when you share, and maintain privacy in a secure, intimate digital space.', '5a3a0b92809c0188873edc833e5ac61c', 'Abel Odoh', 'CloseCircle', 'https://x.com/AbelOdoh_IC/status/2019182748330598637');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://dailyos-ojs.caffeine.xyz/', NULL, 'DailyOS: Personal Productivity & Life Tracking Dashboard', 'A comprehensive personal tracking app that helps users manage daily activities, finances, habits, and journal entries through an intuitive calendar-based interface. Organize your life with a clean, customizable dashboard for holistic personal management.', '80039448c35a029ac544b818fcfa063f', 'Amioo', 'DailyOS', 'https://x.com/amioo1x/status/2019189174579155008');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://empathy-buddy-m74.caffeine.xyz', NULL, 'Empathy Buddy: Anonymous Voice Connection & Compassionate Listening Platform', 'A digital sanctuary for anonymous voice expression where individuals can share their thoughts and feelings through recordings, and connect with empathetic listeners. A supportive space that validates personal experiences and promotes emotional understanding across diverse voices.', '5ec1f5740e1b8151e253cbd4c7ce253d', 'Marton Kabai', 'Empathy Buddy', 'https://x.com/martonkabai');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://achievable-r00.caffeine.xyz/', NULL, 'Achievable: Flexible Progress Tracking for Goal-Oriented Individuals', 'A customizable task management platform that allows users to create tasks with flexible progress tracking, subtasks, and the ability to exceed 100% completion. Designed for motivated individuals who want a personalized way to monitor and achieve their goals.', '14dc30713e4cd5446d34f133cf30d76f', 'Peter', 'Achievable', 'https://x.com/waxyactivity/status/2019194931630559604');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://healthy-wealthy-n-wise-zja.caffeine.xyz/', NULL, 'Nutrition Planner: Personalized Meal Tracking & Planning App', 'A comprehensive meal planning tool that helps you track daily nutrition, create custom meal plans, and manage your weekly diet with an easy-to-use interface. Supports healthy eating habits through personalized food tracking and planning.', 'cf52581fdef51c29582a9754d8a71eda', 'Michael', 'Healthy Wealthy & Wise', 'https://x.com/i/status/2019194983010693467');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://jlpt-5nf.caffeine.xyz/', NULL, 'JLPT Study Pods: Collaborative Japanese Language Learning', 'Join a supportive community of Japanese learners with JLPT Study Pods. Set daily goals, track progress, and stay accountable in small groups. Build language skills consistently with collaborative learning and achievement tracking.', '602c5d1a13d715f8d4c910b284c4742c', 'Jonathan Salatan', 'JLPT PODS', 'https://x.com/eastmaels/status/2019203962063495293?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://goal-rpg-63v.caffeine.xyz/', NULL, 'Goal RPG: Gamify Your Personal Development Journey', 'An innovative RPG-style app that transforms personal goals and habits into an engaging game experience. Level up your life by completing tasks, earning experience, and tracking progress through an immersive, motivational gameplay interface.', '9e0009d347c22b9df4bef08f851947ab', 'Josh', 'Goal RPG', 'https://x.com/bbb_capital/status/2019204382374690835?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lo-fi-y7v.caffeine.xyz/', NULL, 'LI.FM: Lo-Fi Music for Focus & Productivity', 'A minimalist online radio station streaming lo-fi beats designed to help you concentrate, reduce distractions, and support your workflow. Create a calm, productive environment with soothing background music tailored for studying, working, or relaxing.', 'e184bfefd807ffa78433ba0ec43cd12b', 'Mazen Zibara', 'lo-fi.fm', 'https://x.com/mazen_zibara/status/2019205725302730966?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://progress-pulse-lqw.caffeine.xyz/', NULL, 'Progress Pulse: Your Personal Weight Loss Tracking & Motivation Platform', 'Progress Pulse helps you transform your fitness journey with comprehensive weight tracking, progress photo uploads, smart analytics, and a supportive community. Log measurements, visualize progress, and stay motivated with personalized daily insights and premium member support.', '104366258ec005e92a1fb9eb31f5c66f', 'Richard Hery', 'Progress Pulse', 'https://x.com/richard_hery/status/2019205422843109691?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://zenbreathe-c21.caffeine.xyz/', NULL, 'ZenBreathe: Guided Breathing Techniques for Relaxation and Wellness', 'A comprehensive breathing exercise app featuring techniques like Equal Breathing, Box Breathing, and 4-7-8 Deep Sleep method. Designed to help users reduce stress, improve focus, and enhance overall mental well-being through scientifically-backed breathing practices.', '5721d6309fe3d156e882a45dab4fb8b7', 'Saumo Pal', 'ZenBreathe', 'https://x.com/PalSaumo/status/2019212259483086977');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://levelly-mxr.caffeine.xyz/', NULL, 'Levelly: Next-Generation Digital Platform', 'Levelly appears to be an emerging digital platform with a login-based access system. The site suggests a modern web application focused on user authentication and potentially offering personalized digital experiences.', 'a22cbcb3dd1c43b28f77b5d834e9ce2c', 'Ayodeji Odeyale', 'Levelly', 'https://x.com/i/status/2019214316172640434');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://routinelyapp-yny.caffeine.xyz/', NULL, 'Routinely: Craft Your Perfect Daily Routine with Confidence', 'Routinely helps you build and track your ideal daily routine with ease. Add tasks from a curated library, monitor your progress, and develop positive habits at your own pace. Designed to support your personal growth with patience and encouragement.', '888cde766d8cabc19c0aba1bff46b5ac', 'Domika Feliz', 'Routinely App', 'https://x.com/metagalaxy28289/status/2019224196308693329?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://firelight-07x.caffeine.xyz', NULL, 'Firelight Journal: Private, Encrypted Digital Journaling', 'A secure journaling app that protects your personal thoughts through end-to-end encryption, anonymous login, and Zettelkasten-style idea linking. Powered by Internet Computer for ultimate privacy and seamless thought organization.', '58f0d8ae57e0aca9e9a425252e0cecf0', 'Harshil Dave', 'Firelight Journal', 'https://x.com/hersheylasers/status/2019225967172968573');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://hire-lukas-2va.caffeine.xyz/', NULL, 'Lukas Zieba: Freelance Designer for Professional Branding & Visual Identity', 'Experienced graphic designer with 20+ years of expertise, helping businesses create professional and consistent visual identities. Specializing in logotype design, graphic design, and production-ready visuals for brands like Coca-Cola, Klarna, and Telenor.', '86960aed591d75a0104337467594505f', 'Lukas Zieba', 'Hire Lukas', 'https://www.linkedin.com/posts/lukaszieba_hire-lukas-activity-7424992293700620288-404P?utm_source=share&utm_medium=member_desktop&rcm=ACoAAAIiZYsBymthyc374puY63vsTSWoUj89OLY');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://90-minute-launch-4tn.caffeine.xyz', NULL, '90-Minute Launch: Transform Your Mornings with Focused Deep Work', 'A structured morning routine designed to help you build lasting habits through a strategic 90-minute session. Includes setup, deep work, and closing phases to maximize productivity and personal growth.', '3fe49697dd16d1990620133d450d3e2e', 'Vibelawyer', '90-minute-launch', 'https://x.com/vibelawyer/status/2019231167904641278?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://pebble-dpv.caffeine.xyz/', NULL, 'Pebble: Intentional Productivity Modes for Focus & Creativity', 'A digital workspace that helps you optimize your mental state through six purposeful modes: Reading, Coding, Creativity, Deep Work, Organization, and Reflection. Choose your intention and enter a distraction-free environment designed to enhance your workflow and personal growth.', 'b5f5d3a7ebf54eca86f5a1d19d70c7e1', 'Agus Pamungkas', 'Pebble Focus App', 'https://x.com/Agus_pamungkasS/status/2019227344305811467');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://barnabus-the-undeterred-fcm.caffeine.xyz/', NULL, 'Barnabus the Undeterred: A Heroic Journey of Resilience', 'Explore the inspiring story of Barnabus, a character defined by unwavering determination and courage. This showcase reveals a hero who faces challenges head-on, embodying the spirit of perseverance and hope in the face of adversity.', '9d3156d81e586a063507c49959654079', 'Pauls', 'Barnabus, The Undeterred', 'https://www.linkedin.com/feed/update/urn:li:activity:7424981386580824064');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://unstick-hio.caffeine.xyz/', NULL, 'Unstick: Mindful Thought Management & Mental Clarity App', 'A private digital space designed to help you offload mental obligations, capture fleeting thoughts, and gain clarity. Unstick allows you to track and monitor your mental energy without overwhelming your mind, offering a secure and mindful approach to personal thought management.', '1b21beeac73e5fd9d83ea40085e820f3', 'Darlyze Calixte', 'Unstick', 'https://x.com/darveloper/status/2019239124738388054?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://7-day-screen-time-challenge-a8u.caffeine.xyz/', NULL, '7-Day Screen Time Challenge: Social Contemplation Webapp', 'An interactive web application designed to help users track and reflect on their screen time habits over a 7-day period. Features include theme toggling, international login, challenge tracking, invite codes, and an audio tab for enhanced user engagement.', 'edec2d3fdc7776695810ae088ca573e9', 'Wouter van Dam', '7-Day Screen Challenge App', 'https://www.linkedin.com/posts/activity-7425006543986700289-GraX?utm_source=share&utm_medium=member_desktop&rcm=ACoAAAHdmR4BBkf2_kOEGvUXhXL-ep3nf3WfTyU');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://kaidatadiet-44g.caffeine.xyz/', NULL, 'KaiDataDiet+: Anonymous Digital Privacy Analysis Tool', 'A privacy-focused web tool that helps users analyze their digital data exposure anonymously. Sign in without registration, get transparent insights about your data, and maintain complete privacy with pseudonymized, session-based analysis.', '00c78a12e7ebc0020b1b1a22a279f344', 'PixelPaxi', 'KaiDataDiet+', 'https://www.instagram.com/p/DUXCR05ija0/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://make-it-make-sense-tgr.caffeine.xyz/', NULL, 'Make It Make Sense: Transforming Complex Ideas into Clear Insights', 'An innovative platform designed to help users break down complex concepts, enhance understanding, and generate clarity through advanced cognitive processing. Simplify information, improve comprehension, and make sense of challenging ideas with intuitive tools.', '72c95c59019e497f73ca28605892afc4', 'Amer R Amer', 'Make It Make Sense', 'https://www.tiktok.com/@shakaleita/video/7603171018068315414');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://growth-1x5.caffeine.xyz/', NULL, 'Growth: Personal Development Social Network for Habit Tracking', 'A social platform that helps you create custom processes, track personal progress, connect with supportive peers, and stay motivated through gentle nudges and comprehensive stats. Build better habits and learn continuously with personalized tracking and community encouragement.', '285ff6b73565f71119b33de6db3d2f5d', 'Ricardo Leon', 'Growth', 'https://twitter.com/ponshupuesto/status/2019244925628609017');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://one-small-win---privacy-focused-daily-reflection-a-hdw.caffeine.xyz/', NULL, 'One Small Win: Private Daily Reflection & Personal Growth Journal', 'A privacy-focused mobile app designed to help you track and celebrate your daily achievements. Capture personal wins, reflect mindfully, and build positive momentum through a secure, minimalist journaling experience.', '61d3fc88e15ce5680985216e98b4e837', 'Krishna Kumar Mahato', 'One Small Win', 'https://www.instagram.com/reel/DUXE9jJjdvs/?utm_source=ig_web_copy_link&igsh=MzRlODBiNWFlZA==');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://iching-vhr.caffeine.xyz', NULL, 'I Ching Oracle: Ancient Chinese Divination Tool', 'An interactive digital platform for exploring the ancient Chinese divination text, the I Ching (Book of Changes). Draw hexagrams and receive traditional wisdom and philosophical guidance through a modern, user-friendly interface.', 'd8b812e483d2759a6509c19368f34f7f', 'Nick Altemeyer', 'Yì Jīng — The Book of Changes', 'https://x.com/opentosuggest/status/2019247698788958366?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://growinlove-yaq.caffeine.xyz/', NULL, 'GrowInLove: Deepen Your Relationship with Daily Connection', 'A private, thoughtful app designed by a couple to help partners strengthen their bond through daily rituals, love language insights, and meaningful shared moments. Track your relationship growth with personalized prompts and a shared digital scrapbook.', '72e96d0c476d0ad21fafff0e5d93c2c5', 'Dan & Sarah', 'GrowInLove', 'https://x.com/LazyDogeLovers/status/2019250785964675198');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://open-shelf-te4.caffeine.xyz', NULL, 'Open Shelf: A Mindful Personal Reading Companion', 'Open Shelf is a serene digital library platform that helps you curate, track, and reflect on your reading journey. Save meaningful books, monitor your progress at your own pace, and discover new reads without overwhelming pressure.', '180542d263837be5fb58c122e2074636', 'Bruise', 'Open Shelf', 'https://x.com/i/status/2019254102333747259');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://suchefs.com', NULL, 'SuChefs: Smart Meal Planning & Recipe Tracking App', 'A comprehensive food companion app that helps you discover recipes, track meals, plan groceries, and connect with a community of food enthusiasts. Set goals, manage your budget, and simplify your culinary journey.', '688c01a49e413681c4351076895bba3f', 'Alex Racine', 'SuChefs', 'https://x.com/i/status/2019250268278321443');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://jiu-jitsu-journey-lud.caffeine.xyz/', NULL, 'Jiu-Jitsu Journey: Your Personal BJJ Training Companion', 'A comprehensive web app for Brazilian Jiu-Jitsu practitioners to track training sessions, monitor belt progression, and build a personal technique library. Securely authenticated using Internet Identity blockchain technology.', '699b528bba75509e0fd91dc533a8689a', 'Darren Fitzpatrick', 'Jiu-Jitsu Journey', 'https://x.com/_DarrenFitz_/status/2019255797432340523');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://axeboredom.com', NULL, 'Axe Boredom: Daily Challenges & Community Motivation', 'Break free from monotony with Axe Boredom''s interactive platform. Engage in daily challenges across 6 categories, submit proof of your achievements, build streaks, and climb the leaderboard while connecting with a vibrant community of motivated individuals.', '1af1556a441bef817c91ea0c50737688', 'Daniel Hays', 'Axe Boredom', 'https://x.com/axeboredom/status/2019259996798857495?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://recharge-ai-9eo.caffeine.xyz/', NULL, 'Recharge.ai: Optimize Your Daily Energy and Productivity', 'A personal energy tracking platform that helps you monitor your daily battery levels, manage tasks strategically, and build consistent productivity by understanding your unique energy patterns and optimizing work accordingly.', 'c3c2f547f8772f5bef0dca362f785df9', 'Thea Anil', 'Recharge.ai', 'https://x.com/thea_anil/status/2019266156587012316?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://recode-identity-ev7.caffeine.xyz', NULL, 'RECODE Identity Engine: Personal Transformation & Protection Protocol', 'An innovative personal development platform focused on identity evolution. Explore how you can protect and transform your authentic self, moving beyond old safety patterns to unlock personal growth and potential.', '4b54abd149f50d94d8c090f03d641a3e', 'Tasfia Chowdhury', 'RECODE', 'https://x.com/tasfuu19/status/2019266186698166672?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://serenity-gs6.caffeine.xyz/', NULL, 'Serenity: AI-Powered Mental Wellness App for Personal Growth', 'A comprehensive mental wellness platform leveraging AI to support users through breathwork, positive affirmations, cognitive reframing, compassion practices, and habit development. Designed to guide individuals on a personalized journey of mental health and self-improvement.', 'eaa542e34ace68357db469606156d549', 'Varun Jain', 'Serenity', 'https://x.com/varunx_coach/status/2019081452411830661');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://my-goals-2026-k29.caffeine.xyz/', NULL, 'My Goals 2026: Personal and Professional Growth Roadmap', 'A comprehensive goal-tracking platform covering career, personal development, job, family, finance, health, and academic aspirations. Organize, track, and achieve life goals across multiple domains with clear monthly planning and progress tracking.', 'e4f6aaf9afde92aff1aabcdc3fc263b2', 'Flor Campos Flores', 'Dynamic Goal tracker', 'https://x.com/campos_flores/status/2019246447112438045');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://humanmarket-j8u.caffeine.xyz/', NULL, 'HumanMarket: Decentralized Skill Exchange for the Automation Era', 'A blockchain-powered platform enabling direct peer-to-peer skill trading without money or middlemen. Connect with professionals, exchange services, and build economic resilience as automation transforms traditional work models.', '2957cf60eab62598ce167465141d434c', 'Jose Pedro', 'HumanMarket', 'https://x.com/imnotabot2026/status/2019270795995091196?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://focus-flow-cuv.caffeine.xyz', NULL, 'Focus Flow: Productivity Timer & Session Tracking', 'A web-based Pomodoro timer that helps you stay focused with structured 25-minute work sessions and 5-minute breaks. Track your productivity, analyze work patterns, and optimize your daily workflow with intuitive session management and energy tracking.', '7b8acbeb5314119e04bd02f857833682', 'I Gede Surya Diva Ananda', 'Focus Flow', 'https://www.linkedin.com/posts/divaananda_caffeineai-promptathon-icp-activity-7425036337696722944-1MNB');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://dearme-yen.caffeine.xyz/', NULL, 'DearMe: AI-Powered Self-Improvement Journal & Action Planner', 'A minimalist digital journaling platform that helps you reflect on your thoughts and transform insights into actionable steps. Combine personal writing with AI-driven planning to track progress, set goals, and enhance personal growth.', 'f1e6764a83e76bc7b29102e9996098ac', 'Vikas Sabbi', 'DearMe', 'https://x.com/vikassabbi/status/2019270180849979617?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://assetsbag-s73.caffeine.xyz/', NULL, 'AssetsBag: Multi-Asset Portfolio Tracking with Blockchain Security', 'A comprehensive investment tracking platform that monitors portfolios across cryptocurrencies, stocks, commodities, and fiat currencies. Powered by Internet Computer Protocol, AssetsBag offers secure, privacy-first portfolio management with institutional-grade data and decentralized authentication.', '495d3948292da3beaa2c59411e6f5ddf', 'Vitalii', 'AssetsBag.com', 'https://x.com/assetsbagx/status/2019271407020941796?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://perfectday-w7q.caffeine.xyz/', NULL, 'PerfectDay: Precision Time Blocking & Schedule Optimization', 'PerfectDay helps you craft an ideal daily schedule with minute-precise time blocking. Securely authenticate and take control of your time management, creating a personalized productivity blueprint that maximizes your day''s potential.', 'bb90a4fbf66fefc249a667f3687a8837', 'Michael Aronsohn', 'PerfectDay', 'https://x.com/IWannaLambo/status/2019272018580107585');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://between-strangers-hza.caffeine.xyz/', NULL, 'Between Strangers: Anonymous Digital Message Exchange', 'A unique online platform where users can anonymously cast messages into a virtual ocean, sharing thoughts, secrets, and reflections with unknown strangers. Connect through cryptic, ephemeral communication without revealing personal identity.', '761eb88fd00fc1d8ef328c6406bf7b4b', 'Juan Z', 'Between strangers', 'https://x.com/zforever97/status/2019271585824211375?s=46');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://motive-verse-focus-app-l57.caffeine.xyz/', NULL, 'Motive Verse: Productivity Focus App with Timer & Goal Tracking', 'A minimalist focus app designed to help users set and achieve productivity goals through customizable timers, theme options, and a clean interface. Supports focused work sessions with countdown timer, goal setting, and simple task management.', '19cfbebf9236e63d9d2d1ced2d24c8dc', 'Asra Shaheen', 'Motive Verse', 'https://x.com/LeoMark90/status/2019272462450495600?s=20');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://softspace-hg6.caffeine.xyz/', NULL, 'Softspace: A Neurodivergent-Friendly Life Management Dashboard', 'A gentle, customizable digital space designed for neurodivergent individuals to manage their life with comfort and accessibility. Choose from soothing themes like Peach Calm, Ocean, Forest, Night, and High Contrast to create a personalized, supportive dashboard experience.', '15bc5b328d5255b3c549be436ca10ba6', 'Jess', 'Softspace', 'https://x.com/scattercloud_/status/2019272479739638266?s=46&t=VVjUS6hq5zHkxZnIyqfSQA');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-consequences-mqf.caffeine.xyz/', NULL, 'Future Consequences: Personal Progress Tracking & Life Projection App', 'A powerful personal development tool that helps you track daily progress, define success metrics, and visualize potential future outcomes based on your current behavior patterns. Set goals, submit check-ins, and gain insights into how your actions today shape your tomorrow.', '86d255ecd7862b279c73d28166fb47b4', 'Simiyon Vinscent Samuel L', 'Future consequence', 'https://www.linkedin.com/posts/simiyonvinscentsamuel_most-people-in-tech-obsess-over-what-they-activity-7425037816583114753-GvaZ/');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://rippl-osf.caffeine.xyz/', NULL, 'Rippl: Mindful Water Awareness App for Daily Reflection', 'Rippl is a gentle digital companion designed to help users cultivate a more conscious and intentional relationship with water. Through daily prompts and reflective moments, the app encourages mindfulness about water usage, conservation, and personal connection to this essential resource.', '7c2595fc48db5baf0658ff092933ef64', 'Vijayaraaghavan K S', 'Rippl', 'https://www.linkedin.com/posts/k-s-vijayaraaghavan_buildinpublic-uxdesign-behaviorchange-share-7425038931232075776-G-SV?utm_source=share&utm_medium=member_desktop&rcm=ACoAAFKlFKEB7C8Im3o41AWKkbABUrG-QaGqllM');
-INSERT INTO app (url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://secretkeeper-i82.caffeine.xyz/', NULL, 'Secret Keeper: Secure Personal Vault for Encrypted Secrets', 'A confidential digital vault that allows users to securely store and manage sensitive information using Internet Identity authentication. Protect your most private data with advanced encryption and decentralized storage.', '3600ef765e0655798bbce9c8c85e6e49', 'aasim rasheed', 'secret keeper', 'https://x.com/inkisawesom3/status/2019274397723283853');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://myl-f15.caffeine.xyz/', 'https://myl-f15.caffeine.xyz', NULL, 'MYL: Transform Fleeting Ideas into Actionable Missions', 'MYL is an innovative app designed to capture and develop your spontaneous ideas from various sources. Save inspiring moments, organize thoughts, and systematically turn random sparks of creativity into clear, achievable missions with structured planning and tracking.', '0f7157e361177b84e7c87da7edaec5e8', 'Thanos Aravanis', 'MyGallery', 'https://x.com/i/status/2018307457567662444');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://how-lh2.caffeine.xyz', 'https://how-lh2.caffeine.xyz', NULL, 'Caffeine Draft App Management Page', 'A webpage for managing draft apps on the Caffeine platform, allowing users to publish, restore, or modify their app drafts with features like live publishing, monetization, and custom domain options.', 'fedc8a4751eb1a1cb33255b2537ebec9', 'Pratik', 'How', 'https://x.com/gypsyhim/status/2018178548583051336?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lifeflow-qam.caffeine.xyz/', 'https://lifeflow-qam.caffeine.xyz', NULL, 'LifeFlow OS: AI-Powered Holistic Life Management', 'A comprehensive personal management platform that leverages AI to track mental and physical health, enhance productivity, organize daily routines, and provide digital security—all while prioritizing user privacy and autonomy.', 'e624df605a4469556b3eb814c26d2465', 'Danz asydq', 'LifeFlow OS', 'https://x.com/i/status/2018048844416839773');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://veggiemonster-1pc.caffeine.xyz/', 'https://veggiemonster-1pc.caffeine.xyz', NULL, 'VeggieMonster: Gamify Healthy Eating for Kids', 'A fun, interactive app that encourages children to eat fruits and vegetables by feeding a cute monster. Track progress, explore a variety of healthy foods, and make nutrition engaging through playful gameplay.', 'c9d1faf60bc527077b3ad2aea0a96257', 'Glitch', 'VeggieMonster', 'https://x.com/TheGlitchCrypto/status/2018278169724232081');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://savings-plan-b8g.caffeine.xyz/', 'https://savings-plan-b8g.caffeine.xyz', NULL, 'Savings Plan: Blockchain-Powered Personal Finance Tracking', 'A decentralized savings tracker that helps you create, monitor, and achieve financial goals with secure blockchain technology. Set targets, visualize progress, and stay motivated on your path to financial success.', '359e667250e57268d6b7c0ce6b23bb27', 'Suportus', 'Savings Plan', 'https://x.com/suportus/status/2018347616078070094');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://refuelos---wellness-companion-for-remote-workers-n9e.caffeine.xyz/', 'https://refuelos---wellness-companion-for-remote-workers-n9e.caffeine.xyz', NULL, 'RefuelOS: Wellness App for Remote Workers'' Mental Health', 'A digital wellness companion designed to support remote workers'' mental health and well-being. Offers calming tools, secure authentication, and personalized support to help professionals maintain balance and reduce stress in their work-from-home environment.', '568f921d4669c8acb4e9948b3fdfe78d', 'Gboyega_Designs', 'RefuelOS', 'https://www.linkedin.com/posts/adedokun-gboyega_graphicdesign-uxdesign-wellnessdesign-activity-7424124366193364992-MBnl');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://backshift-ueq.caffeine.xyz', 'https://backshift-ueq.caffeine.xyz', NULL, 'Backshift: Resilience and Personal Growth Platform', 'Backshift is a motivational platform encouraging personal resilience and transformation. With a minimalist approach, it inspires individuals to persevere, adapt, and find new paths forward when facing challenges, embodying the philosophy of ''Don''t quit. Just shift.''', 'e2143bfe39200d8918e06dab1ab291ee', 'Forida', 'Backshift', 'https://x.com/i/status/2018360994377142560');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://today-63t.caffeine.xyz/', 'https://today-63t.caffeine.xyz', NULL, 'Today: Personal Daily Intention & Reflection App', 'A private, secure digital space designed to help you set daily intentions, track personal growth, and engage in meaningful self-reflection. Powered by Internet Identity for a confidential and intuitive journaling experience.', '631a051d9bd28385bcbf0a0e32eccfa1', 'Kay', 'Today', 'https://x.com/kayicp/status/2018358085967126542');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://regret-minimizer-fcx.caffeine.xyz/', 'https://regret-minimizer-fcx.caffeine.xyz', NULL, 'Regret Minimizer: AI-Powered Future Simulation Platform', 'Revolutionary self-improvement intelligence platform using advanced neural forecasting, 3D life simulations, and AI-driven insights to help users minimize regrets, maximize potential, and make more informed life decisions through interactive technology.', '1aae573bf3df29bacfc3397a6e2ab956', 'Joseph Israel', 'Regret Minimizer', 'https://x.com/Israel_Icp/status/2018369831418863689?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://nofapnocig-bau.caffeine.xyz/', 'https://nofapnocig-bau.caffeine.xyz', NULL, 'NoFapNoCigs: Dual Habit Quitting Support Platform', 'A comprehensive support system for individuals seeking to overcome simultaneous addictions to smoking and compulsive behaviors. Empowering users to break free from destructive habits, reclaim personal control, and transform their life through strategic, holistic recovery approaches.', '687d6a6f61850464e88e31b71c14ebbb', 'Dipo', 'NoFapNoCig', 'https://x.com/dipoxyz_/status/2018373749406351438?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://danbasketball-team-management-and-scoring-app-htr.caffeine.xyz/', 'https://danbasketball-team-management-and-scoring-app-htr.caffeine.xyz', NULL, 'Dan Basketball: Team Management & Game Scoring App', 'A comprehensive basketball team management platform that allows easy player registration, team formation, and game tracking. Supports team slot assignments, waiting list management, and seamless game preparation for coaches and team organizers.', 'ad1620a44214eedf2b24bb33dd2a7f11', 'Danilo R. Cortaga', 'Dan Basketball team management', 'https://x.com/i/status/2018378949542515152');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://familia-huq.caffeine.xyz/', 'https://familia-huq.caffeine.xyz', NULL, 'Familia HUQ: AI-Powered Family Organization & Emotional Intelligence Platform', 'An innovative family app that combines AI, AR, and emotional intelligence to simplify family life. Features include smart scheduling, immersive storytelling, task management, and wellness tracking, all designed to bring families closer together through cutting-edge technology.', 'adfe416ac3355a1df246f8d972ae1880', 'Hannah', 'Familia', 'https://x.com/DomClipX/status/2018377599374729258?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://handnwellness.org', 'https://handnwellness.org', NULL, 'Here & Now: Mental Wellness Companion App for Emotional Support', 'A compassionate mobile app designed to help users navigate their emotional landscape through guided routines, fear management techniques, mood tracking, and personalized mental health support. Offers quick anchoring, reset strategies, and weekly insights to promote emotional resilience.', '9d50e85ee22e5e583bd5e856261cb761', 'Tyler Pernitsch', 'Here & Now', 'https://x.com/tyicp/status/2018380127550218248?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://momentum-8ap.caffeine.xyz/', 'https://momentum-8ap.caffeine.xyz', NULL, 'Momentum: Build Lasting Habits Through Resilience', 'A habit-tracking app that focuses on building momentum instead of punishing streaks. Celebrate progress, track consistency, and support your personal growth with recovery tasks and a unique Momentum Score that helps you stay motivated.', '71ee0ac7d7f0a0365e816a59704e5bb3', 'Maki', 'Streaks Are Fragile Momentum Isn''t', 'https://x.com/hellhou72100196/status/2018380887709089859?s=46&t=2Z81Z_7jbLLl9s2Okq3IAg');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://atomic-stacks-g08.caffeine.xyz', 'https://atomic-stacks-g08.caffeine.xyz', NULL, 'Atomic: AI-Powered Habit Stacking for Sustainable Personal Growth', 'Atomic is an innovative habit-building app that helps you design sustainable routines by stacking small habits onto existing behaviors. Powered by AI coaching, it provides personalized insights and visualizes your progress to transform willpower into consistent, meaningful change.', 'dcbeded66a020b9c147b6ba1c0e749aa', 'Mohamed Salem', 'Atomic: The Habit Stacking Application', 'https://www.linkedin.com/posts/cs-with-mohamed-salem_productivity-mindset-atomichabits-share-7424150168721141761-BMqP?utm_source=share&utm_medium=member_desktop&rcm=ACoAAD7SkX8BBabPAAA8V-CCfn4HEa-12SEE9iI');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://capability-debt-tracker-p4g.caffeine.xyz/', 'https://capability-debt-tracker-p4g.caffeine.xyz', NULL, 'Capability Debt Tracker: Quantify Professional Development Costs', 'A tool that helps professionals calculate the long-term financial and career impact of delaying skill development, certifications, and professional growth. Assess the hidden costs of postponed learning and career advancement across personal and professional domains.', '59f87d426f98c359dfba7799c0ac902a', 'Nairavine', 'Capability debt tracker', 'https://x.com/Nairavine/status/2018384417169813717?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://reconnect-0t2.caffeine.xyz/', 'https://reconnect-0t2.caffeine.xyz', NULL, 'ReConnect: Couples Wellness App for Deeper Relationship Connection', 'A digital platform designed to help couples strengthen their bond through private reflections, shared insights, and cooperative moments. Enhance communication, understanding, and intimacy with intuitive wellness tools that support relationship growth.', '4eb3c893fba2b549767b619bceb30c31', 'Fahim  Haque', 'ReConnect', 'https://x.com/fahimhaque77/status/2018384949569548412?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ashane---personal-life-transformation-app-72n.caffeine.xyz/', 'https://ashane---personal-life-transformation-app-72n.caffeine.xyz', NULL, 'Ashane: AI Life Coach for Personal Transformation', 'An innovative AI-powered personal coaching app that helps you transform your life by tracking finances, health, mindset, and personal growth. Get personalized guidance from your Future Self to overcome challenges and achieve your dreams.', '7fc93d1bc204727e7d6427dfd8d04d23', 'Tinomutenda Praymore Nkomo', 'Ashane', 'https://vt.tiktok.com/ZSapHevGB/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://readvo-pf0.caffeine.xyz/', 'https://readvo-pf0.caffeine.xyz', NULL, 'ReadVo: Speed Reading Platform for Enhanced Learning & Comprehension', 'A comprehensive training platform that helps users improve reading speed and comprehension through personalized exercises, baseline assessments, progress tracking, and interactive learning tools. Master faster reading with guided techniques and motivational achievements.', '557d6aede4ef16d23f04914ffbf6cf57', 'Nolan Plummer', 'ReadVo', 'https://x.com/DubmansOfficial/status/2018391164215505357');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://pitm.fintiqi.com', 'https://pitm.fintiqi.com', NULL, 'TaxManager NG: Simplify Nigerian Personal Income Tax Management', 'Effortlessly manage your Nigerian personal income tax with TaxManager NG. Track income, optimize exemptions, get deadline reminders, and calculate taxes accurately. Secure, user-friendly platform for stress-free tax compliance.', '828132388e9aa1be85bf5e90246476ba', 'Hope Sammy', 'Personal Income Tax Manager', 'https://www.instagram.com/reel/DTw2_uhCGrG/?igsh=MTgwaXR1OXQ0N2E2Nw==');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://better-leader-hiu.caffeine.xyz/', 'https://better-leader-hiu.caffeine.xyz', NULL, 'Better Leader: Leadership Skills Development Platform', 'A comprehensive online platform designed to help professionals transform their leadership abilities through personalized assessments, tailored development plans, progress tracking, and expert resources. Enhance your leadership skills with structured, data-driven guidance.', 'af2aedd86f3386c3eb8a41396a749abf', 'Martin Plsek', 'Better Leader', 'https://x.com/plsak/status/2018427328456544490?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://one-step-app-54l.caffeine.xyz/', 'https://one-step-app-54l.caffeine.xyz', NULL, 'One Step: Minimal Goal Progression Through Tiny, Achievable Actions', 'A goal-setting app that breaks down personal development into the smallest possible actions, helping users overcome resistance by making progress feel effortless. Inspired by Kaizen and Atomic Habits, One Step focuses on consistent, low-pressure growth across fitness, learning, mindfulness, and personal goals.', 'e78662b6f5a65fe151c8d7c32d2ec14e', 'Valkyrill', 'One Step', 'https://x.com/i/status/2017748323868799452');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://fritso-55m.caffeine.xyz/', 'https://fritso-55m.caffeine.xyz', NULL, 'Fritso: Calculate Work Hours Needed for Your Purchases', 'A simple tool that helps you understand the real value of your purchases by translating item prices into the number of work hours required to afford them. Gain insights into your spending by measuring the true cost of your desires.', '2d56e7b311b9ca60ea5f6731bdf01e50', 'Adam Snetiker', 'Fritso', 'https://www.instagram.com/p/DURf3NHkl6hkejk6op0zXDx7_Epjn1MPyeD3kg0/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://nr-ayr.caffeine.xyz/', 'https://nr-ayr.caffeine.xyz', NULL, 'Pythagorean Numerology Insights: Personal Name & Birth Date Analysis', 'Discover the hidden meanings behind your name and birth date using David Phillips'' numerology system. Generate a comprehensive personal numerology report that reveals insights into your life path, personality, and potential compatibility.', '57eee24cd7b295d50201a213830ec94a', 'Greg', 'Pythagorean Numerology', 'https://x.com/psychobiosocial/status/2018473848455450702?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://aimeal-lj1.caffeine.xyz/', 'https://aimeal-lj1.caffeine.xyz', NULL, 'Caffeine Draft App: Create, Publish, and Monetize Content', 'A platform for content creators to develop, publish, and monetize digital apps with features like live chat, custom domains, and app analytics. Easily manage and share your creative projects through Caffeine''s streamlined publishing tools.', '2bceecd949c03794b2710dd59a70fa11', 'Incognitov1_', 'MEALai', 'https://www.x.com/incognitov1_');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://icpmemeworld-65u.caffeine.xyz', 'https://icpmemeworld-65u.caffeine.xyz', NULL, 'Icpworldmemepad: Revolutionary ICP Memecoin Platform', 'Create and trade memecoins on the Internet Computer Protocol with lightning-fast trading, advanced analytics, and unique Fair Mode protection. Launch your token in minutes, connect your Icpswap wallet, and join the next viral crypto sensation.', '564c3ae6714f189946d942f9a55fe909', 'Akmal Bhutta', 'Icpworldmemepad', 'https://x.com/icpmemeworld?t=3Bbkhkdt1K5GEbF9hkIYKw&s=08');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://task-management-system-ubp.caffeine.xyz/', 'https://task-management-system-ubp.caffeine.xyz', NULL, 'Task Management System: Organize, Prioritize, and Optimize Workflow', 'A comprehensive task management platform that enables users to create custom task fields, track task urgency and value, set dependencies, and leverage automatic metrics calculation. Designed to streamline work organization and enhance productivity with intuitive sorting and filtering capabilities.', 'e97082dea91ecc1aac59c4f41053128d', 'Ioannis Giftakis', 'Task Management System', 'https://www.facebook.com/groups/teachersgr/posts/25889959310620394/?__cft__[0]=AZY4nULzeB-QF4As6tBeksOndfw9i5dzxvz1nhqzkdWzjefCwpBtfGhU3fQ458S9S2H7eos6fz_gHcwhLJ1aWt_uBiOwXoEow38fp8F840HgmbwJfocSH689jYVf4vEmmJWTNXHufb-whKkmT5FYa7nW&__tn__=%2CO%2CP-R');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://pelatihanz7-6fw.caffeine.xyz/', 'https://pelatihanz7-6fw.caffeine.xyz', NULL, 'Training Management System: Streamline Employee Learning & Development', 'A comprehensive web application for efficient training planning, tracking, and management. Features interactive dashboards, automated certificate tracking, secure authentication, and easy data management for organizations to optimize employee skill development.', '33db02aef1d491add8b4806d8fe4b4b7', 'Fariz Arizal', 'Worker Training Planning and Realization Application', 'https://pelatihanz7-6fw.caffeine.xyz/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://questcrush-ai-7zo.caffeine.xyz/', 'https://questcrush-ai-7zo.caffeine.xyz', NULL, 'QuestCrush AI: Gamified Personal Development with AI Coaching', 'Transform personal growth into an engaging adventure with AI-powered quests. Receive smart, personalized challenges, earn XP, track progress, and get motivational support from an intelligent AI coach to level up your life and achieve your goals.', 'a996e9dd38eed6d1734fabac50c59c84', 'Samuel Mungai Owino', 'QuestCrush AI', 'https://x.com/Sam_mungai_/status/2018565489493221528?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://didntquitselfimprovementapp-7gm.caffeine.xyz', 'https://didntquitselfimprovementapp-7gm.caffeine.xyz', NULL, 'Caffeine App Development Platform', 'A web platform for creating, publishing, and managing apps with features like live publishing, monetization options, custom domains, and integrated analytics for developers and creators.', '69ecd02ad0948adffc83b2c367b8afbe', 'John', 'IDidntQuitSelfImprovementApp', 'https://x.com/i/status/2018573622735200744');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://elevatedaily-f64.caffeine.xyz/', 'https://elevatedaily-f64.caffeine.xyz', NULL, 'Elevate Daily: Personal Growth & Habit Tracking App', 'A comprehensive self-development web application designed to help users build positive habits, track personal goals, and enhance overall wellness through an intuitive digital platform. Empower your personal growth journey with personalized tracking and motivational tools.', 'e43318753f2ceedc020b655ce0951663', 'Baron', 'Elevate Daily', 'https://x.com/RCanaa/status/2018582268441465260?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://quit-valhalla-334.caffeine.xyz/', 'https://quit-valhalla-334.caffeine.xyz', NULL, 'Quit Valhalla: Habit Tracking for Personal Transformation', 'A motivational app designed to help users track and overcome challenging habits, celebrating personal growth through sobriety tracking, achievements, and inspirational support. Empower your journey of self-improvement with goal-setting and progress monitoring.', '680da88923f4e6b0c16d382be8204b5d', 'Kenny Blaq', 'Quit Vahalla', 'https://x.com/mint_radarxyz/status/2018595582517129224?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://the-key-sz1.caffeine.xyz/', 'https://the-key-sz1.caffeine.xyz', NULL, 'THE KEY: Daily Goal Tracking & Personal Progress App', 'A powerful personal development tool designed to help you systematically track daily actions, build momentum, and progressively move closer to your life goals through consistent, intentional progress tracking.', '05d529d400ec17a43acfe4fd7362ced7', 'Jan Hruška', 'THE KEY', 'https://x.com/AurroraGenArt/status/2018598105168457806');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ecoverse-idw.caffeine.xyz/', 'https://ecoverse-idw.caffeine.xyz', NULL, 'KindCart: AI-Powered Sustainability Shopping Assistant', 'KindCart helps shoppers make eco-friendly choices by analyzing receipts, providing instant sustainable product alternatives, and tracking personal carbon impact. Empower your shopping with AI-driven green insights and reduce your environmental footprint.', 'e04441d10089a6a3b630276d4f3af94f', 'Koushik Ghosh', 'KindCart', 'https://x.com/koushik_AI/status/2018599523812253865?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://soulsync-2sm.caffeine.xyz/', 'https://soulsync-2sm.caffeine.xyz', NULL, 'SoulSync: AI-Powered Personal Growth Platform', 'An innovative digital platform that combines knowledge management and emotional journaling, enabling personal development through secure, decentralized authentication. Designed to track intellectual and emotional growth with AI-assisted insights.', 'b87a60e8324a66a126bda2d7c8f99435', 'Konson', 'SOULSYNC', 'https://x.com/konson1009/status/2018616102822289867?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://typefaster---typing-challenge-app-mg6.caffeine.xyz', 'https://typefaster---typing-challenge-app-mg6.caffeine.xyz', NULL, 'TypeFaster: Typing Challenge Game with Crypto Rewards', 'Improve your typing skills through 5 progressive levels, earn XP, and compete daily for ICP cryptocurrency prizes. Challenge yourself, track your progress, and climb the leaderboard in this engaging typing performance app.', '87c80abc8d852dbcceb302761e103711', 'Vlad Smen', 'TypeFaster', 'https://x.com/tuvav243788/status/2018618188049649668');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://food-recipes-book-application-22y.caffeine.xyz/', 'https://food-recipes-book-application-22y.caffeine.xyz', NULL, 'Food Recipes Book: Personal Healthy Cooking Companion', 'A digital recipe management platform that lets you create, organize, and share your personal collection of healthy recipes. Securely authenticate and access your culinary library with ease, powered by Internet Computer technology.', 'f2299af00e253d8e35ba5db32b92d26a', 'Artūras', 'Healthy Food Recipes Book', 'https://x.com/i/status/2018620212548116709');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://groundflow---wellness-app-0vp.caffeine.xyz/', 'https://groundflow---wellness-app-0vp.caffeine.xyz', NULL, 'GroundFlow: Holistic Wellness App for Mood Tracking & Self-Care', 'A comprehensive wellness app that helps you track mood, manage stress, and improve mental health through daily check-ins, quick practices, progress monitoring, and community support. Designed to empower personal well-being with intuitive, supportive tools.', 'f8eb46c292c7e69830401d1c3d1c9b3c', 'Jephtah Ogan', 'GroundFlow', 'https://x.com/i/status/2018618337723662380');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://better-me-8j4.caffeine.xyz/', 'https://better-me-8j4.caffeine.xyz', NULL, 'Better Me: Personal Growth and Secure Authentication Platform', 'A transformative web application designed to support your personal development journey, offering secure login through Internet Identity and providing tools to help you track, improve, and evolve your best self.', '85b821ce5be0cf350d6486a1b7ee0f79', 'birjahang kerung', '''''Better Me'''' Self Improvement App', 'https://x.com/i/status/2018624811648610447');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lifereset-jol.caffeine.xyz', 'https://lifereset-jol.caffeine.xyz', NULL, 'LifeReset: Mindful Self-Improvement & Personal Growth Platform', 'A transformative digital tool designed to help users build self-awareness through structured reflection, evidence-based progress tracking, and AI-powered insights. Enhance personal growth with daily practice and intelligent pattern recognition.', '4eab95c6a212de66714780d9eb7dc732', 'Judex', 'LifeReset', 'https://x.com/i/status/2018583191792349414');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://iamfocused.app', 'https://iamfocused.app', NULL, 'FOCUS.: Motion-Driven Productivity App to Reclaim Your Attention', 'A revolutionary app that uses motion detection to help you break digital distractions. By physically placing your phone down, you create a tangible commitment to focus, leveraging behavioral psychology to train your brain, boost productivity, and reconnect with deep work.', '7c9c19a0435e7a7cb682edf74f23585a', 'Markus Brunner', 'FOCUS.', 'https://x.com/markus_ai_/status/2018624902186848336?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-you-simulator-k28.caffeine.xyz/', 'https://future-you-simulator-k28.caffeine.xyz', NULL, 'Future You Simulator: Visualize Your Path by 2026', 'An interactive tool that reveals two potential futures based on your current habits—showing your default trajectory and an optimized path. In just 5 minutes and 10 steps, gain clarity on personal growth and actionable insights without judgment.', '1dad6bad09e7ea5a20fb6631c55aab0b', 'sunil limbu', 'Future You Simulator 2026 Edition', 'https://x.com/i/status/2018647285945733370');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://kosha-compass-i1x.caffeine.xyz/', 'https://kosha-compass-i1x.caffeine.xyz', NULL, 'Kosha Compass: Holistic Personal Growth and Self-Discovery Platform', 'A transformative digital tool designed to help individuals track and understand their five layers of being (koshas), supporting personal development, self-awareness, and spiritual growth through a personalized wellness journey.', '02a2713cf8b28cc72b58ae8959d1e75e', 'Vandana', 'Kosha Compass', 'https://www.linkedin.com/posts/vandana-jariwala-459159386_caffeineai-ai-build-activity-7424429074363637760-drEf?utm_source=share&utm_medium=member_desktop&rcm=ACoAAF8QAEUBm2sBPG4fz_DWg2lipb-9E9S0xgM');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://weekly-reflection-gsy.caffeine.xyz/', 'https://weekly-reflection-gsy.caffeine.xyz', NULL, 'Weekly Reflection: Personal Journey Tracking & Summarization', 'A secure, private platform for documenting and reflecting on your weekly experiences. Personalize your journey, pause and observe your progress, and securely store insights using Internet Identity authentication.', 'de56b5969e4b1ba152151d13e6a90d35', 'Gunawan Yudho Satrio', 'kenzonutss', 'https://x.com/yudho_oo/status/2018666020488266174?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://my-gratitude-and-positivity-promptathon-v2-aps.caffeine.xyz/', 'https://my-gratitude-and-positivity-promptathon-v2-aps.caffeine.xyz', NULL, 'Gratitude & Positivity Promptathon: Daily Reflection & Mindfulness', 'An interactive digital platform designed to help users cultivate daily gratitude and positive thinking through secure, private journaling. Encourage personal growth, mindfulness, and emotional well-being with guided prompts and a supportive online experience.', '495011912e09903fda9f1e70e56620d1', 'Ben Slater', 'My Gratitude & Positivity Promptathon V2', 'https://x.com/monkey898898/status/2018676462795084221?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://deskrhythm-1ua.caffeine.xyz/', 'https://deskrhythm-1ua.caffeine.xyz', NULL, 'DeskRhythm: AI-Powered Wellness App for Healthier Work Habits', 'An intelligent application that uses ambient technology to guide users toward better workplace wellness. Track posture, manage breaks, monitor digital habits, and receive personalized insights to improve daily productivity and overall wellbeing.', 'ed44d85f476671138efc4d4300cfda35', 'Rasheed Mudasiru', 'DeskRhythm', 'https://vt.tiktok.com/ZSaGPsnCf/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://gamified-health-and-wellness-tracker-j9t.caffeine.xyz/', 'https://gamified-health-and-wellness-tracker-j9t.caffeine.xyz', NULL, 'Routinely: Gamified Wellness Tracker for Personal Growth', 'A revolutionary app that transforms self-improvement into an engaging game. Complete daily wellness quests, earn XP, compete on global leaderboards, and level up through cognitive challenges and habit-building activities across mindfulness, fitness, and personal development.', '51554673a376a06385b4e85bf543aac4', 'Syv', 'Routinely', 'https://x.com/0xSyv/status/2018682154494926999');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://daily-icp-mental-health-check-ue7.caffeine.xyz/', 'https://daily-icp-mental-health-check-ue7.caffeine.xyz', NULL, 'ICP Daily Health Check: Economic Simulation & Price Calculation Tool', 'A web application for analyzing and estimating ICP token prices through dynamic economic simulation. Configure cycle demand parameters, explore market variables, and calculate potential price scenarios with interactive dashboard and economic modeling features.', '26de4b567f7911c25af8a9fe1a954373', 'Juice64', 'Make My Day Inputs', 'https://x.com/Juice64icp/status/2017402441042694606?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-you79-25a.caffeine.xyz/', 'https://future-you79-25a.caffeine.xyz', NULL, 'Future You, Interrupted: Goal Tracking & Time-Shifted Messaging', 'A unique platform that allows users to create personal goals, write messages to their future selves, and track progress through an innovative dashboard. Sign in or try demo mode to explore self-reflection and future planning.', '3335caffeb6a4e9c953df31560ac98ea', 'B M Kiran', 'Future You, Interrupted', 'https://x.com/Manojkiran10063/status/2018709212142915638?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://adaptive-habit-coach-aln.caffeine.xyz/', 'https://adaptive-habit-coach-aln.caffeine.xyz', NULL, 'Adaptive Habit Coach: Personalized Habit Building & Progress Tracking', 'A smart, supportive app that helps you build better habits step by step. Track your progress, stay motivated, and create lasting change with personalized insights and encouragement. Secure, private, and designed to help you succeed.', '91258f84fe21951f32e73cc72d959e54', 'Joel', 'Adaptive Habit Coach', 'https://x.com/icpholic/status/2018713286607253575');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://helios-app-6rl.caffeine.xyz/', 'https://helios-app-6rl.caffeine.xyz', NULL, 'HELIOS: Personalized Productivity App with Internet Identity Authentication', 'HELIOS is an innovative app designed to enhance your daily rhythm and productivity. Featuring secure Internet Identity login, the platform offers a streamlined approach to managing tasks and personal workflows with cutting-edge blockchain authentication technology.', '8f68decfcfefdf11f4f40addc75e0fca', 'Aron', 'Helios app', 'https://x.com/Meta_Aron/status/2018720423161049201?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://my-info-vault-3ea.caffeine.xyz/', 'https://my-info-vault-3ea.caffeine.xyz', NULL, 'Info Vault: Secure Personal Data Management on the Blockchain', 'A privacy-focused platform for managing personal information like tasks, bills, and notes using blockchain technology. Leverages Internet Identity for secure authentication and ensures data encryption and protection on the Internet Computer.', 'c3582b197c0ca0619131a42741412c47', 'Edi', 'Info Vault', 'https://x.com/EDI_ICP/status/2018724085891576219?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://theinnerop-o2a.caffeine.xyz/', 'https://theinnerop-o2a.caffeine.xyz', NULL, 'The Inner Operator: Minimalist Objective Tracking for Focused Execution', 'A streamlined productivity tool that operates silently in the background, tracking objectives without distractions. Provides clear orders, honest feedback, and pace tracking to help you execute what matters most, with privacy-first, mobile-friendly design.', 'b7ea670fd9dc1e30d4b998a75176154d', 'Mark Pernitsch', 'The Inner Operator', 'https://x.com/mindr3adr/status/2018744209054793941?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://public-domain-music-radio-l9g.caffeine.xyz/', 'https://public-domain-music-radio-l9g.caffeine.xyz', NULL, 'Public Domain Music Radio: Vintage Streaming for Businesses', 'Stream 100% legal vintage music for restaurants, coffee shops, stores, bars, hotels, and salons. No login required—enjoy curated public domain tracks that create a nostalgic ambiance for your business, completely free forever.', '559fe3ed2d59855e6518787c6be5fa25', 'Romulus', 'Vintage Radio - Public Domain Music for Business', 'https://x.com/Romulus96711505/status/2018747958641020931');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://life-ai-db4.caffeine.xyz/', 'https://life-ai-db4.caffeine.xyz', NULL, 'Life AI: Comprehensive Personal Dashboard for Life Management', 'An innovative digital platform that helps users comprehensively manage and monitor various life aspects through a secure, blockchain-powered dashboard. Track personal progress, maintain privacy, and gain holistic insights into your life''s development.', 'a55770a7dfb65ace24f6236a231ab1c6', 'Aril babunuddin', 'Life AI', 'https://x.com/i/status/2018755320311693655');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-self-notes-6so.caffeine.xyz/', 'https://future-self-notes-6so.caffeine.xyz', NULL, 'Future Self Notes: Reflective Journaling Across Time', 'A unique journaling app that lets you write messages to your future self with time-delayed delivery. Capture insights, track moods, and receive personalized prompts while keeping your reflections private and secure on blockchain technology.', '3bb10ae54c4477b03305604c8fe00480', 'Shane Bloomfield', 'Future Self Notes', 'https://x.com/i/status/2018628333756629001');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://excal-vc5.caffeine.xyz/', 'https://excal-vc5.caffeine.xyz', NULL, 'ExCal: Smart Expense Tracking & Budget Management App', 'ExCal helps you take control of your finances through easy expense recording, budget setting, and insightful spending analytics. Securely track, categorize, and visualize your financial habits with an intuitive, privacy-focused application.', '9dbfec0ec329128893949f0a21a6cf28', 'Ketan Chandekar', 'ExCal Expense Calculator', 'https://x.com/ketan_chandekar/status/2018762789482119472?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://anchor-5zs.caffeine.xyz/', 'https://anchor-5zs.caffeine.xyz', NULL, 'Anchor: Holistic Self-Development App for Women', 'A transformative digital platform designed to support women''s personal growth through intentional, energy-aligned practices. Focuses on emotional well-being, setting meaningful intentions, and nurturing self-awareness beyond traditional productivity metrics.', 'e137f2f89baa5986b2e57459ba403cde', 'Michelle', 'Anchor', 'https://x.com/ben_collinzz/status/2018766979272695927?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lockedin-hfg.caffeine.xyz/', 'https://lockedin-hfg.caffeine.xyz', NULL, 'LockedIn: Strategic Goal Planning & Accountability App', 'A powerful self-development tool that helps entrepreneurs and professionals transform goals into actionable plans. Break down objectives, track daily progress, conduct weekly reviews, and stay consistently aligned with your vision.', 'a308b4b27a736b189dbc413b990abeb7', 'Rissrozy C. Obiefuna', 'LockedIn', 'https://x.com/i/status/2018763743920783378');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://soma-6he.caffeine.xyz/', 'https://soma-6he.caffeine.xyz', NULL, 'Soma: Intuitive Somatic Sensation Tracking App', 'A mobile web application for mindful body awareness, allowing users to log and track bodily sensations through an interactive, dot-based interface. Offers guest mode for quick, local data tracking without account creation.', '472ee1f525913a9346cc73360c4a352c', 'Andreea Coman Oprin', 'Soma', 'https://www.linkedin.com/posts/andreeaoprin_soma-somatic-tracking-mobile-web-application-share-7424546692437417984-n-cy?utm_source=share&utm_medium=member_desktop&rcm=ACoAABaKtEEBqeel5GKa_Vpc3Gl3cw5NKoDdb6U');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://puffinwellness-mu3.caffeine.xyz/', 'https://puffinwellness-mu3.caffeine.xyz', NULL, 'Puffin Wellness: Your Mindful Self-Improvement Companion App', 'A holistic wellness app featuring AI-powered chat, guided breathing, goal tracking, journaling, and interactive tools designed to support emotional well-being and personal growth through engaging, supportive features.', 'bb226b130f1e44232297b161f4c5800b', 'Ben', 'Puffin Wellness', 'https://x.com/PuffinHubx/status/2018781997523706287');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://kindness-garden-egd.caffeine.xyz/', 'https://kindness-garden-egd.caffeine.xyz', NULL, 'Kindness Garden: Character Growth Through Interactive Quests', 'An innovative platform that helps users develop personal character and kindness through engaging, gamified quests. Securely track your progress using Internet Identity while embarking on a meaningful journey of self-improvement and compassionate growth.', 'f18d65568ecebcadb57849e7940b4770', 'Usertest', 'Kindness Garden', 'https://x.com/lukitagamer6/status/2018793127130403156');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://attn-nmp.caffeine.xyz/', 'https://attn-nmp.caffeine.xyz', NULL, 'ATTN: Mindful Attention Tracking Without Distractions', 'A minimalist digital tool designed to help you quietly reflect on and understand your daily attention patterns. Simple, streak-free, and notification-free, ATTN offers a gentle approach to self-awareness and personal insight.', 'b704d16f662265a2106111113b294e67', 'Carl Fitzpatrick', 'ATTN', 'https://x.com/i/status/2018793149116899799');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://daily-diary-ytz.caffeine.xyz/', 'https://daily-diary-ytz.caffeine.xyz', NULL, 'Daily Diary: Private Blockchain-Powered Personal Journal App', 'A secure, modern journal application that leverages blockchain technology to protect your personal memories. Login with Internet Identity for a private, encrypted digital diary experience where your data remains completely yours.', '12b9f2e0fdf6c156408c2e9f6672574a', 'shogaku', 'Yasu', 'https://x.com/i/status/2018805371671085310');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://vision-k0a.caffeine.xyz/', 'https://vision-k0a.caffeine.xyz', NULL, 'Vision: Transform Dreams into Actionable Visual Goals', 'A powerful goal-setting platform that helps you create inspiring vision boards, break down aspirations into microgoals, and track progress across multiple life areas. Visualize your dreams with personalized images and secure authentication.', 'e284c5e9b305222f7fa78c0b0e1f9041', 'SJ', 'Vision', 'https://www.instagram.com/p/DUT9rFQjCqK/?utm_source=ig_web_copy_link&igsh=MzRlODBiNWFlZA==');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://yip-pjz.caffeine.xyz/', 'https://yip-pjz.caffeine.xyz', NULL, 'YIP: Personal Goal Setting & Transformation App', 'A powerful personal development platform that helps you design your future through intentional goal-setting, self-reflection, and creative visualization. Transform your year with purpose, create affirmations, build moodboards, and define your ideal self.', '8c8ac84d143cdd8937b45f74cf16d09f', 'ICPVibeCoder', 'YIP app - "Year in Preview"', 'https://x.com/i/status/2018820750719996013');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://wellness-bell-3kp.caffeine.xyz', 'https://wellness-bell-3kp.caffeine.xyz', NULL, 'Wellness Bell: Mindfulness Timer & Meditation Audio Companion', 'A simple web application designed to support meditation and mindfulness practices. Set custom timer durations, configure bell sounds, and create a peaceful audio-guided experience to help you stay focused and centered during your wellness sessions.', '77ccca1601b6cac43f48faafbd05bfd7', 'Jason Khoh', 'Wellness Bell', 'https://x.com/ICP_Insights/status/2018651384858759302?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ironsharpensiron-nrx.caffeine.xyz/', 'https://ironsharpensiron-nrx.caffeine.xyz', NULL, 'Iron Sharpens Iron: Community-Driven Personal Growth Platform', 'A purpose-driven social network designed to unite individuals committed to self-improvement. Connect with like-minded people, set and track personal goals, share experiences, and grow together through meaningful conversations and mutual accountability.', 'a033a1a649b14cd21a1b0c1351c2ac08', 'Joshua Coston', 'Iron Sharpens Iron', 'https://x.com/Mr_Coston/status/2018852279672320208');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://exercise-and-weight-loss-tracking-app-oz0.caffeine.xyz/', 'https://exercise-and-weight-loss-tracking-app-oz0.caffeine.xyz', NULL, 'GoalQuest: Fitness Tracking App for Weight Loss & Health Goals', 'Transform your body and life with GoalQuest, a comprehensive fitness platform that helps you create SMART goals, track workouts, visualize progress, and connect with a supportive fitness community. Earn rewards and stay motivated on your health journey.', '9972aa390e9eff50eacb6e2587e88176', 'Joe Walsh', 'Self Improvement goal tracking app', 'https://x.com/JAW66Coin/status/2018858993536696746?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://friction-v70.caffeine.xyz', 'https://friction-v70.caffeine.xyz', NULL, 'Friction: Personal Growth App for Intentional Self-Improvement', 'A transformative self-improvement platform that helps users create meaningful personal change through structured guidance, private journaling, goal tracking, and random challenges designed to push individuals beyond their comfort zones and foster continuous personal development.', '206b131b2ad656d64b3ec52a3280419f', 'Juan Fonseca', 'Friction', 'https://x.com/Womplet/status/2018892965469397089?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://the-virtue-nator-rp3.caffeine.xyz/', 'https://the-virtue-nator-rp3.caffeine.xyz', NULL, 'Virtue-Nator: Cultivate Excellence Through Habitual Virtues', 'An interactive web app exploring philosophical wisdom about virtue, helping users understand and practice key ethical principles. Learn how repeated good choices shape character, with practical guidance on developing virtues like justice, wisdom, and self-improvement.', '5977cd9416e8a822d4616b84d4f4117d', 'Joe', 'THE VIRTUE-NATOR', 'https://x.com/JoeWils41632240/status/2018906318539829367');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://colortexteditor-s0u.caffeine.xyz', 'https://colortexteditor-s0u.caffeine.xyz', NULL, 'ColorTextEditor: Advanced Rich Text Editing with Color Tools', 'A professional React-based text editor offering comprehensive formatting options, advanced color manipulation, and versatile file management. Features include theme toggling, headers, lists, code blocks, and easy data backup and export capabilities.', 'c4fd879e294095f978f55080d54404b7', 'Monte Self', 'ColorTextEditor', 'https://x.com/MonteSelf61');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://capitalis-s01.caffeine.xyz', 'https://capitalis-s01.caffeine.xyz', NULL, 'Capitalis: Personal Capital Allocation & Investor Mindset Platform', 'A calm, systematic approach to understanding personal finance and investing. Track time, money, and effort weekly to develop long-term financial clarity and confidence without pressure or noise. Designed to help you gradually build an intentional investment mindset.', '715dab71fd816a0b6a157a2314ffe42b', 'Sanketh Elalli', 'Capitalis', 'https://www.linkedin.com/posts/snketh_n8n-caffeineai-promptengineering-activity-7424707640880021504-J7iq?utm_source=social_share_send&utm_medium=member_desktop_web&rcm=ACoAADpZWXwBw13xmh6mduaQB_5MfS1FWQeCRq0');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://echomoods-n6b.caffeine.xyz', 'https://echomoods-n6b.caffeine.xyz', NULL, 'EchoMoods: Anonymous Global Mood Sharing Platform', 'An innovative app that allows users to anonymously share and explore emotions worldwide. Connect with others through a secure, identity-protected platform that reveals the collective emotional landscape while maintaining personal privacy.', '1f44178b197e8913b33be2d941a7594f', 'Tukalu', 'Echomoods', 'https://x.com/Acut40212407/status/2018968858585923786');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ethosarc-l3q.caffeine.xyz/', 'https://ethosarc-l3q.caffeine.xyz', NULL, 'Ethos: Personal Growth Companion for Intentional Development', 'A digital platform designed to help users track and enhance personal growth through structured insights, progress monitoring, and a supportive dashboard. Empowers individuals to cultivate intentional self-improvement with secure, user-friendly tracking tools.', '829e764d23f0b800181c69098536452d', 'Müslüm', 'Ethos', 'https://x.com/thec4ke/status/2018977937324908577?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://friction-vault-contracts-lbb.caffeine.xyz/', 'https://friction-vault-contracts-lbb.caffeine.xyz', NULL, 'Friction Vault: Personal Goal Achievement with Financial Accountability', 'A blockchain-powered platform that helps users set and track personal goals through financial stakes. Create self-contracts with dual vault systems, daily check-ins, and smart analytics to overcome obstacles and incentivize success.', '8d61c6625e0f262547bfcce5f0d84639', 'Rajat Kumar', 'Friction Vault Contracts', 'https://x.com/rajatwasan2/status/2018599123474387108?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://smart-goals-v9h.caffeine.xyz/', 'https://smart-goals-v9h.caffeine.xyz', NULL, 'SMART GOALS: Personal Goal Planning & Achievement Platform', 'A comprehensive goal tracking tool designed to help you set clear objectives, monitor progress, and systematically work towards personal and professional success. Empower yourself with strategic planning and actionable insights.', 'c6b9c783c18583c0637210e8d85fe38e', 'LUKITO NUGROHO', 'SMART GOALS', 'https://x.com/KiiBit/status/2018988116112203938?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://nora-e7n.caffeine.xyz', 'https://nora-e7n.caffeine.xyz', NULL, 'NORA: Your Mindful Companion for Intentional Living', 'A personal reminder assistant that helps you stay connected to your deeper values and goals through gentle, personalized prompts. NORA supports intentional living by providing meaningful nudges that inspire reflection and alignment with your true motivations.', '5f70f0c3b32ffe3ceec4b7ef50ffd26a', 'Benjamin John', 'N.O.R.A - North-Oriented Reminder Assistant', 'https://x.com/i/status/2018995335578997022');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://one-promise-p83.caffeine.xyz/', 'https://one-promise-p83.caffeine.xyz', NULL, 'One Promise: Daily Self-Trust and Personal Growth', 'A mindful approach to personal development focused on making and keeping one small, meaningful promise to yourself each day. Cultivate self-trust, reflection, and intentional progress through simple, achievable commitments.', 'ff64813ca6aa919acfe762426160aa6a', 'Jacob Kemsley', 'One Promise', 'https://www.linkedin.com/posts/jkemsley_one-promise-share-7424761301492494336-b_hd/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mithaq-l42.caffeine.xyz/', 'https://mithaq-l42.caffeine.xyz', NULL, 'MITHAQ: Personal Accountability Platform for Growth & Goals', 'A private digital vault for personal development, goal tracking, and self-accountability. Set SMART goals, perform daily check-ins, earn progress badges, and maintain consistency in a secure, encrypted personal space.', '949134286676e0fe0d31388e51a4d9d6', 'Alsharif Suhaim', 'MITHAQ', 'https://x.com/i/status/2019000306936274994');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://shelter-connect-0kx.caffeine.xyz/', 'https://shelter-connect-0kx.caffeine.xyz', NULL, 'Shelter Connect: Emergency Storm Shelter Directory for Portugal', 'A community-driven platform helping people locate and share safe storm shelters across Portugal. Add shelter locations, request help during emergencies, and contribute to a collaborative safety network.', '27cf937fda13be93d168f0db94a958e0', 'Sukant Ghosh', 'Shelter Connect', 'https://x.com/zuki_sf/status/2019012863407661081');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mental-health-clinic-kqf.caffeine.xyz/', 'https://mental-health-clinic-kqf.caffeine.xyz', NULL, 'Mental Health Clinic Denpasar: Professional Psychiatric Care in Bali', 'A premium psychiatric clinic offering comprehensive mental health services in Denpasar, Bali. Providing expert evaluation, diagnosis, treatment, and ongoing support for various mental health conditions with a personalized, confidential, and ethical approach.', 'f4db725a4e47526fe27a2292ade5407a', 'Pratiwi', 'Tiwi', 'https://x.com/ceritaseleb62/status/2019018218585890999');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://continuum-10o.caffeine.xyz', 'https://continuum-10o.caffeine.xyz', NULL, 'Continuum: AI-Powered Personal Growth & Habit Tracking', 'An innovative personal development platform that uses AI to help you build clarity and consistency. Track patterns, understand habits, and make meaningful progress through gentle, guided daily growth strategies.', '440a08280ae7e9e5dabef4e57c48ba9f', 'Icpspace', 'Continuum', 'https://x.com/Icpspace/status/2019016711425438192?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://www.prabhatchhirolya.com', 'https://www.prabhatchhirolya.com', NULL, 'Prabhat Chhirolya: XR Design & Immersive Visualization Portfolio', 'Professional portfolio of Prabhat Chhirolya, an innovative XR designer and immersive visualization expert specializing in BIM/VDC architecture and AI-driven creative solutions. Based in Mumbai, India, showcasing cutting-edge design and technological expertise.', '789e359196089435e8d5cd0db85673ac', 'Prabhat Chhirolya', 'Prabhat Chhirolya Creative OS', 'https://www.linkedin.com/posts/prabhatchhirolya_%F0%9D%97%A7%F0%9D%97%B5%F0%9D%97%B6%F0%9D%98%80-%F0%9D%97%BD%F0%9D%97%BC%F0%9D%97%BF%F0%9D%98%81%F0%9D%97%B3%F0%9D%97%BC%F0%9D%97%B9%F0%9D%97%B6%F0%9D%97%BC-%F0%9D%97%96%F0%9D%97%A9-%F0%9D%98%84%F0%9D%97%AE%F0%9D%98%80-activity-7424697985017745408-G4Wm?utm_source=social_share_send&utm_medium=android_app&rcm=ACoAAB6m5MAB9ef0aEv1PqqmsrwbWXLk35pp3UI&utm_campaign=share_via');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mobile-wellness-companion-d7g.caffeine.xyz/', 'https://mobile-wellness-companion-d7g.caffeine.xyz', NULL, 'AI Wellness Coach: Personalized Health & Balance Companion', 'An innovative AI-powered wellness platform that provides tailored personal health programs, progress tracking, and motivational guidance to help you achieve optimal mental and physical well-being through secure, decentralized technology.', '78d01f8ee491801502b84f4fd32f53a6', 'Maira', 'pratiwi-113', 'https://x.com/yunzks/status/2019034128424079497');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://balimedic-ffj.caffeine.xyz/', 'https://balimedic-ffj.caffeine.xyz', NULL, 'BaliMedicCare: Personalized Wellness & Preventive Care in Bali', 'Premium wellness center offering comprehensive health solutions in Bali. Tailored wellness programs, IV therapies, diagnostics, and personalized care for families and individuals seeking holistic health and longevity.', 'cee6d45ef77177bae593465957c053d9', 'dani lingga nugraha', 'wow', 'https://x.com/dalinggra/status/2019035691427205176');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://myrepublic-tuk.caffeine.xyz/', 'https://myrepublic-tuk.caffeine.xyz', NULL, 'MyRepublic Network Hub: Partner Platform for Internet Services', 'Professional network marketing platform for MyRepublic internet services. Manage networks, track performance, and earn attractive commissions by promoting high-speed internet packages ranging from 100 Mbps to 1 Gbps.', '42a3287294f7294703208cf89e6ec628', 'joni', 'joni', 'https://x.com/boster070/status/2019037236302590274');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://wellness-program-app-lwo.caffeine.xyz/', 'https://wellness-program-app-lwo.caffeine.xyz', NULL, 'Wellness Journey: Personalized Health & Mindfulness App', 'A comprehensive wellness app that offers personalized health programs, progress tracking, daily reminders, and secure authentication. Designed to support your journey towards improved well-being and mindfulness with intuitive features and goal-oriented support.', '7f85c9bfdd3e37affd151f8691df6c13', 'ratih', 'tesnet', 'https://x.com/MalakaYatre/status/2019039174154915914');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://caesarmediccare-v7l.caffeine.xyz/', 'https://caesarmediccare-v7l.caffeine.xyz', NULL, 'Caesar Homecare: Professional Home Care Services in Jakarta', 'Trusted home healthcare provider offering compassionate nursing care for seniors, post-surgery patients, and newborns. 24/7 certified nurses, personalized services, and affordable packages in Jakarta.', '603c946d5c5839f71f44126c324cce9f', 'caesar', 'off', 'https://x.com/Bilang2024/status/2019040103499473064');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://quitter-r8y.caffeine.xyz', 'https://quitter-r8y.caffeine.xyz', NULL, 'QUITTER: Addiction Recovery App for Personal Empowerment', 'A mobile web app designed to support individuals overcoming addiction. Track progress, resist cravings, and connect anonymously with a supportive community. Offers local data privacy and optional encrypted storage, helping users take control of their recovery journey.', '0a959c5e615fb76c5825b4d74d21f0d5', 'NEEL BHANUSHALI', 'QUITTER', 'https://x.com/myartbar/status/2019045192863781095');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://physiq-zpa.caffeine.xyz/', 'https://physiq-zpa.caffeine.xyz', NULL, 'PhysiQ: Fitness Progress Tracking & Personal Analytics App', 'PhysiQ helps you document and visualize your fitness journey through daily progress photos, workout logging, and interactive analytics. Securely track your transformation with a private, user-friendly platform that turns your fitness goals into measurable progress.', 'e4c06001dc67bbf3036601ba7815cfa6', 'Zander', 'Physiq', 'https://x.com/i/status/2019041512752046309');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://champain-qqk.caffeine.xyz/', 'https://champain-qqk.caffeine.xyz', NULL, 'Champain: Balanced Personal Growth & Habit Tracking', 'A holistic habit-building app that helps you develop sustainable progress across life areas. Track personal growth, build resilience, and maintain balance between health, happiness, and essential life goals without burnout.', '9b6116b1f72cfb4649760bcc95388f99', 'Yuvaraja', 'Champain', 'https://x.com/youwetweet/status/2019049905776669003');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://evolve-os---personal-growth-jxa.caffeine.xyz/', 'https://evolve-os---personal-growth-jxa.caffeine.xyz', NULL, 'EVOLVE OS: Personal Growth Platform for Self-Discovery & Development', 'A digital ecosystem designed to support personal transformation, offering a joyful and secure space for self-reflection, growth tracking, and intentional development. Empowers users to cultivate meaningful progress through intuitive tools and mindful practices.', 'd8cec26a954921610e5d5120aeff9916', 'Shailesh Sethi', 'Evolve OS', 'https://www.instagram.com/reel/DUVrmnLEqCU/?igsh=MWthM29qbHRvNnc2dw==');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://finplangoalinvest-8u1.caffeine.xyz', 'https://finplangoalinvest-8u1.caffeine.xyz', NULL, 'FinancePro: Smart Goal Planning & Investment Tracking App', 'A comprehensive financial management platform that helps you set, track, and visualize your financial goals across short, mid, and long-term horizons. Monitor investment portfolios, gain powerful insights, and take control of your financial future with intuitive analytics and reporting tools.', '44f5138e7046a214f10cc5b0723cf709', 'Ashish Kumar Gupta', 'FinancePro', 'https://x.com/i/status/2019051903502766477');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://bondly-lpl.caffeine.xyz/', 'https://bondly-lpl.caffeine.xyz', NULL, 'Bondly: AI Relationship Coaching for Modern Connections', 'Bondly is an innovative AI-powered relationship coach that provides witty, supportive guidance to help users improve their interpersonal skills and connections. Designed to offer personalized insights and strategies for building stronger, more meaningful relationships.', 'bd14b02cc2619643f4426680739d5288', 'Jahanvi Jariwala', 'Bondly', 'https://www.linkedin.com/posts/jahanvi-jariwala-b226ab3aa_buildinpublic-caffeineai-relationshipgoals-activity-7424439736066494464-iJua?utm_source=share&utm_medium=member_desktop&rcm=ACoAAGQgYooBvcu0Hx1yW5pp9UxeGcvTUGh3phw');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://habit-chain-icp-295.caffeine.xyz/', 'https://habit-chain-icp-295.caffeine.xyz', NULL, 'HabitChain: Collaborative Habit Building Platform', 'HabitChain helps you develop positive habits through community support and accountability. Connect with others, track progress, and transform your lifestyle by building habits together in a motivating, interactive environment.', 'd6d1864667a6cab82a2973fa10208534', 'Tukalu', 'Habit Chain', 'https://x.com/Acut40212407/status/2019058497485164809');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://mirrorme-weekly-summary-feature-with-interactive-w-uvo.caffeine.xyz/', 'https://mirrorme-weekly-summary-feature-with-interactive-w-uvo.caffeine.xyz', NULL, 'MirrorMe: Daily Wellness & Self-Reflection App', 'A mindful digital companion designed to support personal growth through daily inspirations, affirmations, wellness tips, and guided self-reflection. Nurture your mental health, embrace continuous learning, and discover inner wisdom in just a few minutes each day.', 'ae2d76e1e87622bfb0be4df2f51d63c7', 'Sunit solanki', 'MirrorMe', 'https://x.com/i/status/2019048287576449478,https://www.facebook.com/100001291175592/posts/pfbid02MWnnxMW2bSzWaViPqoTXCSYxBmzZNV2zbcq7Kbb7rrsVytsBNDpH3vSNG6NiXFPql/,https://www.linkedin.com/posts/sunit-solanki_promptathon-wellnesstech-mindfulliving-activity-7424804968295133184-fIDY?utm_source=share&utm_medium=member_android&rcm=ACoAAAktyFsBICLrVOdGpmy2ubJkkO7pv03nXZA,https://www.instagram.com/p/DUVpoihDKG1/?igsh=MXJqbTcxY3QxcHh5dA==');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://brutal.services', 'https://brutal.services', NULL, 'BRUTAL: No-Nonsense Sobriety Tracking App', 'A raw, honest mobile app for tracking alcohol reduction and sobriety. Designed for individuals committed to real personal progress, BRUTAL offers daily check-ins, direct accountability, and a no-excuses approach to changing drinking habits.', 'b40c661b4f4b65ff18a81075448156f4', 'Ptica', 'BRUTAL', 'https://x.com/pticaptica2/status/2019172209558974518');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://forge-ai-3ha.caffeine.xyz/', 'https://forge-ai-3ha.caffeine.xyz', NULL, 'Forge AI: Autonomous Agent Marketplace for Outcome-Driven Execution', 'Forge AI is a revolutionary platform where AI agents autonomously orchestrate complex workflows, discover specialized capabilities, and deliver outcomes. Agents coordinate, execute tasks, and settle payments trustlessly, transforming AI from a tool into an intelligent infrastructure that works without constant human intervention.', '6c40e92a93df2c5ceed3e75c158bfd87', 'MonkeyCruise', 'Forge AI', 'https://x.com/forgeai35916/status/2019070270183481545?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://stimuli-vm1.caffeine.xyz/', 'https://stimuli-vm1.caffeine.xyz', NULL, 'Stimuli: Brain Training App for Cognitive Skills Development', 'A cognitive training app featuring daily mental exercises focused on improving focus, memory, and reasoning skills. Offers time visualizer, number pairs, and reverse order challenges at varying difficulty levels to help users enhance their mental performance.', '26dea3e16411ae815fc957e6f03a046b', 'Disha', 'Stimuli', 'https://x.com/tnt_exploder/status/2019060499741102589?s=46&t=ds1GdwB0ntvk4RHWVdEaJw');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://joyrader.xyz', 'https://joyrader.xyz', NULL, 'Joy Rader: Personal Emotional Tracking & Privacy-First Mood Garden', 'A private digital sanctuary for tracking and understanding your emotions. Designed with complete privacy, Joy Rader helps you cultivate self-awareness and notice moments of joy through a unique, personal emotional tracking experience.', 'e411688fdaea8057096e1b165b8ac949', 'Moicp', 'Joy rader', 'https://x.com/novahodler/status/2018970220560572903?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://ictandem-s4t.caffeine.xyz/', 'https://ictandem-s4t.caffeine.xyz', NULL, 'icTandem: Social Accountability App for Personal Growth', 'A collaborative app that supports personal development through shared motivation. Helps users improve habits, nutrition, and exercise by connecting individuals who support each other''s journey, like riders on a tandem bike working together towards their goals.', 'd094b5fe16f3e70c9b20f4eb267b8f73', 'Francisco Hefner', 'icTandem', 'https://x.com/FranHefner/status/2019082422625661216');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://profitly-jck.caffeine.xyz/projects/1/dashboard', 'https://profitly-jck.caffeine.xyz/projects/1/dashboard', NULL, 'Profitly: Financial Tracking & Profit Management Dashboard', 'A comprehensive financial management platform for tracking income, expenses, and team profits. Add transactions, invite team members, and gain real-time insights into your project''s financial performance.', '953e930fb089382055eb8b79634c5d21', 'Salsa Dwi Nur Hidayah', 'Profitly', 'https://x.com/slzyoung/status/2019082349007298824?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://cryptoswish---cryptocurrency-wallet-and-contact-ma-645.caffeine.xyz/', 'https://cryptoswish---cryptocurrency-wallet-and-contact-ma-645.caffeine.xyz', NULL, 'CryptoSwish: Secure Crypto Wallet and Contact Management Platform', 'A comprehensive cryptocurrency management solution offering secure wallet tracking and contact management. Designed for crypto enthusiasts to streamline their digital asset interactions with robust authentication and user-friendly interface.', 'af63c224bd82bec268fb07745683b665', 'Gunnar', 'CryptoSwish', 'https://x.com/i/status/2018655676697129030');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://milano-cortina-2026-retro-games-hub-wg3.caffeine.xyz', 'https://milano-cortina-2026-retro-games-hub-wg3.caffeine.xyz', NULL, 'Milano Cortina 2026 Retro Olympic Arcade Experience', 'A nostalgic digital arcade celebrating the Milano Cortina 2026 Winter Olympics through classic retro gaming. Sign in with Internet Identity to explore vintage Olympic-themed arcade games and interactive experiences.', 'e80bf4588886f2a7acd95afa70887660', 'Carlo', 'Olympic Arcare Games - Milano Cortina 2k26', 'https://x.com/denardincarlo?s=11&t=7wGxqO7tiOxTLjIKz82K4g');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('http://beforeyousleep-hbz.caffeine.xyz', 'https://beforeyousleep-hbz.caffeine.xyz', NULL, 'Before You Sleep: Daily Reflection & Mindfulness App', 'A peaceful nightly ritual that helps you pause and appreciate life''s small, meaningful moments. Write down 3 good things from your day, build a mindful habit, and learn to enjoy the present instead of always waiting for something extraordinary.', '59451d3ad5a3306d9779ec7ae15aade3', 'Haruto', 'Before You Sleep', 'https://x.com/HarutoWang');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://wa-53i.caffeine.xyz', 'https://wa-53i.caffeine.xyz', NULL, 'WA Sender Pro: Bulk WhatsApp Messaging Made Simple', 'Effortlessly send mass WhatsApp messages with advanced contact management, personalized templates, and real-time delivery tracking. Streamline your communication with easy contact input and dynamic message personalization.', '2419ca23dd3ba9b63a812d1898885f1f', 'bos', 'bos', 'https://x.com/academy_hooked/status/2019091587842851285');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lingoflow-4rj.caffeine.xyz/', 'https://lingoflow-4rj.caffeine.xyz', NULL, 'LingoFlow: Smart Language Learning with Pomodoro & Active Recall', 'An innovative language learning platform that combines focused Pomodoro study sessions, active recall techniques, and secure progress tracking. Sync your vocabulary and stats across devices using blockchain technology for an intelligent, personalized learning experience.', 'a446311efc34f9af4f6146686fbbbc24', 'Maria', 'LingoFlow', 'https://x.com/KanaNarai/status/2019093139445735649?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://numinamath-xzz.caffeine.xyz/', 'https://numinamath-xzz.caffeine.xyz', NULL, 'Numina: Personalized Math Learning Platform for Skills Mastery', 'Numina is an interactive math practice dashboard that helps learners track progress, follow personalized learning paths, and systematically master mathematical skills through targeted exercises and adaptive learning.', '58477842949e735e6ddb9d771a620a68', 'Michael Ansell', 'Numina', 'https://x.com/EdUniteDappsICP');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://soph-hbf.caffeine.xyz', 'https://soph-hbf.caffeine.xyz', NULL, 'Three Little Pigs: Interactive Educational Mini Game', 'An engaging digital learning experience that teaches children problem-solving and storytelling through the classic Three Little Pigs tale. Explore interactive gameplay that makes learning fun and memorable.', '3f4b89dcd7547de6a2bc269c4a0c07fd', 'Paulo Moreno', 'Soph', 'https://x.com/psmoreno/status/2019101867440238722?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://sweetsteps-hah.caffeine.xyz/', 'https://sweetsteps-hah.caffeine.xyz', NULL, 'SweetSteps: Gentle Goal Tracking & Motivation Platform', 'A supportive goal-tracking app that helps you make consistent progress without overwhelming pressure. Climb your goals one sweet step at a time, using a compassionate approach to personal development and motivation.', 'd859b24561d4e49d2940ce8fa1226831', 'Shreya V', 'SweetSteps', 'https://x.com/MMeow28001/status/2019107235650252926?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://streaks-without-shame-12k.caffeine.xyz/', 'https://streaks-without-shame-12k.caffeine.xyz', NULL, 'Streaks Without Shame: Compassionate Personal Growth Companion', 'A supportive app that helps you track personal goals without judgment. Focus on gentle progress, compassionate reflection, and honoring your journey. Designed to make self-improvement feel nurturing and kind.', '721ceda3b800bbbbb7a35af7ff693f0f', 'Roberto pagan', 'Rpjr08', 'https://x.com/robertopjr1?s=21');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://duat-e9v.caffeine.xyz/', 'https://duat-e9v.caffeine.xyz', NULL, 'DUAT: AI Privacy Guidance for Digital Identity Challenges', 'An AI assistant designed to help users understand digital identity issues by providing clear, transient explanations about online incidents without storing data or directly intervening in accounts. Privacy-focused guidance to increase user understanding.', '6acd4f3a55428ef1d2064d1d0234ea52', 'zurab', 'DUAT', 'https://x.com/igalixai/status/2019110046056214786?s=46&t=pkBtk-yExZqO_2MsAcDaIQ');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://zyptext-n69.caffeine.xyz/', 'https://zyptext-n69.caffeine.xyz', NULL, 'ZypText: Speed Reading App for Global Scripts', 'A web-based speed reading application that helps users improve reading speed and comprehension. Supports multiple global scripts including Chinese, Arabic, and Cyrillic, with customizable settings for font, speed, and focus guides.', '308e6dcfdf91cae2da2110e4671f746e', 'Michael Guta', 'ZypText', 'https://x.com/mmguta/status/2019117022626418773');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://bottlepost.blog/', 'https://bottlepost.blog', NULL, 'Bottle Post: Creative Writing and Digital Storytelling Blog', 'A unique blog exploring creative writing, storytelling, and digital narratives through the metaphorical lens of messages in bottles. Discover innovative writing perspectives and personal reflections on communication and creativity.', 'aaeb044d11da90344f67f2e2a09338f4', 'Selman Kelmendi', 'Bottle Post', 'https://x.com/future_rain_/status/2019117781552361820?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://17-minutes-6a5.caffeine.xyz/', 'https://17-minutes-6a5.caffeine.xyz', NULL, '17 Minutes: Mindfulness for Busy Parents', 'A mobile app designed specifically for parents, offering quick and accessible mindfulness practices that fit into those precious few spare minutes of the day. Tailored to help parents find calm and mental clarity amidst their hectic schedules.', '7f007c30d174aad93585768be4889218', 'Hafsa Usmani', '17 Minutes', 'https://x.com/HafsaUsmani2/status/2018910240604090443');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://archetype-edition-fr3.caffeine.xyz/', 'https://archetype-edition-fr3.caffeine.xyz', NULL, 'Studio Chladni: Interactive MIDI Generator & Sound Visualization', 'Advanced musical tool featuring real-time Chladni visualization, probabilistic MIDI generation, and synesthetic color-tone mapping. Explore interactive sound harmonics, wave forms, and experimental audio synthesis with intuitive controls.', '71d50c821d7fdadcfa93060936c996a8', 'teixeira', 'Studio Chladni – Archétype Cristal Polishead Edition V80 FINAL', 'https://x.com/gualterianoo/status/2019120432209883400?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://x39-dark---noticias-de-la-dark-web-y-crypto-k35.caffeine.xyz/', 'https://x39-dark---noticias-de-la-dark-web-y-crypto-k35.caffeine.xyz', NULL, 'X39 Matrix: Decentralized Web Protocol on Internet Computer', 'A cutting-edge blockchain protocol that seamlessly connects tokens, data, and communities through a decentralized framework. Built on Internet Computer, X39 Matrix eliminates intermediaries and provides scalable, mathematically-verified interactions with enhanced trust and transparency.', '3d11592a48f16c4198ec794281b2f712', 'x39', 'X39 Matrix', 'https://www.instagram/Olivares_sfc.com');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://why-again-reflect-iiq.caffeine.xyz/', 'https://why-again-reflect-iiq.caffeine.xyz', NULL, 'Why, Again? - Reflective Pattern Analysis Tool', 'A thoughtful web application designed to help users pause, critically examine personal patterns and habits, and make intentional decisions. Encourages self-reflection through guided questioning and mindful analysis of behavioral routines.', '76a24908bc4795f38309282dd54d6c9d', 'Ahmad Ekhlas', 'Why, Again?', 'https://www.linkedin.com/feed/update/urn:li:activity:7423535412201308160/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://managingfriends-l6r.caffeine.xyz/', 'https://managingfriends-l6r.caffeine.xyz', NULL, 'Friends: Easy Event Planning & Group Decision Making', 'A collaborative platform for organizing events, tournaments, and activities with friends. Create polls, vote democratically on locations, manage a shared calendar, and make group decisions simple and transparent.', 'e75c6a0a740e20bda16e2a8f6b3b8c31', 'Alessandro', 'ManagingFriends', 'https://x.com/AleCastaz/status/2019129652128997733?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://innerbloom-6ty.caffeine.xyz/', 'https://innerbloom-6ty.caffeine.xyz', NULL, 'Inner Bloom: Personalized Meditation App for Mindfulness & Calm', 'A holistic meditation app that offers tailored meditation sessions, peaceful sounds, and progress tracking. Designed to help users build a gentle mindfulness habit, reduce stress, and find inner clarity through personalized, intuitive experiences.', 'ef18df6de4bb635e43c594d3353e1ed3', 'Dovydas Šalkevičius', 'Inner Bloom', 'https://x.com/daveDash2000/status/2019130849707385225');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://growthtree-z2y.caffeine.xyz/', 'https://growthtree-z2y.caffeine.xyz', NULL, 'GrowthTree: Personal Development Tracker & Habit Management App', 'A comprehensive personal growth platform that helps users track progress across life areas like health, mind, career, and creativity. Visualize skill development, log habits, set challenges, and build consistent routines through an engaging, gamified interface.', 'd31a2407c8a613150254ebb6c8cd42bf', 'Sidharth Sethi', 'GrowthTree', 'https://x.com/TechSpiritSS/status/2019133048319779222?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://inky-frame-emulator-nt0.caffeine.xyz/', 'https://inky-frame-emulator-nt0.caffeine.xyz', NULL, 'Inky Frame Emulator: Browser-Based E-Ink Display Testing Tool', 'A web-based emulator for Pimoroni Inky Frame that provides accurate 800×480 e-ink display simulation, interactive code sandbox, virtual SD card, and time control features for developers to test and visualize e-ink display projects without physical hardware.', 'af5143e86049d7f1b350b39f740f9b62', 'Goth2Boss', 'Inky frame emulator', 'https://x.com/_Goth2Boss/status/2019080859228233913?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://the-pivot-rif.caffeine.xyz/', 'https://the-pivot-rif.caffeine.xyz', NULL, 'The Pivot: Adaptive Self-Improvement Without Judgment', 'A compassionate personal growth app that dynamically adjusts goals to your daily energy levels. Focuses on sustainable progress through intelligent difficulty scaling, friction mapping, and identity-driven insights—helping you grow at your own pace without guilt or rigid streaks.', '9705d13bd554b163600042a20dec881d', 'John Oloruntobi', 'The Pivot', 'https://vt.tiktok.com/ZSaWS2RxY/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://myappstudysearch-cgq.caffeine.xyz', 'https://myappstudysearch-cgq.caffeine.xyz', NULL, 'AI Study Search: Smart Learning Resource Discovery Platform', 'An intelligent study resource search application that helps students find high-quality learning materials across various subjects. Powered by AI, users can search, upload, and collaborate on study resources while earning points and accessing premium content.', 'e6d123f67db9a7d3cd83345e961050d1', 'Miles Morales', 'StudySearch', 'https://x.com/i/status/1972777263906324943');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://inventory-saas-application-9xy.caffeine.xyz/', 'https://inventory-saas-application-9xy.caffeine.xyz', NULL, 'Inventory Management SaaS - Multi-Location Stock Tracking', 'A comprehensive SaaS solution for seamless inventory management across multiple locations. Track stock levels, enable team collaboration, maintain detailed audit trails, and leverage secure Internet Identity authentication for streamlined business operations.', 'b94dd755465f51fdb56fd020f3484e05', 'Kunal Prashant', 'Inventory SaaS', 'https://x.com/kunalprashant2/status/2019144078991192503?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://rephrase-o7t.caffeine.xyz/', 'https://rephrase-o7t.caffeine.xyz', NULL, 'RePhrase: Transform Complaints into Positive Affirmations', 'A mindfulness app that helps users convert negative thoughts into empowering statements. Track personal growth, receive weekly motivation, and develop a more positive mental approach through guided reframing techniques.', 'b5375e33259de7d9739c614af1827667', 'Paul Lamping', 'RePhrase', 'https://x.com/PabloLim7/status/2019147509655048585?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://superpod.app/', 'https://superpod.app', NULL, 'Superpod: AI Relationship Intelligence App for Deeper Connections', 'Capture and remember important moments with people who matter. Superpod uses AI to help you organize relationships, get personalized insights, track important dates, and build meaningful connections—all while keeping your data completely private and secure.', 'e80b4f901b9d71b3d431827f9587a9d9', 'Marco Näf', 'Superpod', 'https://x.com/naefdigital/status/2019150113420243144?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://prep-v3j.caffeine.xyz/', 'https://prep-v3j.caffeine.xyz', NULL, 'PREP: AI-Powered Smart Study Assistant for Personalized Quizzes', 'Transform your study materials into effective practice quizzes with PREP. Upload lecture notes, PDFs, and documents to generate personalized quizzes, get instant feedback, and track your exam readiness in just three simple steps.', 'e36b9bdd28455606011159571f1705e9', 'Deborah Emily', 'PREP', 'https://vt.tiktok.com/ZSaWyLQnP/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://moments-fyn.caffeine.xyz/', 'https://moments-fyn.caffeine.xyz', NULL, 'Moments: Capture and Share Life''s Meaningful Snapshots', 'A digital platform designed to help users create and preserve genuine memories, moving beyond mindless social media scrolling. Moments encourages intentional, meaningful photo sharing and connection.', '7edbf181ccbdb9770f3e4c00e8d1c84f', 'Janice Gathoga', 'MOMENTS', 'https://x.com/JaniceGathoga/status/2019161283875205182?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://carfix-huj.caffeine.xyz/', 'https://carfix-huj.caffeine.xyz', NULL, 'AutoMaintain: DIY Vehicle Maintenance Made Easy', 'A comprehensive app that helps car owners perform their own maintenance by providing vehicle-specific parts information and detailed step-by-step repair guides. Simply enter your car''s details to access personalized maintenance instructions and compatible part recommendations.', 'f1bf0786abaef50193619cffd1d524b9', 'Sean johnson', 'Car fix', 'https://x.com/SquiddVicious/status/2019168695885656164?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://aura-llu.caffeine.xyz', 'https://aura-llu.caffeine.xyz', NULL, 'Aura: Personal Knowledge Curation Platform', 'A secure web application that serves as a personal gateway for discovering, organizing, and accessing curated knowledge and daily insights. Powered by Internet Identity for seamless and secure authentication on the Internet Computer.', '38b731a5462fac3db9241054b91c348f', 'Peter Kazadi', 'aura', 'https://https:www.facebook.com/reel/3167146663458162/?app=fbl');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://thrive-pp7.caffeine.xyz', 'https://thrive-pp7.caffeine.xyz', NULL, 'Thrive: Personal Growth & Habit Tracking Platform', 'A secure, private platform designed to support personal development through daily habit tracking, mindful check-ins, and guided progress sessions. Empower your growth journey with personalized tools that help you build lasting positive change.', '60077defa926353533e031f9c1bd7d03', 'Subin S K', 'Thrive', 'https://x.com/subinspacesk/status/2019172108191035470?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://thrive-ai---your-personal-growth-companion-9dh.caffeine.xyz/', 'https://thrive-ai---your-personal-growth-companion-9dh.caffeine.xyz', NULL, 'Thrive AI: Your Personal Growth and Development Companion', 'Thrive AI is an innovative personal growth platform that offers secure, personalized development tools powered by Internet Computer authentication. Designed to help users enhance their potential through intelligent, adaptive guidance and support.', '98f28aa08effcce90ab45b4bbeec1943', 'Speed', 'Thrive AI', 'https://x.com/Big_Timmm/status/2019173204976152697?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://peepal-1ot.caffeine.xyz/', 'https://peepal-1ot.caffeine.xyz', NULL, 'PeePal: Your Personal Hydration Tracking Companion', 'PeePal is a quick and easy hydration tracking app that helps you understand your body''s hydration needs. Simply check your urine color and get personalized water intake recommendations in under a minute, with optional history tracking.', '9e9bdff6bcf07cc99a48ba492ef8d6e7', 'Bartolomej Vesely', 'PeePal', 'https://x.com/bartholomewve/status/2019177159865111020?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://zeal-4s6.caffeine.xyz/', 'https://zeal-4s6.caffeine.xyz', NULL, 'Zeal: Energy Balance and Burnout Prevention App', 'Zeal helps you track and manage your personal energy levels by identifying life''s energy drainers and chargers. Monitor your emotional and mental well-being to prevent burnout and find a sustainable balance in work and life.', '57f72b6ab432bb93afbe4712eb998c32', 'KevLoui', 'Zeal', 'https://x.com/majesticloui/status/2019178069286932622?s=46&t=1vKY6R0FYADl7T6o9UFBEQ');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://brave-echo-qog.caffeine.xyz/', 'https://brave-echo-qog.caffeine.xyz', NULL, 'Brave Echo: Personal Growth & Daily Challenge Tracking App', 'Brave Echo helps you build courage and personal resilience through daily challenges. Track progress, connect with friends, and customize tasks to progressively develop strength and confidence in small, meaningful steps.', 'd305d4e4b608c8a9736c87fea768cd2e', 'Anna', 'Brave Echo', 'https://x.com/patrikvonsponge/status/2019180488284324172?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://painto-41c.caffeine.xyz', 'https://painto-41c.caffeine.xyz', NULL, 'Painto: Community-Driven Digital Art Platform for Mobile & Tablet', 'A creative drawing app that enables users to create, share, and explore digital artwork. Sign in to access a collaborative canvas, showcase your paintings, and engage with a vibrant artistic community through ratings and shared inspiration.', '6d5a856d8c44e6611fcf0d13f84593fe', 'Ra.icp', 'Painto', 'https://x.com/i/status/2019181891484541330');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://brainsnacks-9nf.caffeine.xyz/', 'https://brainsnacks-9nf.caffeine.xyz', NULL, 'BrainSnacks: 5-Minute Micro-Learning for Busy Minds', 'Transform your daily routine with bite-sized learning sessions. Master skills in psychology, productivity, and leadership in just 5 minutes a day. Earn XP, track progress, and compete globally while building knowledge effortlessly.', '990e9463e4df3cc49e3518820472876c', 'Cryptomax', 'BrainSnacks', 'https://x.com/crypt0max_/status/2019182451713560907?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://closecircle-9fc.caffeine.xyz/', 'https://closecircle-9fc.caffeine.xyz', NULL, 'CloseCircle: Private Emotional Sharing for Your Closest Connections', 'An invite-only social app that lets you share personal emotional statuses exclusively with your most trusted circle. Control what you share, when you share, and maintain privacy in a secure, intimate digital space.', '5a3a0b92809c0188873edc833e5ac61c', 'Abel Odoh', 'CloseCircle', 'https://x.com/AbelOdoh_IC/status/2019182748330598637');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://dailyos-ojs.caffeine.xyz/', 'https://dailyos-ojs.caffeine.xyz', NULL, 'DailyOS: Personal Productivity & Life Tracking Dashboard', 'A comprehensive personal tracking app that helps users manage daily activities, finances, habits, and journal entries through an intuitive calendar-based interface. Organize your life with a clean, customizable dashboard for holistic personal management.', '80039448c35a029ac544b818fcfa063f', 'Amioo', 'DailyOS', 'https://x.com/amioo1x/status/2019189174579155008');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://empathy-buddy-m74.caffeine.xyz', 'https://empathy-buddy-m74.caffeine.xyz', NULL, 'Empathy Buddy: Anonymous Voice Connection & Compassionate Listening Platform', 'A digital sanctuary for anonymous voice expression where individuals can share their thoughts and feelings through recordings, and connect with empathetic listeners. A supportive space that validates personal experiences and promotes emotional understanding across diverse voices.', '5ec1f5740e1b8151e253cbd4c7ce253d', 'Marton Kabai', 'Empathy Buddy', 'https://x.com/martonkabai');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://achievable-r00.caffeine.xyz/', 'https://achievable-r00.caffeine.xyz', NULL, 'Achievable: Flexible Progress Tracking for Goal-Oriented Individuals', 'A customizable task management platform that allows users to create tasks with flexible progress tracking, subtasks, and the ability to exceed 100% completion. Designed for motivated individuals who want a personalized way to monitor and achieve their goals.', '14dc30713e4cd5446d34f133cf30d76f', 'Peter', 'Achievable', 'https://x.com/waxyactivity/status/2019194931630559604');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://healthy-wealthy-n-wise-zja.caffeine.xyz/', 'https://healthy-wealthy-n-wise-zja.caffeine.xyz', NULL, 'Nutrition Planner: Personalized Meal Tracking & Planning App', 'A comprehensive meal planning tool that helps you track daily nutrition, create custom meal plans, and manage your weekly diet with an easy-to-use interface. Supports healthy eating habits through personalized food tracking and planning.', 'cf52581fdef51c29582a9754d8a71eda', 'Michael', 'Healthy Wealthy & Wise', 'https://x.com/i/status/2019194983010693467');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://jlpt-5nf.caffeine.xyz/', 'https://jlpt-5nf.caffeine.xyz', NULL, 'JLPT Study Pods: Collaborative Japanese Language Learning', 'Join a supportive community of Japanese learners with JLPT Study Pods. Set daily goals, track progress, and stay accountable in small groups. Build language skills consistently with collaborative learning and achievement tracking.', '602c5d1a13d715f8d4c910b284c4742c', 'Jonathan Salatan', 'JLPT PODS', 'https://x.com/eastmaels/status/2019203962063495293?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://goal-rpg-63v.caffeine.xyz/', 'https://goal-rpg-63v.caffeine.xyz', NULL, 'Goal RPG: Gamify Your Personal Development Journey', 'An innovative RPG-style app that transforms personal goals and habits into an engaging game experience. Level up your life by completing tasks, earning experience, and tracking progress through an immersive, motivational gameplay interface.', '9e0009d347c22b9df4bef08f851947ab', 'Josh', 'Goal RPG', 'https://x.com/bbb_capital/status/2019204382374690835?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://lo-fi-y7v.caffeine.xyz/', 'https://lo-fi-y7v.caffeine.xyz', NULL, 'LI.FM: Lo-Fi Music for Focus & Productivity', 'A minimalist online radio station streaming lo-fi beats designed to help you concentrate, reduce distractions, and support your workflow. Create a calm, productive environment with soothing background music tailored for studying, working, or relaxing.', 'e184bfefd807ffa78433ba0ec43cd12b', 'Mazen Zibara', 'lo-fi.fm', 'https://x.com/mazen_zibara/status/2019205725302730966?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://progress-pulse-lqw.caffeine.xyz/', 'https://progress-pulse-lqw.caffeine.xyz', NULL, 'Progress Pulse: Your Personal Weight Loss Tracking & Motivation Platform', 'Progress Pulse helps you transform your fitness journey with comprehensive weight tracking, progress photo uploads, smart analytics, and a supportive community. Log measurements, visualize progress, and stay motivated with personalized daily insights and premium member support.', '104366258ec005e92a1fb9eb31f5c66f', 'Richard Hery', 'Progress Pulse', 'https://x.com/richard_hery/status/2019205422843109691?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://zenbreathe-c21.caffeine.xyz/', 'https://zenbreathe-c21.caffeine.xyz', NULL, 'ZenBreathe: Guided Breathing Techniques for Relaxation and Wellness', 'A comprehensive breathing exercise app featuring techniques like Equal Breathing, Box Breathing, and 4-7-8 Deep Sleep method. Designed to help users reduce stress, improve focus, and enhance overall mental well-being through scientifically-backed breathing practices.', '5721d6309fe3d156e882a45dab4fb8b7', 'Saumo Pal', 'ZenBreathe', 'https://x.com/PalSaumo/status/2019212259483086977');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://levelly-mxr.caffeine.xyz/', 'https://levelly-mxr.caffeine.xyz', NULL, 'Levelly: Next-Generation Digital Platform', 'Levelly appears to be an emerging digital platform with a login-based access system. The site suggests a modern web application focused on user authentication and potentially offering personalized digital experiences.', 'a22cbcb3dd1c43b28f77b5d834e9ce2c', 'Ayodeji Odeyale', 'Levelly', 'https://x.com/i/status/2019214316172640434');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://routinelyapp-yny.caffeine.xyz/', 'https://routinelyapp-yny.caffeine.xyz', NULL, 'Routinely: Craft Your Perfect Daily Routine with Confidence', 'Routinely helps you build and track your ideal daily routine with ease. Add tasks from a curated library, monitor your progress, and develop positive habits at your own pace. Designed to support your personal growth with patience and encouragement.', '888cde766d8cabc19c0aba1bff46b5ac', 'Domika Feliz', 'Routinely App', 'https://x.com/metagalaxy28289/status/2019224196308693329?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://firelight-07x.caffeine.xyz', 'https://firelight-07x.caffeine.xyz', NULL, 'Firelight Journal: Private, Encrypted Digital Journaling', 'A secure journaling app that protects your personal thoughts through end-to-end encryption, anonymous login, and Zettelkasten-style idea linking. Powered by Internet Computer for ultimate privacy and seamless thought organization.', '58f0d8ae57e0aca9e9a425252e0cecf0', 'Harshil Dave', 'Firelight Journal', 'https://x.com/hersheylasers/status/2019225967172968573');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://hire-lukas-2va.caffeine.xyz/', 'https://hire-lukas-2va.caffeine.xyz', NULL, 'Lukas Zieba: Freelance Designer for Professional Branding & Visual Identity', 'Experienced graphic designer with 20+ years of expertise, helping businesses create professional and consistent visual identities. Specializing in logotype design, graphic design, and production-ready visuals for brands like Coca-Cola, Klarna, and Telenor.', '86960aed591d75a0104337467594505f', 'Lukas Zieba', 'Hire Lukas', 'https://www.linkedin.com/posts/lukaszieba_hire-lukas-activity-7424992293700620288-404P?utm_source=share&utm_medium=member_desktop&rcm=ACoAAAIiZYsBymthyc374puY63vsTSWoUj89OLY');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://90-minute-launch-4tn.caffeine.xyz', 'https://90-minute-launch-4tn.caffeine.xyz', NULL, '90-Minute Launch: Transform Your Mornings with Focused Deep Work', 'A structured morning routine designed to help you build lasting habits through a strategic 90-minute session. Includes setup, deep work, and closing phases to maximize productivity and personal growth.', '3fe49697dd16d1990620133d450d3e2e', 'Vibelawyer', '90-minute-launch', 'https://x.com/vibelawyer/status/2019231167904641278?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://pebble-dpv.caffeine.xyz/', 'https://pebble-dpv.caffeine.xyz', NULL, 'Pebble: Intentional Productivity Modes for Focus & Creativity', 'A digital workspace that helps you optimize your mental state through six purposeful modes: Reading, Coding, Creativity, Deep Work, Organization, and Reflection. Choose your intention and enter a distraction-free environment designed to enhance your workflow and personal growth.', 'b5f5d3a7ebf54eca86f5a1d19d70c7e1', 'Agus Pamungkas', 'Pebble Focus App', 'https://x.com/Agus_pamungkasS/status/2019227344305811467');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://barnabus-the-undeterred-fcm.caffeine.xyz/', 'https://barnabus-the-undeterred-fcm.caffeine.xyz', NULL, 'Barnabus the Undeterred: A Heroic Journey of Resilience', 'Explore the inspiring story of Barnabus, a character defined by unwavering determination and courage. This showcase reveals a hero who faces challenges head-on, embodying the spirit of perseverance and hope in the face of adversity.', '9d3156d81e586a063507c49959654079', 'Pauls', 'Barnabus, The Undeterred', 'https://www.linkedin.com/feed/update/urn:li:activity:7424981386580824064');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://unstick-hio.caffeine.xyz/', 'https://unstick-hio.caffeine.xyz', NULL, 'Unstick: Mindful Thought Management & Mental Clarity App', 'A private digital space designed to help you offload mental obligations, capture fleeting thoughts, and gain clarity. Unstick allows you to track and monitor your mental energy without overwhelming your mind, offering a secure and mindful approach to personal thought management.', '1b21beeac73e5fd9d83ea40085e820f3', 'Darlyze Calixte', 'Unstick', 'https://x.com/darveloper/status/2019239124738388054?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://7-day-screen-time-challenge-a8u.caffeine.xyz/', 'https://7-day-screen-time-challenge-a8u.caffeine.xyz', NULL, '7-Day Screen Time Challenge: Social Contemplation Webapp', 'An interactive web application designed to help users track and reflect on their screen time habits over a 7-day period. Features include theme toggling, international login, challenge tracking, invite codes, and an audio tab for enhanced user engagement.', 'edec2d3fdc7776695810ae088ca573e9', 'Wouter van Dam', '7-Day Screen Challenge App', 'https://www.linkedin.com/posts/activity-7425006543986700289-GraX?utm_source=share&utm_medium=member_desktop&rcm=ACoAAAHdmR4BBkf2_kOEGvUXhXL-ep3nf3WfTyU');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://kaidatadiet-44g.caffeine.xyz/', 'https://kaidatadiet-44g.caffeine.xyz', NULL, 'KaiDataDiet+: Anonymous Digital Privacy Analysis Tool', 'A privacy-focused web tool that helps users analyze their digital data exposure anonymously. Sign in without registration, get transparent insights about your data, and maintain complete privacy with pseudonymized, session-based analysis.', '00c78a12e7ebc0020b1b1a22a279f344', 'PixelPaxi', 'KaiDataDiet+', 'https://www.instagram.com/p/DUXCR05ija0/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://make-it-make-sense-tgr.caffeine.xyz/', 'https://make-it-make-sense-tgr.caffeine.xyz', NULL, 'Make It Make Sense: Transforming Complex Ideas into Clear Insights', 'An innovative platform designed to help users break down complex concepts, enhance understanding, and generate clarity through advanced cognitive processing. Simplify information, improve comprehension, and make sense of challenging ideas with intuitive tools.', '72c95c59019e497f73ca28605892afc4', 'Amer R Amer', 'Make It Make Sense', 'https://www.tiktok.com/@shakaleita/video/7603171018068315414');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://growth-1x5.caffeine.xyz/', 'https://growth-1x5.caffeine.xyz', NULL, 'Growth: Personal Development Social Network for Habit Tracking', 'A social platform that helps you create custom processes, track personal progress, connect with supportive peers, and stay motivated through gentle nudges and comprehensive stats. Build better habits and learn continuously with personalized tracking and community encouragement.', '285ff6b73565f71119b33de6db3d2f5d', 'Ricardo Leon', 'Growth', 'https://twitter.com/ponshupuesto/status/2019244925628609017');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://one-small-win---privacy-focused-daily-reflection-a-hdw.caffeine.xyz/', 'https://one-small-win---privacy-focused-daily-reflection-a-hdw.caffeine.xyz', NULL, 'One Small Win: Private Daily Reflection & Personal Growth Journal', 'A privacy-focused mobile app designed to help you track and celebrate your daily achievements. Capture personal wins, reflect mindfully, and build positive momentum through a secure, minimalist journaling experience.', '61d3fc88e15ce5680985216e98b4e837', 'Krishna Kumar Mahato', 'One Small Win', 'https://www.instagram.com/reel/DUXE9jJjdvs/?utm_source=ig_web_copy_link&igsh=MzRlODBiNWFlZA==');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://iching-vhr.caffeine.xyz', 'https://iching-vhr.caffeine.xyz', NULL, 'I Ching Oracle: Ancient Chinese Divination Tool', 'An interactive digital platform for exploring the ancient Chinese divination text, the I Ching (Book of Changes). Draw hexagrams and receive traditional wisdom and philosophical guidance through a modern, user-friendly interface.', 'd8b812e483d2759a6509c19368f34f7f', 'Nick Altemeyer', 'Yì Jīng — The Book of Changes', 'https://x.com/opentosuggest/status/2019247698788958366?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://growinlove-yaq.caffeine.xyz/', 'https://growinlove-yaq.caffeine.xyz', NULL, 'GrowInLove: Deepen Your Relationship with Daily Connection', 'A private, thoughtful app designed by a couple to help partners strengthen their bond through daily rituals, love language insights, and meaningful shared moments. Track your relationship growth with personalized prompts and a shared digital scrapbook.', '72e96d0c476d0ad21fafff0e5d93c2c5', 'Dan & Sarah', 'GrowInLove', 'https://x.com/LazyDogeLovers/status/2019250785964675198');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://open-shelf-te4.caffeine.xyz', 'https://open-shelf-te4.caffeine.xyz', NULL, 'Open Shelf: A Mindful Personal Reading Companion', 'Open Shelf is a serene digital library platform that helps you curate, track, and reflect on your reading journey. Save meaningful books, monitor your progress at your own pace, and discover new reads without overwhelming pressure.', '180542d263837be5fb58c122e2074636', 'Bruise', 'Open Shelf', 'https://x.com/i/status/2019254102333747259');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://suchefs.com', 'https://suchefs.com', NULL, 'SuChefs: Smart Meal Planning & Recipe Tracking App', 'A comprehensive food companion app that helps you discover recipes, track meals, plan groceries, and connect with a community of food enthusiasts. Set goals, manage your budget, and simplify your culinary journey.', '688c01a49e413681c4351076895bba3f', 'Alex Racine', 'SuChefs', 'https://x.com/i/status/2019250268278321443');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://jiu-jitsu-journey-lud.caffeine.xyz/', 'https://jiu-jitsu-journey-lud.caffeine.xyz', NULL, 'Jiu-Jitsu Journey: Your Personal BJJ Training Companion', 'A comprehensive web app for Brazilian Jiu-Jitsu practitioners to track training sessions, monitor belt progression, and build a personal technique library. Securely authenticated using Internet Identity blockchain technology.', '699b528bba75509e0fd91dc533a8689a', 'Darren Fitzpatrick', 'Jiu-Jitsu Journey', 'https://x.com/_DarrenFitz_/status/2019255797432340523');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://axeboredom.com', 'https://axeboredom.com', NULL, 'Axe Boredom: Daily Challenges & Community Motivation', 'Break free from monotony with Axe Boredom''s interactive platform. Engage in daily challenges across 6 categories, submit proof of your achievements, build streaks, and climb the leaderboard while connecting with a vibrant community of motivated individuals.', '1af1556a441bef817c91ea0c50737688', 'Daniel Hays', 'Axe Boredom', 'https://x.com/axeboredom/status/2019259996798857495?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://recharge-ai-9eo.caffeine.xyz/', 'https://recharge-ai-9eo.caffeine.xyz', NULL, 'Recharge.ai: Optimize Your Daily Energy and Productivity', 'A personal energy tracking platform that helps you monitor your daily battery levels, manage tasks strategically, and build consistent productivity by understanding your unique energy patterns and optimizing work accordingly.', 'c3c2f547f8772f5bef0dca362f785df9', 'Thea Anil', 'Recharge.ai', 'https://x.com/thea_anil/status/2019266156587012316?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://recode-identity-ev7.caffeine.xyz', 'https://recode-identity-ev7.caffeine.xyz', NULL, 'RECODE Identity Engine: Personal Transformation & Protection Protocol', 'An innovative personal development platform focused on identity evolution. Explore how you can protect and transform your authentic self, moving beyond old safety patterns to unlock personal growth and potential.', '4b54abd149f50d94d8c090f03d641a3e', 'Tasfia Chowdhury', 'RECODE', 'https://x.com/tasfuu19/status/2019266186698166672?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://serenity-gs6.caffeine.xyz/', 'https://serenity-gs6.caffeine.xyz', NULL, 'Serenity: AI-Powered Mental Wellness App for Personal Growth', 'A comprehensive mental wellness platform leveraging AI to support users through breathwork, positive affirmations, cognitive reframing, compassion practices, and habit development. Designed to guide individuals on a personalized journey of mental health and self-improvement.', 'eaa542e34ace68357db469606156d549', 'Varun Jain', 'Serenity', 'https://x.com/varunx_coach/status/2019081452411830661');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://my-goals-2026-k29.caffeine.xyz/', 'https://my-goals-2026-k29.caffeine.xyz', NULL, 'My Goals 2026: Personal and Professional Growth Roadmap', 'A comprehensive goal-tracking platform covering career, personal development, job, family, finance, health, and academic aspirations. Organize, track, and achieve life goals across multiple domains with clear monthly planning and progress tracking.', 'e4f6aaf9afde92aff1aabcdc3fc263b2', 'Flor Campos Flores', 'Dynamic Goal tracker', 'https://x.com/campos_flores/status/2019246447112438045');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://humanmarket-j8u.caffeine.xyz/', 'https://humanmarket-j8u.caffeine.xyz', NULL, 'HumanMarket: Decentralized Skill Exchange for the Automation Era', 'A blockchain-powered platform enabling direct peer-to-peer skill trading without money or middlemen. Connect with professionals, exchange services, and build economic resilience as automation transforms traditional work models.', '2957cf60eab62598ce167465141d434c', 'Jose Pedro', 'HumanMarket', 'https://x.com/imnotabot2026/status/2019270795995091196?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://focus-flow-cuv.caffeine.xyz', 'https://focus-flow-cuv.caffeine.xyz', NULL, 'Focus Flow: Productivity Timer & Session Tracking', 'A web-based Pomodoro timer that helps you stay focused with structured 25-minute work sessions and 5-minute breaks. Track your productivity, analyze work patterns, and optimize your daily workflow with intuitive session management and energy tracking.', '7b8acbeb5314119e04bd02f857833682', 'I Gede Surya Diva Ananda', 'Focus Flow', 'https://www.linkedin.com/posts/divaananda_caffeineai-promptathon-icp-activity-7425036337696722944-1MNB');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://dearme-yen.caffeine.xyz/', 'https://dearme-yen.caffeine.xyz', NULL, 'DearMe: AI-Powered Self-Improvement Journal & Action Planner', 'A minimalist digital journaling platform that helps you reflect on your thoughts and transform insights into actionable steps. Combine personal writing with AI-driven planning to track progress, set goals, and enhance personal growth.', 'f1e6764a83e76bc7b29102e9996098ac', 'Vikas Sabbi', 'DearMe', 'https://x.com/vikassabbi/status/2019270180849979617?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://assetsbag-s73.caffeine.xyz/', 'https://assetsbag-s73.caffeine.xyz', NULL, 'AssetsBag: Multi-Asset Portfolio Tracking with Blockchain Security', 'A comprehensive investment tracking platform that monitors portfolios across cryptocurrencies, stocks, commodities, and fiat currencies. Powered by Internet Computer Protocol, AssetsBag offers secure, privacy-first portfolio management with institutional-grade data and decentralized authentication.', '495d3948292da3beaa2c59411e6f5ddf', 'Vitalii', 'AssetsBag.com', 'https://x.com/assetsbagx/status/2019271407020941796?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://perfectday-w7q.caffeine.xyz/', 'https://perfectday-w7q.caffeine.xyz', NULL, 'PerfectDay: Precision Time Blocking & Schedule Optimization', 'PerfectDay helps you craft an ideal daily schedule with minute-precise time blocking. Securely authenticate and take control of your time management, creating a personalized productivity blueprint that maximizes your day''s potential.', 'bb90a4fbf66fefc249a667f3687a8837', 'Michael Aronsohn', 'PerfectDay', 'https://x.com/IWannaLambo/status/2019272018580107585');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://between-strangers-hza.caffeine.xyz/', 'https://between-strangers-hza.caffeine.xyz', NULL, 'Between Strangers: Anonymous Digital Message Exchange', 'A unique online platform where users can anonymously cast messages into a virtual ocean, sharing thoughts, secrets, and reflections with unknown strangers. Connect through cryptic, ephemeral communication without revealing personal identity.', '761eb88fd00fc1d8ef328c6406bf7b4b', 'Juan Z', 'Between strangers', 'https://x.com/zforever97/status/2019271585824211375?s=46');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://motive-verse-focus-app-l57.caffeine.xyz/', 'https://motive-verse-focus-app-l57.caffeine.xyz', NULL, 'Motive Verse: Productivity Focus App with Timer & Goal Tracking', 'A minimalist focus app designed to help users set and achieve productivity goals through customizable timers, theme options, and a clean interface. Supports focused work sessions with countdown timer, goal setting, and simple task management.', '19cfbebf9236e63d9d2d1ced2d24c8dc', 'Asra Shaheen', 'Motive Verse', 'https://x.com/LeoMark90/status/2019272462450495600?s=20');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://softspace-hg6.caffeine.xyz/', 'https://softspace-hg6.caffeine.xyz', NULL, 'Softspace: A Neurodivergent-Friendly Life Management Dashboard', 'A gentle, customizable digital space designed for neurodivergent individuals to manage their life with comfort and accessibility. Choose from soothing themes like Peach Calm, Ocean, Forest, Night, and High Contrast to create a personalized, supportive dashboard experience.', '15bc5b328d5255b3c549be436ca10ba6', 'Jess', 'Softspace', 'https://x.com/scattercloud_/status/2019272479739638266?s=46&t=VVjUS6hq5zHkxZnIyqfSQA');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://future-consequences-mqf.caffeine.xyz/', 'https://future-consequences-mqf.caffeine.xyz', NULL, 'Future Consequences: Personal Progress Tracking & Life Projection App', 'A powerful personal development tool that helps you track daily progress, define success metrics, and visualize potential future outcomes based on your current behavior patterns. Set goals, submit check-ins, and gain insights into how your actions today shape your tomorrow.', '86d255ecd7862b279c73d28166fb47b4', 'Simiyon Vinscent Samuel L', 'Future consequence', 'https://www.linkedin.com/posts/simiyonvinscentsamuel_most-people-in-tech-obsess-over-what-they-activity-7425037816583114753-GvaZ/');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://rippl-osf.caffeine.xyz/', 'https://rippl-osf.caffeine.xyz', NULL, 'Rippl: Mindful Water Awareness App for Daily Reflection', 'Rippl is a gentle digital companion designed to help users cultivate a more conscious and intentional relationship with water. Through daily prompts and reflective moments, the app encourages mindfulness about water usage, conservation, and personal connection to this essential resource.', '7c2595fc48db5baf0658ff092933ef64', 'Vijayaraaghavan K S', 'Rippl', 'https://www.linkedin.com/posts/k-s-vijayaraaghavan_buildinpublic-uxdesign-behaviorchange-share-7425038931232075776-G-SV?utm_source=share&utm_medium=member_desktop&rcm=ACoAAFKlFKEB7C8Im3o41AWKkbABUrG-QaGqllM');
+INSERT INTO app (url, canonical_url, canister_id, title, description, image_id, author_name, app_name, social_post_url) VALUES ('https://secretkeeper-i82.caffeine.xyz/', 'https://secretkeeper-i82.caffeine.xyz', NULL, 'Secret Keeper: Secure Personal Vault for Encrypted Secrets', 'A confidential digital vault that allows users to securely store and manage sensitive information using Internet Identity authentication. Protect your most private data with advanced encryption and decentralized storage.', '3600ef765e0655798bbce9c8c85e6e49', 'aasim rasheed', 'secret keeper', 'https://x.com/inkisawesom3/status/2019274397723283853');
@@ -0,0 +1,107 @@
+use serde::Serialize;
+
+use crate::app::app_types::App;
+use crate::{config, datetime, escape, ssr};
+
+/// Registered in the shared XML environment, see [`ssr::render_xml`].
+pub(crate) const TEMPLATES: &[(&str, &str)] = &[
+    ("sitemap.xml", include_str!("includes/sitemap.xml")),
+    (
+        "sitemap_index.xml",
+        include_str!("includes/sitemap_index.xml"),
+    ),
+];
+
+/// Maximum number of URLs a single sitemap file may list, per the
+/// sitemaps.org protocol. Above this, `/sitemap.xml` becomes a sitemap index
+/// pointing at `/sitemap/<n>.xml` pages.
+pub const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+#[derive(Serialize)]
+struct UrlEntry {
+    loc: String,
+    lastmod: Option<String>,
+    images: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SitemapEntry {
+    loc: String,
+    lastmod: Option<String>,
+}
+
+/// Render `/sitemap.xml` for the given apps.
+///
+/// Returns a plain `<urlset>` while all URLs fit in one sitemap, otherwise a
+/// `<sitemapindex>` listing the paginated sitemaps.
+pub fn render(apps: &[App]) -> Result<String, String> {
+    let entries = url_entries(apps);
+    if entries.len() <= MAX_URLS_PER_SITEMAP {
+        return render_urlset(&entries);
+    }
+
+    let origin = config::canonical_origin();
+    let sitemaps: Vec<SitemapEntry> = entries
+        .chunks(MAX_URLS_PER_SITEMAP)
+        .enumerate()
+        .map(|(i, chunk)| SitemapEntry {
+            loc: escape::xml(&format!("{origin}/sitemap/{}.xml", i + 1)),
+            lastmod: chunk.iter().filter_map(|e| e.lastmod.clone()).max(),
+        })
+        .collect();
+
+    ssr::render_xml(
+        "sitemap_index.xml",
+        &minijinja::context! { sitemaps => sitemaps },
+    )
+}
+
+/// Render page `page` (1-based) of a split sitemap.
+///
+/// Returns `Ok(None)` if the page does not exist.
+pub fn render_page(apps: &[App], page: usize) -> Result<Option<String>, String> {
+    let entries = url_entries(apps);
+    match page
+        .checked_sub(1)
+        .and_then(|i| entries.chunks(MAX_URLS_PER_SITEMAP).nth(i))
+    {
+        Some(chunk) => render_urlset(chunk).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Build the sitemap URL entries: the home page followed by every app page.
+fn url_entries(apps: &[App]) -> Vec<UrlEntry> {
+    let origin = config::canonical_origin();
+
+    let mut entries = Vec::with_capacity(apps.len() + 1);
+    entries.push(UrlEntry {
+        loc: escape::xml(&format!("{origin}/")),
+        lastmod: apps
+            .iter()
+            .map(|app| app.updated_at)
+            .max()
+            .map(datetime::format_rfc3339),
+        images: vec![],
+    });
+
+    for app in apps {
+        let images = app
+            .image_id
+            .iter()
+            .map(|image_id| escape::xml(&format!("{origin}/images/{image_id}_1500.jpg")))
+            .collect();
+        entries.push(UrlEntry {
+            loc: escape::xml(&format!("{origin}/app/{}", app.id)),
+            lastmod: Some(datetime::format_rfc3339(app.updated_at)),
+            images,
+        });
+    }
+
+    entries
+}
+
+fn render_urlset(entries: &[UrlEntry]) -> Result<String, String> {
+    // Values are XML-escaped when the entries are built
+    ssr::render_xml("sitemap.xml", &minijinja::context! { urls => entries })
+}
@@ -0,0 +1,369 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use ic_asset_router::{HttpResponse, StatusCode};
+use minijinja::{AutoEscape, Environment, ErrorKind, Value};
+use serde::Serialize;
+
+use crate::app::app_types::App;
+use crate::escape;
+use crate::frontend;
+use crate::page_meta::PageMeta;
+use crate::{feed, oembed, ogimage, sitemap, stats};
+
+/// The Vite-built `index.html`, used as the shell for every server-rendered
+/// page unless a newer build has been uploaded.
+static SHELL_TEMPLATE: &str = include_str!("../../../dist/index.html");
+
+/// Served as-is when rendering fails, so it cannot fail itself.
+static ERROR_PAGE: &str = include_str!("includes/error.html");
+
+static TEMPLATES: &[(&str, &str)] = &[
+    ("index.html", SHELL_TEMPLATE),
+    ("home.html", include_str!("includes/home.html")),
+    ("search.html", include_str!("includes/search.html")),
+    ("author.html", include_str!("includes/author.html")),
+    ("app_detail.html", include_str!("includes/app_detail.html")),
+    ("app_card.html", include_str!("includes/app_card.html")),
+    (
+        "search_form.html",
+        include_str!("includes/search_form.html"),
+    ),
+    ("embed_card.html", oembed::EMBED_CARD_TEMPLATE),
+];
+
+thread_local! {
+    /// Parsed on first render, and again after a new `index.html` is uploaded.
+    static ENVIRONMENT: RefCell<Option<Rc<Environment<'static>>>> = const { RefCell::new(None) };
+
+    /// Parsed on first render; the XML templates are only ever compiled in.
+    static XML_ENVIRONMENT: RefCell<Option<Rc<Environment<'static>>>> = const { RefCell::new(None) };
+}
+
+#[derive(Serialize)]
+struct ShellContext<'a> {
+    #[serde(flatten)]
+    meta: &'a PageMeta,
+    body: &'a str,
+}
+
+#[derive(Serialize)]
+struct HomeContext<'a> {
+    title: &'a str,
+    description: &'a str,
+    apps: &'a [App],
+    query: &'a str,
+}
+
+#[derive(Serialize)]
+struct SearchContext<'a> {
+    query: &'a str,
+    apps: &'a [App],
+}
+
+#[derive(Serialize)]
+struct AuthorContext<'a> {
+    author: &'a str,
+    apps: &'a [App],
+}
+
+#[derive(Serialize)]
+struct AppDetailContext<'a> {
+    app: Option<&'a App>,
+}
+
+/// Build the template environment. Every template is HTML auto-escaped,
+/// whatever its name, and two filters cover the contexts where HTML escaping
+/// is not enough:
+///
+/// - `href` for URLs in `href`/`src` attributes, see [`escape::href`]
+/// - `script_json` for data embedded in a `<script>` element
+///
+/// Pre-rendered markup is passed through with `safe` only where the source
+/// is one of these templates, e.g. the page `body`.
+///
+/// `shell` replaces the compiled-in `index.html`.
+fn build_environment(shell: Option<String>) -> Result<Environment<'static>, String> {
+    let mut env = Environment::new();
+    env.set_auto_escape_callback(|_| AutoEscape::Html);
+    env.add_filter("href", |url: Cow<'_, str>| escape::href(&url).to_string());
+    env.add_filter("script_json", script_json);
+    for (name, source) in TEMPLATES {
+        env.add_template(name, source)
+            .map_err(|e| format!("Template parse error: {e}"))?;
+    }
+    if let Some(shell) = shell {
+        env.add_template_owned("index.html", shell)
+            .map_err(|e| format!("Template parse error in index.html: {e}"))?;
+    }
+    Ok(env)
+}
+
+/// Build the environment for the XML and SVG documents: sitemaps, feeds,
+/// stats charts and the compiled-in OG card templates. Their values are
+/// escaped with [`escape::xml`] as the context is built, so nothing is
+/// auto-escaped here.
+fn build_xml_environment() -> Result<Environment<'static>, String> {
+    let mut env = Environment::new();
+    env.set_auto_escape_callback(|_| AutoEscape::None);
+    for (name, source) in [sitemap::TEMPLATES, feed::TEMPLATES, stats::TEMPLATES].concat() {
+        env.add_template(name, source)
+            .map_err(|e| format!("Template parse error in {name}: {e}"))?;
+    }
+    for (name, source) in ogimage::builtin_templates() {
+        env.add_template_owned(ogimage::builtin_template_name(name), source)
+            .map_err(|e| format!("Template parse error in {name}: {e}"))?;
+    }
+    Ok(env)
+}
+
+/// Check that an uploaded `index.html` can serve as the page shell.
+pub fn check_shell(shell: &str) -> Result<(), String> {
+    let env = build_environment(Some(shell.to_string()))?;
+    let meta = PageMeta::noindex("Shell check", "");
+    env.get_template("index.html")
+        .and_then(|tmpl| {
+            tmpl.render(ShellContext {
+                meta: &meta,
+                body: "",
+            })
+        })
+        .map(|_| ())
+        .map_err(|e| format!("Template render error in index.html: {e}"))
+}
+
+/// Drop the parsed templates so the next render picks up a newly uploaded
+/// `index.html`.
+pub fn reset() {
+    ENVIRONMENT.set(None);
+}
+
+fn with_environment<T>(
+    f: impl FnOnce(&Environment<'static>) -> Result<T, String>,
+) -> Result<T, String> {
+    let env = match ENVIRONMENT.with_borrow(Clone::clone) {
+        Some(env) => env,
+        None => {
+            let env = Rc::new(build_environment(frontend::shell()?)?);
+            ENVIRONMENT.set(Some(env.clone()));
+            env
+        }
+    };
+    f(&env)
+}
+
+/// Serialize a value as JSON that cannot break out of its `<script>`.
+fn script_json(value: Value) -> Result<Value, minijinja::Error> {
+    let json = serde_json::to_string(&value)
+        .map_err(|e| minijinja::Error::new(ErrorKind::BadSerialization, e.to_string()))?;
+    Ok(Value::from_safe_string(escape::json_for_script(&json)))
+}
+
+fn render<C: Serialize>(name: &str, ctx: &C) -> Result<String, String> {
+    with_environment(|env| render_with(env, name, ctx))
+}
+
+fn render_with<C: Serialize>(env: &Environment, name: &str, ctx: &C) -> Result<String, String> {
+    let tmpl = env
+        .get_template(name)
+        .map_err(|e| format!("Template lookup error: {e}"))?;
+    tmpl.render(ctx)
+        .map_err(|e| format!("Template render error: {e}"))
+}
+
+/// Render one of the XML or SVG templates from [`build_xml_environment`].
+/// Text in `ctx` must already be escaped with [`escape::xml`].
+pub(crate) fn render_xml<C: Serialize>(name: &str, ctx: &C) -> Result<String, String> {
+    let env = match XML_ENVIRONMENT.with_borrow(Clone::clone) {
+        Some(env) => env,
+        None => {
+            let env = Rc::new(build_xml_environment()?);
+            XML_ENVIRONMENT.set(Some(env.clone()));
+            env
+        }
+    };
+    render_with(&env, name, ctx)
+}
+
+/// Render the standalone oEmbed card, see [`oembed::render_card`].
+pub(crate) fn render_embed_card<C: Serialize>(ctx: &C) -> Result<String, String> {
+    render("embed_card.html", ctx)
+}
+
+/// Shorten `text` to `max` characters, ending in an ellipsis if cut. Cut
+/// before escaping, so no entity is split.
+pub(crate) fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let mut out: String = text.chars().take(max.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+}
+
+/// Render a full HTML document: the page's `<head>` metadata plus `body`
+/// placed inside `#root`.
+pub fn render_page(meta: &PageMeta, body: &str) -> Result<String, String> {
+    render("index.html", &ShellContext { meta, body })
+}
+
+/// Render the gallery markup placed inside `#root` on `/`.
+pub fn render_home(title: &str, description: &str, apps: &[App]) -> Result<String, String> {
+    render(
+        "home.html",
+        &HomeContext {
+            title,
+            description,
+            apps,
+            query: "",
+        },
+    )
+}
+
+/// Render the results markup placed inside `#root` on `/search?q=`.
+pub fn render_search(query: &str, apps: &[App]) -> Result<String, String> {
+    render("search.html", &SearchContext { query, apps })
+}
+
+/// Render the author's gallery placed inside `#root` on `/author/:name`.
+pub fn render_author(author: &str, apps: &[App]) -> Result<String, String> {
+    render("author.html", &AuthorContext { author, apps })
+}
+
+/// Render the detail markup placed inside `#root` on `/app/:id`, or a short
+/// not-found notice when `app` is `None`.
+pub fn render_app(app: Option<&App>) -> Result<String, String> {
+    render("app_detail.html", &AppDetailContext { app })
+}
+
+/// Turn a rendered page into an HTML response with `status`. A render error
+/// is logged and served as a static 500 page rather than trapping. Like every
+/// server error, it is sent with `Cache-Control: no-store` and not kept in
+/// the router's cache, see `http_request_update`.
+pub fn html_response(page: Result<String, String>, status: StatusCode) -> HttpResponse<'static> {
+    let (status, body) = match page {
+        Ok(html) => (status, Cow::Owned(html.into_bytes())),
+        Err(e) => {
+            ic_cdk::println!("Page render failed: {e}");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Cow::Borrowed(ERROR_PAGE.as_bytes()),
+            )
+        }
+    };
+
+    HttpResponse::builder()
+        .with_headers(vec![("Content-Type".into(), "text/html".into())])
+        .with_status_code(status)
+        .with_body(body)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = "<script>alert(1)</script>";
+
+    /// Render with the compiled-in shell, which would otherwise be looked up
+    /// in SQLite.
+    fn use_compiled_shell() {
+        ENVIRONMENT.set(Some(Rc::new(build_environment(None).unwrap())));
+    }
+
+    fn hostile_app() -> App {
+        App {
+            id: 7,
+            url: "javascript:alert(1)".to_string(),
+            canister_id: None,
+            title: format!("</title>{SCRIPT}"),
+            description: format!("</script>{SCRIPT}<!--"),
+            image_id: Some("abc".to_string()),
+            author_name: Some(format!("\"><img src=x onerror=alert(1)>{SCRIPT}")),
+            app_name: Some(format!("\" onmouseover=\"alert(1){SCRIPT}")),
+            social_post_url: Some("//evil.example/status/1".to_string()),
+            created_at: 0,
+            updated_at: 0,
+            blurhash: None,
+            dominant_color: None,
+            accent_color: None,
+            screenshot_flags: Vec::new(),
+            alive: None,
+            last_seen_alive_at: None,
+            unreachable_since: None,
+        }
+    }
+
+    /// No hostile value made it into the markup unescaped.
+    fn assert_escaped(html: &str) {
+        assert!(!html.contains(SCRIPT), "{html}");
+        assert!(!html.contains("<img src=x"), "{html}");
+        assert!(!html.contains("\" onmouseover="), "{html}");
+        assert!(!html.contains("href=\"javascript:"), "{html}");
+        assert!(!html.contains("href=\"//"), "{html}");
+    }
+
+    #[test]
+    fn truncate_counts_characters() {
+        assert_eq!(truncate("short", 5), "short");
+        assert_eq!(truncate("longer", 5), "long…");
+        assert_eq!(truncate("ééééé", 4), "ééé…");
+        assert_eq!(truncate("a", 0), "…");
+    }
+
+    #[test]
+    fn home_escapes_app_fields() {
+        use_compiled_shell();
+        let html = render_home(SCRIPT, SCRIPT, &[hostile_app()]).unwrap();
+        assert_escaped(&html);
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;&#x2f;script&gt;"));
+    }
+
+    #[test]
+    fn search_escapes_query_in_text_and_attributes() {
+        use_compiled_shell();
+        let query = format!("\"><a href=\"javascript:alert(1)\">{SCRIPT}");
+        for apps in [vec![hostile_app()], Vec::new()] {
+            let html = render_search(&query, &apps).unwrap();
+            assert_escaped(&html);
+            assert!(!html.contains("\"><a href"), "{html}");
+        }
+    }
+
+    #[test]
+    fn author_escapes_author_name() {
+        use_compiled_shell();
+        let author = hostile_app().author_name.unwrap();
+        for apps in [vec![hostile_app()], Vec::new()] {
+            assert_escaped(&render_author(&author, &apps).unwrap());
+        }
+    }
+
+    #[test]
+    fn app_detail_neutralizes_links() {
+        use_compiled_shell();
+        let html = render_app(Some(&hostile_app())).unwrap();
+        assert_escaped(&html);
+        assert_eq!(html.matches("href=\"#\"").count(), 2, "{html}");
+    }
+
+    #[test]
+    fn page_keeps_json_ld_inside_its_script() {
+        use_compiled_shell();
+        let app = hostile_app();
+        let body = render_app(Some(&app)).unwrap();
+        let html = render_page(&PageMeta::for_app(&app), &body).unwrap();
+
+        let (head, rest) = html
+            .split_once("<script type=\"application/ld+json\">")
+            .unwrap();
+        let (json, tail) = rest.split_once("</script>").unwrap();
+        assert_escaped(&format!("{head}{tail}"));
+        assert!(!json.contains('<'), "{json}");
+        let data: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(data["description"], app.description);
+        assert_eq!(data["headline"], app.title);
+    }
+}
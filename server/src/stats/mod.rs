@@ -0,0 +1,439 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use ic_asset_router::{HttpResponse, StatusCode};
+use ic_rusqlite::with_connection;
+use serde::Serialize;
+
+use crate::app::app_manager::LISTED;
+use crate::{escape, ssr};
+
+/// Registered in the shared XML environment, see [`ssr::render_xml`].
+pub(crate) const TEMPLATES: &[(&str, &str)] = &[
+    ("bar_chart.svg", include_str!("includes/bar_chart.svg")),
+    ("histogram.svg", include_str!("includes/histogram.svg")),
+];
+
+/// Longest span of days the `created_at` histogram covers, counting back
+/// from the newest app.
+const HISTOGRAM_MAX_DAYS: u32 = 366;
+/// Rows drawn in the per-author and per-domain charts.
+const CHART_MAX_BARS: usize = 15;
+const CHART_WIDTH: u32 = 640;
+const LABEL_WIDTH: u32 = 200;
+const BAR_MAX_WIDTH: u32 = 360;
+const ROW_HEIGHT: u32 = 24;
+const HISTOGRAM_HEIGHT: u32 = 240;
+
+thread_local! {
+    /// Computed on first use, and again after the app data changes.
+    static STATS: RefCell<Option<Rc<Stats>>> = const { RefCell::new(None) };
+}
+
+/// Aggregates over the submitted apps, served at `/api/stats` and drawn at
+/// `/stats/*.svg`.
+#[derive(Serialize)]
+pub struct Stats {
+    pub app_count: u32,
+    pub author_count: u32,
+    /// Most apps first. Apps without an author are left out.
+    pub apps_per_author: Vec<Bucket>,
+    /// Most apps first, keyed by the app URL's host without its first label
+    /// when that leaves a registrable domain, e.g. `icp0.io`.
+    pub apps_per_domain: Vec<Bucket>,
+    pub with_screenshot: u32,
+    pub without_screenshot: u32,
+    pub with_social_post: u32,
+    pub without_social_post: u32,
+    /// Apps created per UTC day, `YYYY-MM-DD`, oldest first. Days without
+    /// submissions are included with a count of 0.
+    pub created_per_day: Vec<Bucket>,
+}
+
+#[derive(Serialize)]
+pub struct Bucket {
+    pub key: String,
+    pub count: u32,
+}
+
+/// The charts served under `/stats/`.
+pub enum Chart {
+    Authors,
+    Domains,
+    Coverage,
+    Submissions,
+}
+
+#[derive(Serialize)]
+struct Bar {
+    label: String,
+    value: u32,
+    y: u32,
+    width: u32,
+}
+
+#[derive(Serialize)]
+struct Column {
+    label: String,
+    value: u32,
+    x: f64,
+    y: f64,
+    height: f64,
+}
+
+/// The current stats, computed once per change to the app data.
+pub fn get() -> Result<Rc<Stats>, String> {
+    if let Some(stats) = STATS.with_borrow(Clone::clone) {
+        return Ok(stats);
+    }
+    let stats = Rc::new(compute()?);
+    STATS.set(Some(stats.clone()));
+    Ok(stats)
+}
+
+/// Drop the computed stats so the next request sees changed app data.
+pub fn reset() {
+    STATS.set(None);
+}
+
+fn compute() -> Result<Stats, String> {
+    with_connection(|conn| {
+        let (app_count, author_count, with_screenshot, with_social_post) = conn
+            .query_row(
+                &format!(
+                    "SELECT COUNT(*), COUNT(DISTINCT author_name), COUNT(image_id),
+                            COUNT(NULLIF(social_post_url, ''))
+                     FROM app WHERE {LISTED}"
+                ),
+                (),
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .map_err(|e| e.to_string())?;
+
+        let buckets = |sql: &str| -> Result<Vec<Bucket>, String> {
+            let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+            let rows = stmt
+                .query_map((), |row| {
+                    Ok(Bucket {
+                        key: row.get(0)?,
+                        count: row.get(1)?,
+                    })
+                })
+                .map_err(|e| e.to_string())?;
+            rows.collect::<ic_rusqlite::Result<Vec<_>>>()
+                .map_err(|e| e.to_string())
+        };
+
+        let apps_per_author = buckets(&format!(
+            "SELECT author_name, COUNT(*) FROM app
+             WHERE author_name IS NOT NULL AND author_name <> '' AND {LISTED}
+             GROUP BY author_name
+             ORDER BY COUNT(*) DESC, author_name"
+        ))?;
+
+        // The host is whatever follows the scheme up to the first `/`, `?`,
+        // `#` or `:`; hosts with at least two dots lose their first label
+        let apps_per_domain = buckets(&format!(
+            "WITH rest AS (
+                 SELECT substr(url, instr(url, '://') + 3) || '/' AS rest FROM app
+                 WHERE {LISTED}
+             ), host AS (
+                 SELECT lower(substr(rest, 1, min(
+                     instr(rest, '/'),
+                     coalesce(nullif(instr(rest, '?'), 0), length(rest)),
+                     coalesce(nullif(instr(rest, '#'), 0), length(rest)),
+                     coalesce(nullif(instr(rest, ':'), 0), length(rest))
+                 ) - 1)) AS host FROM rest
+             ), domain AS (
+                 SELECT CASE
+                     WHEN length(host) - length(replace(host, '.', '')) >= 2
+                     THEN substr(host, instr(host, '.') + 1)
+                     ELSE host
+                 END AS domain FROM host
+             )
+             SELECT domain, COUNT(*) FROM domain
+             GROUP BY domain
+             ORDER BY COUNT(*) DESC, domain"
+        ))?;
+
+        let created_per_day = buckets(&format!(
+            "WITH RECURSIVE day(day) AS (
+                 SELECT max(
+                     date(MIN(created_at), 'unixepoch'),
+                     date(MAX(created_at), 'unixepoch', '-{} days')
+                 ) FROM app WHERE {LISTED}
+                 UNION ALL
+                 SELECT date(day, '+1 day') FROM day
+                 WHERE day < (SELECT date(MAX(created_at), 'unixepoch') FROM app WHERE {LISTED})
+             ), created AS (
+                 SELECT date(created_at, 'unixepoch') AS day, COUNT(*) AS count
+                 FROM app WHERE {LISTED} GROUP BY 1
+             )
+             SELECT day, coalesce(count, 0) FROM day LEFT JOIN created USING (day)
+             WHERE day IS NOT NULL
+             ORDER BY day",
+            HISTOGRAM_MAX_DAYS - 1
+        ))?;
+
+        Ok(Stats {
+            app_count,
+            author_count,
+            apps_per_author,
+            apps_per_domain,
+            with_screenshot,
+            without_screenshot: app_count - with_screenshot,
+            with_social_post,
+            without_social_post: app_count - with_social_post,
+            created_per_day,
+        })
+    })
+}
+
+/// Render one of the charts as an SVG document.
+pub fn render_chart(chart: Chart) -> Result<String, String> {
+    let stats = get()?;
+    match chart {
+        Chart::Authors => bar_chart("Apps per author", &stats.apps_per_author),
+        Chart::Domains => bar_chart("Apps per hosting domain", &stats.apps_per_domain),
+        Chart::Coverage => bar_chart(
+            "Screenshots and social posts",
+            &[
+                ("With screenshot", stats.with_screenshot),
+                ("Without screenshot", stats.without_screenshot),
+                ("With social post", stats.with_social_post),
+                ("Without social post", stats.without_social_post),
+            ]
+            .map(|(key, count)| Bucket {
+                key: key.to_string(),
+                count,
+            }),
+        ),
+        Chart::Submissions => histogram("Submissions per day", &stats.created_per_day),
+    }
+}
+
+/// Serve a chart, or a plain-text error if it could not be rendered.
+pub fn chart_response(chart: Chart) -> HttpResponse<'static> {
+    match render_chart(chart) {
+        Ok(svg) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "image/svg+xml".into())])
+            .with_status_code(StatusCode::OK)
+            .with_body(Cow::Owned(svg.into_bytes()))
+            .build(),
+        Err(e) => HttpResponse::builder()
+            .with_headers(vec![("Content-Type".into(), "text/plain".into())])
+            .with_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+            .with_body(Cow::Owned(
+                format!("Chart generation failed: {e}").into_bytes(),
+            ))
+            .build(),
+    }
+}
+
+/// Horizontal bars for the first [`CHART_MAX_BARS`] buckets, scaled to the
+/// largest.
+fn bar_chart(title: &str, buckets: &[Bucket]) -> Result<String, String> {
+    let shown = &buckets[..buckets.len().min(CHART_MAX_BARS)];
+    let max = shown.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+    let bars: Vec<Bar> = shown
+        .iter()
+        .enumerate()
+        .map(|(i, bucket)| Bar {
+            label: escape::xml(&ssr::truncate(&bucket.key, 28)),
+            value: bucket.count,
+            y: 44 + i as u32 * ROW_HEIGHT,
+            width: (bucket.count * BAR_MAX_WIDTH / max).max(1),
+        })
+        .collect();
+
+    // Values are XML-escaped as the context is built
+    ssr::render_xml(
+        "bar_chart.svg",
+        &minijinja::context! {
+            title => escape::xml(title),
+            width => CHART_WIDTH,
+            height => 60 + bars.len().max(1) as u32 * ROW_HEIGHT,
+            label_width => LABEL_WIDTH,
+            bars => bars,
+        },
+    )
+}
+
+/// One column per bucket, scaled to the largest.
+fn histogram(title: &str, buckets: &[Bucket]) -> Result<String, String> {
+    let (plot_left, plot_top, plot_bottom) = (48.0, 56.0, f64::from(HISTOGRAM_HEIGHT - 32));
+    let plot_width = f64::from(CHART_WIDTH) - 16.0 - plot_left;
+    let column_width = plot_width / buckets.len().max(1) as f64;
+    let max = buckets.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+    let columns: Vec<Column> = buckets
+        .iter()
+        .enumerate()
+        .map(|(i, bucket)| {
+            let height = (plot_bottom - plot_top) * f64::from(bucket.count) / f64::from(max);
+            Column {
+                label: escape::xml(&bucket.key),
+                value: bucket.count,
+                x: plot_left + i as f64 * column_width,
+                y: plot_bottom - height,
+                height,
+            }
+        })
+        .collect();
+
+    ssr::render_xml(
+        "histogram.svg",
+        &minijinja::context! {
+            title => escape::xml(title),
+            width => CHART_WIDTH,
+            height => HISTOGRAM_HEIGHT,
+            plot_left => plot_left,
+            plot_top => plot_top,
+            plot_bottom => plot_bottom,
+            column_width => column_width,
+            max => max,
+            first => buckets.first().map(|b| escape::xml(&b.key)),
+            last => buckets.last().map(|b| escape::xml(&b.key)),
+            columns => columns,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use resvg::usvg::roxmltree::Document;
+
+    use super::*;
+
+    fn bucket(key: &str, count: u32) -> Bucket {
+        Bucket {
+            key: key.to_string(),
+            count,
+        }
+    }
+
+    /// Every text node of `svg`, unescaped, which also checks it is well
+    /// formed.
+    fn texts(svg: &str) -> Vec<String> {
+        let doc = Document::parse(svg).unwrap();
+        doc.descendants()
+            .filter_map(|node| node.text())
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect()
+    }
+
+    /// Set the creation time of every app to `default`, except those of
+    /// the first few, which get `times` in turn.
+    fn set_created_at(default: i64, times: &[i64]) {
+        with_connection(|conn| {
+            conn.execute("UPDATE app SET created_at = ?1", (default,))
+                .unwrap();
+            for (i, time) in times.iter().enumerate() {
+                conn.execute(
+                    "UPDATE app SET created_at = ?2
+                     WHERE id = (SELECT id FROM app ORDER BY id LIMIT 1 OFFSET ?1)",
+                    (i as i64, time),
+                )
+                .unwrap();
+            }
+        })
+    }
+
+    #[test]
+    fn bar_chart_escapes_labels() {
+        let svg = bar_chart(
+            "Apps per <domain> & more",
+            &[
+                bucket("a<b>.icp0.io", 3),
+                bucket("x&y.caffeine.xyz", 2),
+                bucket(&"&".repeat(40), 1),
+            ],
+        )
+        .unwrap();
+        let texts = texts(&svg);
+        assert!(texts.contains(&"Apps per <domain> & more".to_string()));
+        assert!(texts.contains(&"a<b>.icp0.io".to_string()));
+        assert!(texts.contains(&"x&y.caffeine.xyz".to_string()));
+        // Cut before escaping, so no entity is split
+        assert!(texts.contains(&format!("{}…", "&".repeat(27))));
+    }
+
+    #[test]
+    fn histogram_escapes_labels() {
+        let svg = histogram(
+            "Per <day> & night",
+            &[
+                bucket("<first>", 1),
+                bucket("a&b", 0),
+                bucket("\"last\"", 2),
+            ],
+        )
+        .unwrap();
+        let texts = texts(&svg);
+        for text in ["Per <day> & night", "<first>", "\"last\"", "a&b: 0"] {
+            assert!(texts.contains(&text.to_string()), "{text} in {texts:?}");
+        }
+    }
+
+    #[test]
+    fn histogram_columns_span_the_plot() {
+        let buckets = [
+            bucket("2026-01-01", 2),
+            bucket("2026-01-02", 0),
+            bucket("2026-01-03", 4),
+        ];
+        let svg = histogram("Submissions per day", &buckets).unwrap();
+        let doc = Document::parse(&svg).unwrap();
+        let columns: Vec<(f64, f64)> = doc
+            .descendants()
+            .filter(|node| node.has_tag_name("rect") && node.attribute("fill") == Some("#6366f1"))
+            .map(|node| {
+                let attr = |name| node.attribute(name).unwrap().parse::<f64>().unwrap();
+                (attr("x"), attr("height"))
+            })
+            .collect();
+        assert_eq!(columns.len(), 3);
+        let column_width = (f64::from(CHART_WIDTH) - 16.0 - 48.0) / 3.0;
+        assert_eq!(columns[0].0, 48.0);
+        assert_eq!(columns[2].0 + column_width, f64::from(CHART_WIDTH) - 16.0);
+        assert_eq!(columns[1].1, 0.0);
+        assert_eq!(columns[2].1, 2.0 * columns[0].1);
+    }
+
+    #[test]
+    fn days_split_at_utc_midnight() {
+        crate::use_test_database();
+        // 2026-01-10T00:00:00Z
+        let midnight = 1_768_003_200;
+        set_created_at(midnight + 3600, &[midnight - 1, midnight - 2 * 86_400]);
+
+        let stats = compute().unwrap();
+        let days: Vec<(&str, u32)> = stats
+            .created_per_day
+            .iter()
+            .map(|b| (b.key.as_str(), b.count))
+            .collect();
+        assert_eq!(
+            days,
+            [
+                ("2026-01-08", 1),
+                ("2026-01-09", 1),
+                ("2026-01-10", stats.app_count - 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn days_cover_at_most_a_year() {
+        crate::use_test_database();
+        let newest = 1_768_003_200;
+        set_created_at(newest, &[newest - 400 * 86_400]);
+
+        let days = compute().unwrap().created_per_day;
+        assert_eq!(days.len(), HISTOGRAM_MAX_DAYS as usize);
+        assert_eq!(days.first().unwrap().key, "2025-01-10");
+        assert_eq!(days.first().unwrap().count, 0);
+        assert_eq!(days.last().unwrap().key, "2026-01-10");
+    }
+}
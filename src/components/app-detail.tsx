@@ -1,6 +1,7 @@
 import { Link } from "@tanstack/react-router";
 import type { App } from "@/types";
 import { getWinnerBadge } from "@/lib/constants";
+import { screenshotPlaceholder, screenshotSrcSet } from "@/lib/images";
 import ImageWithSkeleton from "@/components/image-with-skeleton";
 import ArrowLeftIcon from "@/components/icons/arrow-left";
 import SocialEmbed from "@/components/social-embed";
@@ -40,7 +41,10 @@ export default function AppDetail({
         {app.image_id && (
           <ImageWithSkeleton
             src={`/images/${app.image_id}_1500.jpg`}
+            srcSet={screenshotSrcSet(app.image_id, [600, 900, 1200, 1500])}
+            sizes="(min-width: 768px) 704px, 100vw"
             alt={app.title}
+            placeholder={screenshotPlaceholder(app)}
           />
         )}
 
@@ -75,6 +79,17 @@ export default function AppDetail({
                 </td>
               </tr>
             )}
+            {app.unreachable_since !== null && (
+              <tr className="border-b border-border">
+                <td className="py-3 pr-6 text-muted-foreground whitespace-nowrap">Status</td>
+                <td className="py-3 text-muted-foreground">
+                  Unreachable since{" "}
+                  {new Date(app.unreachable_since * 1000).toLocaleDateString(undefined, {
+                    dateStyle: "medium",
+                  })}
+                </td>
+              </tr>
+            )}
           </tbody>
         </table>
 
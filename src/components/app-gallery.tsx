@@ -1,7 +1,12 @@
-import { useMemo, useState } from "react";
+import { useMemo, useState, type CSSProperties } from "react";
 import { Link } from "@tanstack/react-router";
 import type { App } from "@/types";
-import { WINNER_IDS } from "@/lib/constants";
+import { UNREACHABLE_DEPRIORITIZE_SECONDS, WINNER_IDS } from "@/lib/constants";
+import {
+  hasUsableScreenshot,
+  screenshotPlaceholder,
+  screenshotSrcSet,
+} from "@/lib/images";
 
 function shuffle<T>(array: T[]): T[] {
   const shuffled = [...array];
@@ -24,18 +29,40 @@ function SkeletonCard() {
   );
 }
 
-function CardImage({ src, alt }: { src: string; alt: string }) {
+function CardImage({
+  src,
+  srcSet,
+  alt,
+  placeholder,
+  accentColor,
+}: {
+  src: string;
+  srcSet?: string;
+  alt: string;
+  placeholder?: CSSProperties;
+  accentColor?: string | null;
+}) {
   const [loaded, setLoaded] = useState(false);
 
   return (
     <div className="overflow-hidden relative aspect-video rounded">
-      {!loaded && (
-        <div className="absolute inset-0 animate-pulse bg-muted" />
-      )}
+      {!loaded &&
+        (placeholder ? (
+          <div className="absolute inset-0" style={placeholder} />
+        ) : (
+          <div className="absolute inset-0 animate-pulse bg-muted" />
+        ))}
       <img
         src={src}
+        srcSet={srcSet}
+        sizes="300px"
         alt={alt}
-        className={`w-full h-full object-cover group-hover:scale-110 transition-transform duration-300 border border-white/20 rounded ${loaded ? "opacity-100" : "opacity-0"}`}
+        className={`w-full h-full object-cover group-hover:scale-110 transition-transform duration-300 border border-white/20 rounded ${accentColor ? "group-hover:border-(--accent-color)" : ""} ${loaded ? "opacity-100" : "opacity-0"}`}
+        style={
+          accentColor
+            ? ({ "--accent-color": accentColor } as CSSProperties)
+            : undefined
+        }
         loading="lazy"
         onLoad={() => { setLoaded(true); }}
       />
@@ -53,7 +80,15 @@ export default function AppGallery({
   const shuffledApps = useMemo(() => {
     if (!apps) return [];
     const winnerIdSet = new Set(WINNER_IDS);
-    return shuffle(apps.filter((app) => !winnerIdSet.has(app.id)));
+    const shuffled = shuffle(apps.filter((app) => !winnerIdSet.has(app.id)));
+    // Apps that have been down for a week go last
+    const cutoff = Date.now() / 1000 - UNREACHABLE_DEPRIORITIZE_SECONDS;
+    const isLongDown = (app: App) =>
+      app.unreachable_since !== null && app.unreachable_since < cutoff;
+    return [
+      ...shuffled.filter((app) => !isLongDown(app)),
+      ...shuffled.filter(isLongDown),
+    ];
   }, [apps]);
 
   if (isLoading) {
@@ -82,10 +117,13 @@ export default function AppGallery({
             params={{ id: String(app.id) }}
             className="group block w-75 overflow-hidden transition-all"
           >
-            {app.image_id ? (
+            {hasUsableScreenshot(app) ? (
               <CardImage
                 src={`/images/${app.image_id}_300.jpg`}
+                srcSet={screenshotSrcSet(app.image_id, [300, 600])}
                 alt={app.title}
+                placeholder={screenshotPlaceholder(app)}
+                accentColor={app.accent_color}
               />
             ) : (
               <div className="rounded bg-secondary flex items-center justify-center aspect-video">
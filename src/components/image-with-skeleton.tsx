@@ -1,21 +1,32 @@
-import { useState } from "react";
+import { useState, type CSSProperties } from "react";
 
 export default function ImageWithSkeleton({
   src,
+  srcSet,
+  sizes,
   alt,
+  placeholder,
 }: {
   src: string;
+  srcSet?: string;
+  sizes?: string;
   alt: string;
+  placeholder?: CSSProperties;
 }) {
   const [loaded, setLoaded] = useState(false);
 
   return (
     <div className="w-full rounded-xl my-8 overflow-hidden bg-card">
-      {!loaded && (
-        <div className="w-full aspect-video animate-pulse rounded-xl bg-muted" />
-      )}
+      {!loaded &&
+        (placeholder ? (
+          <div className="w-full aspect-video rounded-xl" style={placeholder} />
+        ) : (
+          <div className="w-full aspect-video animate-pulse rounded-xl bg-muted" />
+        ))}
       <img
         src={src}
+        srcSet={srcSet}
+        sizes={sizes}
         alt={alt}
         className={`w-full h-auto ${loaded ? "" : "hidden"}`}
         onLoad={() => setLoaded(true)}
@@ -1,5 +1,6 @@
 import { Link } from "@tanstack/react-router";
 import type { App } from "@/types";
+import { screenshotPlaceholder, screenshotSrcSet } from "@/lib/images";
 
 
 export default function SearchResults({
@@ -47,9 +48,18 @@ export default function SearchResults({
             className="block group"
           >
             <div className="flex flex-col sm:flex-row items-center hover:bg-card/50 rounded p-3 gap-3">
-              <div className="shrink-0 w-full sm:w-72 rounded overflow-hidden bg-card border-white/20 border ">
+              <div
+                className="shrink-0 w-full sm:w-72 rounded overflow-hidden bg-card border-white/20 border "
+                style={screenshotPlaceholder(app)}
+              >
                 <img
                   src={`/images/${app.image_id ?? ""}_300.jpg`}
+                  srcSet={
+                    app.image_id
+                      ? screenshotSrcSet(app.image_id, [300, 600, 900])
+                      : undefined
+                  }
+                  sizes="(min-width: 640px) 288px, 100vw"
                   alt={app.title}
                   className="w-full h-full object-cover group-hover:scale-110 transition-transform duration-300"
                   loading="lazy"
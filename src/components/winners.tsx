@@ -1,20 +1,47 @@
-import { useState } from "react";
+import { useState, type CSSProperties } from "react";
 import { Link } from "@tanstack/react-router";
 import type { App } from "@/types";
 import { WINNER_IDS, PODIUM } from "@/lib/constants";
+import {
+  hasUsableScreenshot,
+  screenshotPlaceholder,
+  screenshotSrcSet,
+} from "@/lib/images";
 
-function WinnerCardImage({ src, alt }: { src: string; alt: string }) {
+function WinnerCardImage({
+  src,
+  srcSet,
+  alt,
+  placeholder,
+  accentColor,
+}: {
+  src: string;
+  srcSet?: string;
+  alt: string;
+  placeholder?: CSSProperties;
+  accentColor?: string | null;
+}) {
   const [loaded, setLoaded] = useState(false);
 
   return (
     <div className="overflow-hidden relative aspect-video rounded">
-      {!loaded && (
-        <div className="absolute inset-0 animate-pulse bg-muted" />
-      )}
+      {!loaded &&
+        (placeholder ? (
+          <div className="absolute inset-0" style={placeholder} />
+        ) : (
+          <div className="absolute inset-0 animate-pulse bg-muted" />
+        ))}
       <img
         src={src}
+        srcSet={srcSet}
+        sizes="300px"
         alt={alt}
-        className={`w-full h-full object-cover group-hover:scale-110 transition-transform duration-300 border border-white/20 rounded ${loaded ? "opacity-100" : "opacity-0"}`}
+        className={`w-full h-full object-cover group-hover:scale-110 transition-transform duration-300 border border-white/20 rounded ${accentColor ? "group-hover:border-(--accent-color)" : ""} ${loaded ? "opacity-100" : "opacity-0"}`}
+        style={
+          accentColor
+            ? ({ "--accent-color": accentColor } as CSSProperties)
+            : undefined
+        }
         loading="lazy"
         onLoad={() => { setLoaded(true); }}
       />
@@ -83,10 +110,13 @@ export default function Winners({
               className="group block w-75 overflow-hidden transition-all"
             >
               <div className="relative">
-                {app.image_id ? (
+                {hasUsableScreenshot(app) ? (
                   <WinnerCardImage
                     src={`/images/${app.image_id}_300.jpg`}
+                    srcSet={screenshotSrcSet(app.image_id, [300, 600])}
                     alt={app.title}
+                    placeholder={screenshotPlaceholder(app)}
+                    accentColor={app.accent_color}
                   />
                 ) : (
                   <div className="rounded bg-secondary flex items-center justify-center aspect-video">
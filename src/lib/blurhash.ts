@@ -0,0 +1,109 @@
+const BASE83 =
+  "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+const dataUrls = new Map<string, string>();
+
+function decode83(str: string): number {
+  let value = 0;
+  for (const char of str) {
+    value = value * 83 + BASE83.indexOf(char);
+  }
+  return value;
+}
+
+function srgbToLinear(value: number): number {
+  const v = value / 255;
+  return v <= 0.04045 ? v / 12.92 : Math.pow((v + 0.055) / 1.055, 2.4);
+}
+
+function linearToSrgb(value: number): number {
+  const v = Math.max(0, Math.min(1, value));
+  const srgb = v <= 0.0031308 ? v * 12.92 : 1.055 * Math.pow(v, 1 / 2.4) - 0.055;
+  return Math.round(srgb * 255);
+}
+
+function signPow(value: number, exp: number): number {
+  return Math.sign(value) * Math.pow(Math.abs(value), exp);
+}
+
+/**
+ * Decode a BlurHash, as computed by the canister for each screenshot, into
+ * RGBA pixels. See https://github.com/woltapp/blurhash.
+ */
+export function decodeBlurhash(
+  hash: string,
+  width: number,
+  height: number,
+): Uint8ClampedArray {
+  const sizeFlag = decode83(hash[0]);
+  const numX = (sizeFlag % 9) + 1;
+  const numY = Math.floor(sizeFlag / 9) + 1;
+  const maxValue = (decode83(hash[1]) + 1) / 166;
+
+  const colors: number[][] = [];
+  const dc = decode83(hash.substring(2, 6));
+  colors.push([
+    srgbToLinear(dc >> 16),
+    srgbToLinear((dc >> 8) & 255),
+    srgbToLinear(dc & 255),
+  ]);
+  for (let i = 1; i < numX * numY; i++) {
+    const ac = decode83(hash.substring(4 + i * 2, 6 + i * 2));
+    colors.push([
+      signPow((Math.floor(ac / (19 * 19)) - 9) / 9, 2) * maxValue,
+      signPow(((Math.floor(ac / 19) % 19) - 9) / 9, 2) * maxValue,
+      signPow(((ac % 19) - 9) / 9, 2) * maxValue,
+    ]);
+  }
+
+  const pixels = new Uint8ClampedArray(width * height * 4);
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      let r = 0;
+      let g = 0;
+      let b = 0;
+      for (let j = 0; j < numY; j++) {
+        for (let i = 0; i < numX; i++) {
+          const basis =
+            Math.cos((Math.PI * x * i) / width) *
+            Math.cos((Math.PI * y * j) / height);
+          const color = colors[i + j * numX];
+          r += color[0] * basis;
+          g += color[1] * basis;
+          b += color[2] * basis;
+        }
+      }
+      const offset = (y * width + x) * 4;
+      pixels[offset] = linearToSrgb(r);
+      pixels[offset + 1] = linearToSrgb(g);
+      pixels[offset + 2] = linearToSrgb(b);
+      pixels[offset + 3] = 255;
+    }
+  }
+  return pixels;
+}
+
+/**
+ * A small PNG data URL of the BlurHash, for use as a CSS background that is
+ * stretched over the screenshot's box. Results are cached by hash.
+ */
+export function blurhashDataUrl(hash: string): string | undefined {
+  const cached = dataUrls.get(hash);
+  if (cached) return cached;
+
+  const width = 32;
+  const height = 18;
+  const canvas = document.createElement("canvas");
+  canvas.width = width;
+  canvas.height = height;
+  const ctx = canvas.getContext("2d");
+  if (!ctx) return undefined;
+  ctx.putImageData(
+    new ImageData(decodeBlurhash(hash, width, height), width, height),
+    0,
+    0,
+  );
+  const url = canvas.toDataURL();
+  dataUrls.set(hash, url);
+  return url;
+}
@@ -1,6 +1,9 @@
 // Promptathon winner app IDs (1st, 2nd, 3rd place)
 export const WINNER_IDS: [number, number, number] = [121, 147, 98];
 
+// Apps unreachable for longer than this are listed last, as on the server
+export const UNREACHABLE_DEPRIORITIZE_SECONDS = 7 * 24 * 60 * 60;
+
 export const PODIUM = [
   {
     label: "Winner",
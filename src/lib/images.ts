@@ -0,0 +1,37 @@
+import type { CSSProperties } from "react";
+import type { App } from "@/types";
+import { blurhashDataUrl } from "@/lib/blurhash";
+
+/**
+ * A `srcSet` of screenshot sizes, resized on request by the canister. Widths
+ * must be among those the server allows: 150, 300, 600, 900, 1200 and 1500.
+ */
+export function screenshotSrcSet(imageId: string, widths: number[]): string {
+  return widths.map((w) => `/images/${imageId}?w=${w} ${w}w`).join(", ");
+}
+
+/**
+ * Background for a screenshot's box while it loads: the BlurHash stretched
+ * over the dominant colour. Empty for apps that have neither yet.
+ */
+export function screenshotPlaceholder(
+  app: Pick<App, "blurhash" | "dominant_color">,
+): CSSProperties | undefined {
+  if (!app.blurhash && !app.dominant_color) return undefined;
+  const url = app.blurhash ? blurhashDataUrl(app.blurhash) : undefined;
+  return {
+    backgroundColor: app.dominant_color ?? undefined,
+    backgroundImage: url ? `url(${url})` : undefined,
+    backgroundSize: "100% 100%",
+  };
+}
+
+/**
+ * Whether the app has a screenshot worth showing, i.e. one that was not
+ * flagged as blank or as an error page shared with other apps.
+ */
+export function hasUsableScreenshot(
+  app: Pick<App, "image_id" | "screenshot_flags">,
+): app is Pick<App, "screenshot_flags"> & { image_id: string } {
+  return app.image_id !== null && app.screenshot_flags.length === 0;
+}
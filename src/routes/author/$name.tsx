@@ -0,0 +1,24 @@
+import { useEffect } from "react";
+import { createFileRoute, useNavigate } from "@tanstack/react-router";
+import useSearchQuery from "@/hooks/use-search-query";
+
+export const Route = createFileRoute("/author/$name")({
+  component: AuthorRoute,
+});
+
+// /author/:name is server-rendered for crawlers and no-JS clients, mainly so
+// shared links get the author's card. Interactive browsing lives on the index
+// page, so hand the author over to its search.
+function AuthorRoute() {
+  const { name } = Route.useParams();
+  const { setQuery, setDebouncedQuery } = useSearchQuery();
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    setQuery(name);
+    setDebouncedQuery(name.trim());
+    void navigate({ to: "/", replace: true });
+  }, [name, setQuery, setDebouncedQuery, navigate]);
+
+  return null;
+}
@@ -0,0 +1,26 @@
+import { useEffect } from "react";
+import { createFileRoute, useNavigate } from "@tanstack/react-router";
+import useSearchQuery from "@/hooks/use-search-query";
+
+export const Route = createFileRoute("/search")({
+  validateSearch: (search: Record<string, unknown>) => ({
+    q: typeof search.q === "string" ? search.q : "",
+  }),
+  component: SearchRoute,
+});
+
+// /search?q= is server-rendered for no-JS clients. Interactive search lives on
+// the index page, so hand the query over to it.
+function SearchRoute() {
+  const { q } = Route.useSearch();
+  const { setQuery, setDebouncedQuery } = useSearchQuery();
+  const navigate = useNavigate();
+
+  useEffect(() => {
+    setQuery(q);
+    setDebouncedQuery(q.trim());
+    void navigate({ to: "/", replace: true });
+  }, [q, setQuery, setDebouncedQuery, navigate]);
+
+  return null;
+}
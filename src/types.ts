@@ -10,4 +10,20 @@ export interface App {
   social_post_url: string | null;
   created_at: number;
   updated_at: number;
+  /** Placeholder for the screenshot while it loads. */
+  blurhash: string | null;
+  /** Most common colour of the screenshot, as `#rrggbb`. */
+  dominant_color: string | null;
+  /** A saturated colour from the screenshot, as `#rrggbb`. */
+  accent_color: string | null;
+  /**
+   * Why the screenshot looks broken, if it does: `blank`, `uniform` and/or
+   * `shared` (the same picture as several other apps).
+   */
+  screenshot_flags: string[];
+  /** Whether the URL responded when last checked, `null` until checked. */
+  alive: boolean | null;
+  last_seen_alive_at: number | null;
+  /** When checks of the URL started failing, if they still are. */
+  unreachable_since: number | null;
 }